@@ -1,5 +1,7 @@
 #![allow(dead_code)]
 
+use std::num::NonZeroUsize;
+
 use darling::{
     FromDeriveInput, FromField, FromMeta, FromVariant,
     ast::{Data, Fields, NestedMeta},
@@ -150,6 +152,36 @@ impl FromMeta for Deprecation {
     }
 }
 
+/// A field timeout, written as a number followed by the `ms`, `s` or `m`
+/// unit, e.g. `"500ms"`. Stored in milliseconds.
+#[derive(Debug, Clone, Copy)]
+pub struct Timeout(pub u64);
+
+impl FromMeta for Timeout {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        let invalid = || {
+            darling::Error::custom(format!(
+                "invalid timeout `{value}`, expected a duration such as \"500ms\", \"2s\" or \"1m\""
+            ))
+        };
+        let (amount, unit) = if let Some(amount) = value.strip_suffix("ms") {
+            (amount, 1)
+        } else if let Some(amount) = value.strip_suffix('s') {
+            (amount, 1_000)
+        } else if let Some(amount) = value.strip_suffix('m') {
+            (amount, 60_000)
+        } else {
+            return Err(invalid());
+        };
+        amount
+            .parse::<u64>()
+            .ok()
+            .and_then(|amount| amount.checked_mul(unit))
+            .map(Timeout)
+            .ok_or_else(invalid)
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub enum Resolvability {
     #[default]
@@ -216,6 +248,8 @@ pub struct SimpleObjectField {
     #[darling(default)]
     pub guard: Option<Expr>,
     #[darling(default)]
+    pub guard_result: Option<Expr>,
+    #[darling(default)]
     pub visible: Option<Visible>,
     #[darling(default, multiple)]
     pub derived: Vec<DerivedField>,
@@ -237,6 +271,12 @@ pub struct SimpleObjectField {
     pub complexity: Option<Expr>,
     #[darling(default, multiple)]
     pub requires_scopes: Vec<String>,
+    #[darling(default)]
+    pub authenticated: bool,
+    #[darling(default)]
+    pub timeout: Option<Timeout>,
+    #[darling(default)]
+    pub max_concurrency: Option<NonZeroUsize>,
 }
 
 #[derive(FromDeriveInput)]
@@ -286,10 +326,16 @@ pub struct SimpleObject {
     pub input_name: Option<String>,
     #[darling(default)]
     pub guard: Option<Expr>,
+    #[darling(default)]
+    pub guard_result: Option<Expr>,
+    #[darling(default)]
+    pub type_guard: Option<Expr>,
     #[darling(default, multiple, rename = "directive")]
     pub directives: Vec<Expr>,
     #[darling(default, multiple)]
     pub requires_scopes: Vec<String>,
+    #[darling(default)]
+    pub authenticated: bool,
     #[darling(rename = "crate")]
     pub crate_path: Option<Path>,
 }
@@ -339,10 +385,16 @@ pub struct Object {
     pub concretes: Vec<ConcreteType>,
     #[darling(default)]
     pub guard: Option<Expr>,
+    #[darling(default)]
+    pub guard_result: Option<Expr>,
+    #[darling(default)]
+    pub type_guard: Option<Expr>,
     #[darling(default, multiple, rename = "directive")]
     pub directives: Vec<Expr>,
     #[darling(default, multiple)]
     pub requires_scopes: Vec<String>,
+    #[darling(default)]
+    pub authenticated: bool,
     #[darling(rename = "crate")]
     pub crate_path: Option<Path>,
 }
@@ -352,6 +404,7 @@ pub struct Object {
 pub struct ObjectField {
     pub skip: bool,
     pub entity: bool,
+    pub batch: bool,
     pub name: Option<String>,
     pub deprecation: Deprecation,
     pub cache_control: CacheControl,
@@ -364,6 +417,8 @@ pub struct ObjectField {
     pub tags: Vec<String>,
     pub override_from: Option<String>,
     pub guard: Option<Expr>,
+    #[darling(default)]
+    pub guard_result: Option<Expr>,
     pub visible: Option<Visible>,
     pub complexity: Option<Expr>,
     #[darling(default, multiple)]
@@ -373,6 +428,12 @@ pub struct ObjectField {
     pub directives: Vec<Expr>,
     #[darling(default, multiple)]
     pub requires_scopes: Vec<String>,
+    #[darling(default)]
+    pub authenticated: bool,
+    #[darling(default)]
+    pub timeout: Option<Timeout>,
+    #[darling(default)]
+    pub max_concurrency: Option<NonZeroUsize>,
 }
 
 #[derive(FromMeta, Default, Clone)]
@@ -416,6 +477,8 @@ pub struct Enum {
     pub directives: Vec<Expr>,
     #[darling(default, multiple)]
     pub requires_scopes: Vec<String>,
+    #[darling(default)]
+    pub authenticated: bool,
     #[darling(rename = "crate")]
     pub crate_path: Option<Path>,
 }
@@ -461,6 +524,8 @@ pub struct Union {
     pub inaccessible: bool,
     #[darling(default, multiple, rename = "tag")]
     pub tags: Vec<String>,
+    #[darling(default)]
+    pub type_guard: Option<Expr>,
 
     #[darling(default, multiple, rename = "concrete")]
     pub concretes: Vec<ConcreteType>,
@@ -554,6 +619,8 @@ pub struct InputObject {
     pub concretes: Vec<ConcreteType>,
     #[darling(default)]
     pub validator: Option<Expr>,
+    #[darling(default, multiple, rename = "object_validator")]
+    pub object_validators: Vec<Expr>,
     // for SimpleObject
     #[darling(default)]
     pub complex: bool,
@@ -616,6 +683,8 @@ pub struct OneofObject {
     pub tags: Vec<String>,
     #[darling(default, multiple, rename = "concrete")]
     pub concretes: Vec<ConcreteType>,
+    #[darling(default, multiple, rename = "object_validator")]
+    pub object_validators: Vec<Expr>,
     #[darling(default, multiple, rename = "directive")]
     pub directives: Vec<Expr>,
     // for Interface
@@ -681,6 +750,8 @@ pub struct InterfaceField {
     pub directives: Vec<Expr>,
     #[darling(default, multiple)]
     pub requires_scopes: Vec<String>,
+    #[darling(default)]
+    pub authenticated: bool,
 }
 
 #[derive(FromVariant)]
@@ -717,6 +788,8 @@ pub struct Interface {
     pub inaccessible: bool,
     #[darling(default, multiple, rename = "tag")]
     pub tags: Vec<String>,
+    #[darling(default)]
+    pub type_guard: Option<Expr>,
     #[darling(default, multiple, rename = "directive")]
     pub directives: Vec<Expr>,
     // for OneofObject
@@ -724,6 +797,8 @@ pub struct Interface {
     pub input_name: Option<String>,
     #[darling(default, multiple)]
     pub requires_scopes: Vec<String>,
+    #[darling(default)]
+    pub authenticated: bool,
     #[darling(rename = "crate")]
     pub crate_path: Option<Path>,
 }
@@ -743,6 +818,8 @@ pub struct Scalar {
     pub specified_by_url: Option<String>,
     #[darling(default, multiple)]
     pub requires_scopes: Vec<String>,
+    #[darling(default)]
+    pub authenticated: bool,
     #[darling(rename = "crate")]
     pub crate_path: Option<Path>,
 }
@@ -793,6 +870,10 @@ pub struct SubscriptionField {
     pub complexity: Option<Expr>,
     #[darling(default, multiple, rename = "directive")]
     pub directives: Vec<Expr>,
+    #[darling(default, multiple)]
+    pub requires_scopes: Vec<String>,
+    #[darling(default)]
+    pub authenticated: bool,
 }
 
 #[derive(FromField)]
@@ -995,6 +1076,8 @@ pub struct ComplexObject {
     pub rename_fields: Option<RenameRule>,
     pub rename_args: Option<RenameRule>,
     pub guard: Option<Expr>,
+    #[darling(default)]
+    pub guard_result: Option<Expr>,
     #[darling(rename = "crate")]
     pub crate_path: Option<Path>,
 }
@@ -1015,6 +1098,8 @@ pub struct ComplexObjectField {
     pub tags: Vec<String>,
     pub override_from: Option<String>,
     pub guard: Option<Expr>,
+    #[darling(default)]
+    pub guard_result: Option<Expr>,
     pub visible: Option<Visible>,
     pub complexity: Option<Expr>,
     #[darling(multiple)]
@@ -1024,6 +1109,12 @@ pub struct ComplexObjectField {
     pub directives: Vec<Expr>,
     #[darling(default, multiple)]
     pub requires_scopes: Vec<String>,
+    #[darling(default)]
+    pub authenticated: bool,
+    #[darling(default)]
+    pub timeout: Option<Timeout>,
+    #[darling(default)]
+    pub max_concurrency: Option<NonZeroUsize>,
 }
 
 #[derive(FromMeta, Default)]
@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{num::NonZeroUsize, str::FromStr};
 
 use proc_macro::TokenStream;
 use proc_macro2::Ident;
@@ -13,9 +13,9 @@ use crate::{
     output_type::OutputType,
     utils::{
         GeneratorResult, extract_input_args, gen_boxed_trait, gen_deprecation, gen_directive_calls,
-        generate_default, generate_guards, get_cfg_attrs, get_crate_path, get_rustdoc,
-        get_type_path_and_name, parse_complexity_expr, parse_graphql_attrs, remove_graphql_attrs,
-        visible_fn,
+        generate_default, generate_guards, generate_result_guard, generate_type_guard,
+        get_cfg_attrs, get_crate_path, get_rustdoc, get_type_path_and_name, parse_complexity_expr,
+        parse_graphql_attrs, remove_graphql_attrs, visible_fn,
     },
 };
 
@@ -337,6 +337,10 @@ pub fn generate(
                         .push(quote!(arg.directive_invocations = ::std::vec![ #(#directives),* ];));
                 }
 
+                if let Some(validator) = validator.as_ref() {
+                    arg_sets.push(validator.create_constraints(&crate_name, quote!(arg)));
+                }
+
                 schema_args.push(quote! {
                     {
                         let mut arg = #crate_name::registry::MetaInputValue::new(
@@ -482,6 +486,20 @@ pub fn generate(
                 field_sets
                     .push(quote!(field.requires_scopes = ::std::vec![ #(#requires_scopes),* ];));
             }
+            if method_args.authenticated {
+                field_sets.push(quote!(field.authenticated = true;));
+            }
+            if let Some(args::Timeout(millis)) = method_args.timeout {
+                field_sets.push(quote! {
+                    field.timeout =
+                        ::std::option::Option::Some(::std::time::Duration::from_millis(#millis));
+                });
+            }
+            if let Some(max_concurrency) = method_args.max_concurrency.map(NonZeroUsize::get) {
+                field_sets.push(quote! {
+                    field.max_concurrency = ::std::num::NonZeroUsize::new(#max_concurrency);
+                });
+            }
 
             schema_fields.push(quote! {
                 #(#cfg_attrs)*
@@ -547,6 +565,11 @@ pub fn generate(
                 None => None,
             };
 
+            let result_guard = method_args
+                .guard_result
+                .as_ref()
+                .map(|code| generate_result_guard(&crate_name, code, quote!(&obj)));
+
             let resolve_block = if is_async {
                 quote! {
                     let f = async move {
@@ -555,6 +578,7 @@ pub fn generate(
                         #resolve_obj
                     };
                     let obj = f.await.map_err(|err| ctx.set_error_path(err))?;
+                    #result_guard
                     let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
                     return #crate_name::OutputType::resolve(&obj, &ctx_obj, ctx.item)
                         .await
@@ -565,6 +589,7 @@ pub fn generate(
                     #(#get_params)*
                     #guard
                     let obj = #resolve_obj.map_err(|err| ctx.set_error_path(err))?;
+                    #result_guard
                     return #crate_name::resolver_utils::resolve_simple_field_value(ctx, &obj).await;
                 }
             };
@@ -580,6 +605,19 @@ pub fn generate(
         }
     }
 
+    // The object guard is checked once by the `SimpleObject`, before its
+    // fields are resolved.
+    let check_type_guard_fn =
+        generate_type_guard(&crate_name, None, object_args.guard_result.as_ref(), None)
+            .0
+            .unwrap_or_else(|| {
+                quote! {
+                    async fn check_type_guard(&self, _: &#crate_name::Context<'_>) -> #crate_name::Result<#crate_name::GuardAction> {
+                        ::std::result::Result::Ok(#crate_name::GuardAction::Allow)
+                    }
+                }
+            });
+
     let expanded = quote! {
         #item_impl
 
@@ -596,6 +634,8 @@ pub fn generate(
                 #(#resolvers)*
                 ::std::result::Result::Ok(::std::option::Option::None)
             }
+
+            #check_type_guard_fn
         }
     };
 
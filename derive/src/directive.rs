@@ -109,6 +109,10 @@ pub fn generate(
             arg_sets.push(quote!(arg.directive_invocations = ::std::vec![ #(#directives),* ];));
         }
 
+        if let Some(validator) = validator.as_ref() {
+            arg_sets.push(validator.create_constraints(&crate_name, quote!(arg)));
+        }
+
         schema_args.push(quote! {
             {
                 let mut arg = #crate_name::registry::MetaInputValue::new(
@@ -233,6 +233,9 @@ pub fn generate(enum_args: &args::Enum) -> GeneratorResult<TokenStream> {
     if has_requires_scopes {
         enum_builder_calls.push(quote!(.requires_scopes(::std::vec![ #(#requires_scopes),* ])));
     }
+    if enum_args.authenticated {
+        enum_builder_calls.push(quote!(.authenticated(true)));
+    }
     let expanded = quote! {
         #[allow(clippy::all, clippy::pedantic)]
         impl #crate_name::resolver_utils::EnumType for #ident {
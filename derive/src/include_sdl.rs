@@ -0,0 +1,718 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use async_graphql_parser::{
+    parse_schema,
+    types::{
+        BaseType, ConstDirective, EnumType, FieldDefinition, InputObjectType, InputValueDefinition,
+        InterfaceType, ObjectType, Type, TypeDefinition, TypeKind, TypeSystemDefinition, UnionType,
+    },
+};
+use async_graphql_value::ConstValue;
+use heck::{ToPascalCase, ToSnakeCase};
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{Error, Ident, LitStr};
+
+use crate::utils::{GeneratorResult, get_crate_path};
+
+struct Roots {
+    query: String,
+    mutation: Option<String>,
+    subscription: Option<String>,
+}
+
+struct Generator<'a> {
+    crate_name: syn::Path,
+    path: &'a LitStr,
+    roots: Roots,
+    /// The objects implementing each interface.
+    implementations: HashMap<String, Vec<String>>,
+    /// The types defined in the SDL.
+    types: HashMap<&'a str, &'a TypeKind>,
+}
+
+fn to_ident(name: &str) -> Ident {
+    syn::parse_str::<Ident>(name).unwrap_or_else(|_| Ident::new_raw(name, Span::call_site()))
+}
+
+fn docs(description: Option<&str>) -> Vec<TokenStream2> {
+    description
+        .into_iter()
+        .flat_map(str::lines)
+        .map(|line| quote!(#[doc = #line]))
+        .collect()
+}
+
+fn deprecation(directives: &[async_graphql_parser::Positioned<ConstDirective>]) -> TokenStream2 {
+    let Some(directive) = directives
+        .iter()
+        .find(|directive| directive.node.name.node == "deprecated")
+    else {
+        return quote!();
+    };
+    match directive
+        .node
+        .get_argument("reason")
+        .map(|reason| &reason.node)
+    {
+        Some(ConstValue::String(reason)) => quote!(deprecation = #reason,),
+        _ => quote!(deprecation,),
+    }
+}
+
+impl Generator<'_> {
+    fn error(&self, message: impl std::fmt::Display) -> Error {
+        Error::new_spanned(self.path, message)
+    }
+
+    fn named_type(&self, name: &str) -> TokenStream2 {
+        let crate_name = &self.crate_name;
+        match name {
+            "Int" => quote!(::std::primitive::i32),
+            "Float" => quote!(::std::primitive::f64),
+            "String" => quote!(::std::string::String),
+            "Boolean" => quote!(::std::primitive::bool),
+            "ID" => quote!(#crate_name::ID),
+            _ => {
+                let ident = to_ident(name);
+                quote!(#ident)
+            }
+        }
+    }
+
+    fn rust_type(&self, ty: &Type) -> TokenStream2 {
+        let inner = match &ty.base {
+            BaseType::Named(name) => self.named_type(name),
+            BaseType::List(ty) => {
+                let ty = self.rust_type(ty);
+                quote!(::std::vec::Vec<#ty>)
+            }
+        };
+        if ty.nullable {
+            quote!(::std::option::Option<#inner>)
+        } else {
+            inner
+        }
+    }
+
+    fn value(&self, value: &ConstValue) -> TokenStream2 {
+        let crate_name = &self.crate_name;
+        match value {
+            ConstValue::Null | ConstValue::Binary(_) => quote!(#crate_name::Value::Null),
+            ConstValue::Number(n) => {
+                if let Some(n) = n.as_i64() {
+                    quote!(#crate_name::Value::from(#n))
+                } else if let Some(n) = n.as_u64() {
+                    quote!(#crate_name::Value::from(#n))
+                } else {
+                    let n = n.as_f64().unwrap_or_default();
+                    quote!(#crate_name::Value::from(#n))
+                }
+            }
+            ConstValue::String(s) => quote!(#crate_name::Value::from(#s)),
+            ConstValue::Boolean(b) => quote!(#crate_name::Value::from(#b)),
+            ConstValue::Enum(name) => {
+                let name = name.as_str();
+                quote!(#crate_name::Value::Enum(#crate_name::Name::new(#name)))
+            }
+            ConstValue::List(items) => {
+                let items = items.iter().map(|item| self.value(item));
+                quote!(#crate_name::Value::List(::std::vec![#(#items),*]))
+            }
+            ConstValue::Object(fields) => {
+                let fields = fields.iter().map(|(name, value)| {
+                    let name = name.as_str();
+                    let value = self.value(value);
+                    quote!(map.insert(#crate_name::Name::new(#name), #value);)
+                });
+                quote!(#crate_name::Value::Object({
+                    let mut map = #crate_name::indexmap::IndexMap::new();
+                    #(#fields)*
+                    map
+                }))
+            }
+        }
+    }
+
+    /// Checks that `value` is a valid value of `ty`.
+    ///
+    /// The values of custom scalars are only checked when the schema is built.
+    fn check_value(&self, ty: &Type, value: &ConstValue) -> Result<(), String> {
+        match (value, &ty.base) {
+            (ConstValue::Null, _) if ty.nullable => Ok(()),
+            (ConstValue::Null, _) => Err(format!("`null` is not a valid `{}`", ty)),
+            (ConstValue::List(items), BaseType::List(item_ty)) => items
+                .iter()
+                .try_for_each(|item| self.check_value(item_ty, item)),
+            // A single value is coerced to a list with one item.
+            (_, BaseType::List(item_ty)) => self.check_value(item_ty, value),
+            (_, BaseType::Named(name)) => self.check_named_value(name, value),
+        }
+    }
+
+    fn check_named_value(&self, name: &str, value: &ConstValue) -> Result<(), String> {
+        let valid = match (name, value) {
+            ("Int", ConstValue::Number(n)) => n.as_i64().is_some_and(|n| i32::try_from(n).is_ok()),
+            ("Float", ConstValue::Number(_))
+            | ("String" | "ID", ConstValue::String(_))
+            | ("Boolean", ConstValue::Boolean(_)) => true,
+            ("ID", ConstValue::Number(n)) => n.is_i64() || n.is_u64(),
+            ("Int" | "Float" | "String" | "ID" | "Boolean", _) => false,
+            _ => match (self.types.get(name), value) {
+                (Some(TypeKind::Enum(enum_type)), ConstValue::Enum(value)) => enum_type
+                    .values
+                    .iter()
+                    .any(|enum_value| enum_value.node.value.node == *value),
+                (Some(TypeKind::Enum(_)), _) => false,
+                (Some(TypeKind::InputObject(input_object)), ConstValue::Object(fields)) => {
+                    for (field_name, field_value) in fields {
+                        let field = input_object
+                            .fields
+                            .iter()
+                            .find(|field| field.node.name.node == *field_name)
+                            .ok_or_else(|| format!("`{}` has no field `{}`", name, field_name))?;
+                        self.check_value(&field.node.ty.node, field_value)?;
+                    }
+                    for field in &input_object.fields {
+                        let field = &field.node;
+                        if !field.ty.node.nullable
+                            && field.default_value.is_none()
+                            && !fields.contains_key(&field.name.node)
+                        {
+                            return Err(format!(
+                                "the field `{}` of `{}` is missing",
+                                field.name.node, name
+                            ));
+                        }
+                    }
+                    true
+                }
+                (Some(TypeKind::InputObject(_)), _) => false,
+                _ => true,
+            },
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(format!("`{}` is not a valid `{}`", value, name))
+        }
+    }
+
+    /// Returns the `default_with` attribute of an input value.
+    fn default_with(&self, input_value: &InputValueDefinition) -> Result<TokenStream2, Error> {
+        let Some(default_value) = &input_value.default_value else {
+            return Ok(quote!());
+        };
+        self.check_value(&input_value.ty.node, &default_value.node)
+            .map_err(|err| {
+                self.error(format!(
+                    "Invalid default value of `{}`: {}.",
+                    input_value.name.node, err
+                ))
+            })?;
+        let crate_name = &self.crate_name;
+        let ty = self.rust_type(&input_value.ty.node);
+        let value = self.value(&default_value.node);
+        let message = format!("invalid default value of `{}`", input_value.name.node);
+        let expr = quote! {
+            match <#ty as #crate_name::InputType>::parse(::std::option::Option::Some(#value)) {
+                ::std::result::Result::Ok(value) => value,
+                ::std::result::Result::Err(_) => ::std::panic!(#message),
+            }
+        }
+        .to_string();
+        Ok(quote!(default_with = #expr,))
+    }
+
+    fn generate_arguments(
+        &self,
+        arguments: &[async_graphql_parser::Positioned<InputValueDefinition>],
+    ) -> Result<(Vec<TokenStream2>, Vec<Ident>), Error> {
+        let mut decl_args = Vec::new();
+        let mut use_args = Vec::new();
+        for argument in arguments {
+            let argument = &argument.node;
+            let name = argument.name.node.as_str();
+            let arg_ident = to_ident(&name.to_snake_case());
+            let ty = self.rust_type(&argument.ty.node);
+            let default_with = self.default_with(argument)?;
+            let desc = argument
+                .description
+                .as_ref()
+                .map(|desc| {
+                    let desc = &desc.node;
+                    quote!(desc = #desc,)
+                })
+                .unwrap_or_default();
+            let deprecation = deprecation(&argument.directives);
+            decl_args.push(quote! {
+                #[graphql(name = #name, #default_with #desc #deprecation)] #arg_ident: #ty
+            });
+            use_args.push(arg_ident);
+        }
+        Ok((decl_args, use_args))
+    }
+
+    fn generate_object(
+        &self,
+        def: &TypeDefinition,
+        object: &ObjectType,
+    ) -> GeneratorResult<TokenStream2> {
+        let crate_name = &self.crate_name;
+        let name = def.name.node.as_str();
+        let ident = to_ident(name);
+        let trait_ident = to_ident(&format!("{}Resolver", name));
+        let type_docs = docs(def.description.as_ref().map(|desc| desc.node.as_str()));
+        let is_subscription = self.roots.subscription.as_deref() == Some(name);
+
+        let mut trait_methods = Vec::new();
+        let mut resolvers = Vec::new();
+        for field in &object.fields {
+            let FieldDefinition {
+                description,
+                name: field_name,
+                arguments,
+                ty,
+                directives,
+            } = &field.node;
+            let field_name = field_name.node.as_str();
+            let method = to_ident(&field_name.to_snake_case());
+            let field_docs = docs(description.as_ref().map(|desc| desc.node.as_str()));
+            let deprecation = deprecation(directives);
+            let (decl_args, use_args) = self.generate_arguments(arguments)?;
+            let trait_args =
+                decl_args
+                    .iter()
+                    .zip(&use_args)
+                    .zip(arguments)
+                    .map(|((_, arg_ident), argument)| {
+                        let ty = self.rust_type(&argument.node.ty.node);
+                        quote!(#arg_ident: #ty)
+                    });
+            let mut ty = self.rust_type(&ty.node);
+            if is_subscription {
+                ty = quote!(#crate_name::futures_util::stream::BoxStream<'static, #ty>);
+            }
+
+            trait_methods.push(quote! {
+                #(#field_docs)*
+                async fn #method(
+                    &self,
+                    ctx: &#crate_name::Context<'_>,
+                    #(#trait_args),*
+                ) -> #crate_name::Result<#ty>;
+            });
+            resolvers.push(quote! {
+                #(#field_docs)*
+                #[graphql(name = #field_name, #deprecation)]
+                async fn #method(
+                    &self,
+                    ctx: &#crate_name::Context<'_>,
+                    #(#decl_args),*
+                ) -> #crate_name::Result<#ty> {
+                    self.0.#method(ctx, #(#use_args),*).await
+                }
+            });
+        }
+
+        let trait_doc = format!(
+            "The resolvers of the `{}` type, used with [`{}::new`].",
+            name, name
+        );
+        let macro_attr = if is_subscription {
+            quote!(#[#crate_name::Subscription(name = #name)])
+        } else {
+            quote!(#[#crate_name::Object(name = #name)])
+        };
+
+        Ok(quote! {
+            #[doc = #trait_doc]
+            #[#crate_name::async_trait::async_trait]
+            pub trait #trait_ident: ::std::marker::Send + ::std::marker::Sync {
+                #(#trait_methods)*
+            }
+
+            #(#type_docs)*
+            pub struct #ident(::std::boxed::Box<dyn #trait_ident>);
+
+            impl #ident {
+                /// Create the GraphQL object from its resolvers.
+                pub fn new(resolver: impl #trait_ident + 'static) -> Self {
+                    Self(::std::boxed::Box::new(resolver))
+                }
+            }
+
+            #(#type_docs)*
+            #macro_attr
+            impl #ident {
+                #(#resolvers)*
+            }
+        })
+    }
+
+    fn generate_interface(
+        &self,
+        def: &TypeDefinition,
+        interface: &InterfaceType,
+    ) -> GeneratorResult<TokenStream2> {
+        let crate_name = &self.crate_name;
+        let name = def.name.node.as_str();
+        let ident = to_ident(name);
+        let type_docs = docs(def.description.as_ref().map(|desc| desc.node.as_str()));
+
+        if !interface.implements.is_empty() {
+            return Err(self
+                .error(format!(
+                    "Interface `{}` implements other interfaces, which is not supported.",
+                    name
+                ))
+                .into());
+        }
+
+        let implementations = self
+            .implementations
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        if implementations.is_empty() {
+            return Err(self
+                .error(format!(
+                    "Interface `{}` is not implemented by any object.",
+                    name
+                ))
+                .into());
+        }
+        let variants = implementations.iter().map(|object| {
+            let object = to_ident(object);
+            quote!(#object(#object))
+        });
+
+        let mut fields = Vec::new();
+        for field in &interface.fields {
+            let field = &field.node;
+            let field_name = field.name.node.as_str();
+            let method = field_name.to_snake_case();
+            let ty = self.rust_type(&field.ty.node).to_string();
+            let desc = field
+                .description
+                .as_ref()
+                .map(|desc| {
+                    let desc = &desc.node;
+                    quote!(desc = #desc,)
+                })
+                .unwrap_or_default();
+            let deprecation = deprecation(&field.directives);
+            let args = field
+                .arguments
+                .iter()
+                .map(|argument| {
+                    let argument = &argument.node;
+                    let name = argument.name.node.as_str();
+                    let ty = self.rust_type(&argument.ty.node).to_string();
+                    let default_with = self.default_with(argument)?;
+                    let desc = argument
+                        .description
+                        .as_ref()
+                        .map(|desc| {
+                            let desc = &desc.node;
+                            quote!(desc = #desc,)
+                        })
+                        .unwrap_or_default();
+                    Ok::<_, Error>(quote!(arg(name = #name, ty = #ty, #default_with #desc)))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            fields.push(quote! {
+                field(name = #field_name, method = #method, ty = #ty, #desc #deprecation #(#args),*)
+            });
+        }
+
+        Ok(quote! {
+            #(#type_docs)*
+            #[derive(#crate_name::Interface)]
+            #[graphql(name = #name, #(#fields),*)]
+            pub enum #ident {
+                #(#variants),*
+            }
+        })
+    }
+
+    fn generate_union(
+        &self,
+        def: &TypeDefinition,
+        union: &UnionType,
+    ) -> GeneratorResult<TokenStream2> {
+        let crate_name = &self.crate_name;
+        let name = def.name.node.as_str();
+        let ident = to_ident(name);
+        let type_docs = docs(def.description.as_ref().map(|desc| desc.node.as_str()));
+        let variants = union.members.iter().map(|member| {
+            let member = to_ident(&member.node);
+            quote!(#member(#member))
+        });
+
+        Ok(quote! {
+            #(#type_docs)*
+            #[derive(#crate_name::Union)]
+            #[graphql(name = #name)]
+            pub enum #ident {
+                #(#variants),*
+            }
+        })
+    }
+
+    fn generate_enum(&self, def: &TypeDefinition, ty: &EnumType) -> GeneratorResult<TokenStream2> {
+        let crate_name = &self.crate_name;
+        let name = def.name.node.as_str();
+        let ident = to_ident(name);
+        let type_docs = docs(def.description.as_ref().map(|desc| desc.node.as_str()));
+        let items = ty.values.iter().map(|value| {
+            let value = &value.node;
+            let item_name = value.value.node.as_str();
+            let item = to_ident(&item_name.to_lowercase().to_pascal_case());
+            let item_docs = docs(value.description.as_ref().map(|desc| desc.node.as_str()));
+            let deprecation = deprecation(&value.directives);
+            quote! {
+                #(#item_docs)*
+                #[graphql(name = #item_name, #deprecation)]
+                #item
+            }
+        });
+
+        Ok(quote! {
+            #(#type_docs)*
+            #[derive(#crate_name::Enum, ::std::fmt::Debug, ::std::marker::Copy, ::std::clone::Clone, ::std::cmp::Eq, ::std::cmp::PartialEq)]
+            #[graphql(name = #name)]
+            pub enum #ident {
+                #(#items),*
+            }
+        })
+    }
+
+    fn generate_input_object(
+        &self,
+        def: &TypeDefinition,
+        input_object: &InputObjectType,
+    ) -> GeneratorResult<TokenStream2> {
+        let crate_name = &self.crate_name;
+        let name = def.name.node.as_str();
+        let ident = to_ident(name);
+        let type_docs = docs(def.description.as_ref().map(|desc| desc.node.as_str()));
+        let is_oneof = def
+            .directives
+            .iter()
+            .any(|directive| directive.node.name.node == "oneOf");
+
+        if is_oneof {
+            let mut variants = Vec::new();
+            for field in &input_object.fields {
+                let field = &field.node;
+                let field_name = field.name.node.as_str();
+                if !field.ty.node.nullable {
+                    return Err(self
+                        .error(format!(
+                            "The field `{}` of the oneof input object `{}` must be nullable.",
+                            field_name, name
+                        ))
+                        .into());
+                }
+                let variant = to_ident(&field_name.to_pascal_case());
+                let ty = self.rust_type(&Type {
+                    base: field.ty.node.base.clone(),
+                    nullable: false,
+                });
+                let field_docs = docs(field.description.as_ref().map(|desc| desc.node.as_str()));
+                variants.push(quote! {
+                    #(#field_docs)*
+                    #[graphql(name = #field_name)]
+                    #variant(#ty)
+                });
+            }
+
+            return Ok(quote! {
+                #(#type_docs)*
+                #[derive(#crate_name::OneofObject)]
+                #[graphql(name = #name)]
+                pub enum #ident {
+                    #(#variants),*
+                }
+            });
+        }
+
+        let fields = input_object
+            .fields
+            .iter()
+            .map(|field| {
+                let field = &field.node;
+                let field_name = field.name.node.as_str();
+                let field_ident = to_ident(&field_name.to_snake_case());
+                let ty = self.rust_type(&field.ty.node);
+                let field_docs = docs(field.description.as_ref().map(|desc| desc.node.as_str()));
+                let default_with = self.default_with(field)?;
+                let deprecation = deprecation(&field.directives);
+                Ok::<_, Error>(quote! {
+                    #(#field_docs)*
+                    #[graphql(name = #field_name, #default_with #deprecation)]
+                    pub #field_ident: #ty
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(quote! {
+            #(#type_docs)*
+            #[derive(#crate_name::InputObject)]
+            #[graphql(name = #name)]
+            pub struct #ident {
+                #(#fields),*
+            }
+        })
+    }
+}
+
+pub fn generate(path: &LitStr) -> GeneratorResult<TokenStream> {
+    let crate_name = get_crate_path(&None, false);
+    let full_path = std::env::var("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default()
+        .join(path.value());
+    let source = std::fs::read_to_string(&full_path).map_err(|err| {
+        Error::new_spanned(
+            path,
+            format!("Failed to read `{}`: {}", full_path.display(), err),
+        )
+    })?;
+    let doc = parse_schema(&source)
+        .map_err(|err| Error::new_spanned(path, format!("Invalid SDL: {}", err)))?;
+
+    let mut schema = None;
+    let mut types = Vec::new();
+    for definition in doc.definitions {
+        match definition {
+            TypeSystemDefinition::Schema(def) if def.node.extend => {
+                return Err(
+                    Error::new_spanned(path, "Schema extensions are not supported.").into(),
+                );
+            }
+            TypeSystemDefinition::Schema(def) => schema = Some(def.node),
+            TypeSystemDefinition::Type(def) if def.node.extend => {
+                return Err(Error::new_spanned(
+                    path,
+                    format!(
+                        "The extension of `{}` is not supported.",
+                        def.node.name.node
+                    ),
+                )
+                .into());
+            }
+            TypeSystemDefinition::Type(def) => types.push(def.node),
+            // Directives are implemented with `#[Directive]` and `#[TypeDirective]`.
+            TypeSystemDefinition::Directive(_) => {}
+        }
+    }
+
+    let type_exists = |name: &str| types.iter().any(|ty| ty.name.node == name);
+    let roots = match schema {
+        Some(schema) => Roots {
+            query: schema
+                .query
+                .map(|name| name.node.to_string())
+                .unwrap_or_else(|| "Query".to_string()),
+            mutation: schema.mutation.map(|name| name.node.to_string()),
+            subscription: schema.subscription.map(|name| name.node.to_string()),
+        },
+        None => Roots {
+            query: "Query".to_string(),
+            mutation: Some("Mutation".to_string()).filter(|name| type_exists(name)),
+            subscription: Some("Subscription".to_string()).filter(|name| type_exists(name)),
+        },
+    };
+    if !type_exists(&roots.query) {
+        return Err(Error::new_spanned(
+            path,
+            format!("The query root `{}` is not defined.", roots.query),
+        )
+        .into());
+    }
+
+    let mut implementations: HashMap<String, Vec<String>> = HashMap::new();
+    for ty in &types {
+        if let TypeKind::Object(object) = &ty.kind {
+            for interface in &object.implements {
+                implementations
+                    .entry(interface.node.to_string())
+                    .or_default()
+                    .push(ty.name.node.to_string());
+            }
+        }
+    }
+
+    let generator = Generator {
+        crate_name,
+        path,
+        roots,
+        implementations,
+        types: types
+            .iter()
+            .map(|ty| (ty.name.node.as_str(), &ty.kind))
+            .collect(),
+    };
+    let mut items = Vec::new();
+    for ty in &types {
+        items.push(match &ty.kind {
+            // Custom scalars are the Rust types with the same name in scope.
+            TypeKind::Scalar => continue,
+            TypeKind::Object(object) => generator.generate_object(ty, object)?,
+            TypeKind::Interface(interface) => generator.generate_interface(ty, interface)?,
+            TypeKind::Union(union) => generator.generate_union(ty, union)?,
+            TypeKind::Enum(enum_type) => generator.generate_enum(ty, enum_type)?,
+            TypeKind::InputObject(input_object) => {
+                generator.generate_input_object(ty, input_object)?
+            }
+        });
+    }
+
+    let crate_name = &generator.crate_name;
+    let query = to_ident(&generator.roots.query);
+    let mutation = match &generator.roots.mutation {
+        Some(mutation) => {
+            let mutation = to_ident(mutation);
+            quote!(#mutation)
+        }
+        None => quote!(#crate_name::EmptyMutation),
+    };
+    let subscription = match &generator.roots.subscription {
+        Some(subscription) => {
+            let subscription = to_ident(subscription);
+            quote!(#subscription)
+        }
+        None => quote!(#crate_name::EmptySubscription),
+    };
+    let full_path = full_path.display().to_string();
+    // Named after the path, so that several files can be included in a module.
+    let test_ident = to_ident(&format!(
+        "sdl_matches_{}",
+        path.value()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>()
+            .to_snake_case()
+    ));
+
+    Ok(quote! {
+        #(#items)*
+
+        // Rebuild when the SDL file changes.
+        const _: &str = ::std::include_str!(#full_path);
+
+        #[cfg(test)]
+        #[test]
+        fn #test_ident() {
+            if let ::std::result::Result::Err(err) = #crate_name::resolver_utils::check_sdl::<#query, #mutation, #subscription>(
+                ::std::include_str!(#full_path),
+            ) {
+                ::std::panic!("{}", err);
+            }
+        }
+    }
+    .into())
+}
@@ -6,8 +6,8 @@ use syn::{Error, ext::IdentExt};
 use crate::{
     args::{self, RenameRuleExt, RenameTarget, TypeDirectiveLocation},
     utils::{
-        GeneratorResult, gen_deprecation, gen_directive_calls, generate_default, get_crate_path,
-        get_rustdoc, visible_fn,
+        GeneratorResult, gen_deprecation, gen_directive_calls, gen_object_validators,
+        generate_default, get_crate_path, get_rustdoc, visible_fn,
     },
 };
 
@@ -244,6 +244,10 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
             );
         }
 
+        if let Some(validator) = &field.validator {
+            input_sets.push(validator.create_constraints(&crate_name, quote!(input_value)));
+        }
+
         schema_fields.push(quote! {
             {
                 let mut input_value = #crate_name::registry::MetaInputValue::new(
@@ -287,6 +291,8 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
         .validator
         .as_ref()
         .map(|expr| quote! { #crate_name::CustomValidator::check(&#expr, &obj)?; });
+    let object_validators =
+        gen_object_validators(&crate_name, &object_args.object_validators, quote!(obj));
 
     let mut input_object_builder = Vec::new();
     input_object_builder.push(quote!(.rust_typename(::std::any::type_name::<Self>())));
@@ -336,6 +342,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
                         #(#get_fields)*
                         let obj = Self { #(#fields),* };
                         #obj_validator
+                        #object_validators
                         ::std::result::Result::Ok(obj)
                     } else {
                         ::std::result::Result::Err(#crate_name::InputValueError::expected_type(value.unwrap_or_default()))
@@ -385,6 +392,7 @@ pub fn generate(object_args: &args::InputObject) -> GeneratorResult<TokenStream>
                         #(#get_fields)*
                         let obj = Self { #(#fields),* };
                         #obj_validator
+                        #object_validators
                         ::std::result::Result::Ok(obj)
                     } else {
                         ::std::result::Result::Err(#crate_name::InputValueError::expected_type(value.unwrap_or_default()))
@@ -14,7 +14,7 @@ use crate::{
     output_type::OutputType,
     utils::{
         GeneratorResult, RemoveLifetime, gen_boxed_trait, gen_deprecation, gen_directive_calls,
-        generate_default, get_crate_path, get_rustdoc, visible_fn,
+        generate_default, generate_type_guard, get_crate_path, get_rustdoc, visible_fn,
     },
 };
 
@@ -73,6 +73,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
     let mut enum_items = HashSet::new();
     let mut type_into_impls = Vec::new();
     let inaccessible = interface_args.inaccessible;
+    let authenticated = interface_args.authenticated;
     let tags = interface_args
         .tags
         .iter()
@@ -116,6 +117,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
     let mut possible_types = Vec::new();
     let mut get_introspection_typename = Vec::new();
     let mut collect_all_fields = Vec::new();
+    let mut check_type_guards = Vec::new();
 
     for variant in s {
         let enum_name = &variant.ident;
@@ -181,6 +183,10 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
             collect_all_fields.push(quote! {
                 #ident::#enum_name(obj) => obj.collect_all_fields(ctx, fields)
             });
+
+            check_type_guards.push(quote! {
+                #ident::#enum_name(obj) => #crate_name::resolver_utils::boxed_check_type_guard(obj, ctx)
+            });
         } else {
             return Err(Error::new_spanned(ty, "Invalid type").into());
         }
@@ -215,6 +221,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
         override_from,
         directives,
         requires_scopes,
+        authenticated,
     } in &interface_args.fields
     {
         let (name, method_name) = if let Some(method) = method {
@@ -449,6 +456,9 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
         if has_requires_scopes {
             field_sets.push(quote!(field.requires_scopes = ::std::vec![ #(#requires_scopes),* ];));
         }
+        if *authenticated {
+            field_sets.push(quote!(field.authenticated = true;));
+        }
 
         schema_fields.push(quote! {
             let mut field = #crate_name::registry::MetaField::new(
@@ -487,6 +497,18 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
 
     let visible = visible_fn(&interface_args.visible);
     let field_count = schema_fields.len();
+    let (check_type_guard_fn, check_type_guard) = generate_type_guard(
+        &crate_name,
+        interface_args.type_guard.as_ref(),
+        None,
+        Some(quote! {
+            match self {
+                #(#check_type_guards),*
+            }
+            .await
+        }),
+    );
+
     let expanded = quote! {
         #(#type_into_impls)*
 
@@ -508,6 +530,8 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
                     #(#collect_all_fields),*
                 }
             }
+
+            #check_type_guard_fn
         }
 
         #[allow(clippy::all, clippy::pedantic)]
@@ -546,6 +570,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
                         rust_typename: ::std::option::Option::Some(::std::any::type_name::<Self>()),
                         directive_invocations: ::std::vec![ #(#directives),* ],
                         requires_scopes: ::std::vec![ #(#requires_scopes),* ],
+                        authenticated: #authenticated,
                     }
                 })
             }
@@ -555,6 +580,7 @@ pub fn generate(interface_args: &args::Interface) -> GeneratorResult<TokenStream
                 ctx: &#crate_name::ContextSelectionSet<'_>,
                 _field: &#crate_name::Positioned<#crate_name::parser::types::Field>,
             ) -> #crate_name::ServerResult<#crate_name::Value> {
+                #check_type_guard
                 #crate_name::resolver_utils::resolve_container(ctx, self).await
             }
         }
@@ -10,6 +10,7 @@ mod complex_object;
 mod description;
 mod directive;
 mod r#enum;
+mod include_sdl;
 mod input_object;
 mod interface;
 mod merged_object;
@@ -28,7 +29,7 @@ mod validators;
 
 use darling::{FromDeriveInput, FromMeta};
 use proc_macro::TokenStream;
-use syn::{DeriveInput, ItemFn, ItemImpl, parse_macro_input};
+use syn::{DeriveInput, ItemFn, ItemImpl, LitStr, parse_macro_input};
 
 macro_rules! parse_nested_meta {
     ($ty:ty, $args:expr) => {{
@@ -243,3 +244,12 @@ pub fn derive_oneof_object(input: TokenStream) -> TokenStream {
         Err(err) => err.write_errors().into(),
     }
 }
+
+#[proc_macro]
+pub fn include_sdl(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+    match include_sdl::generate(&path) {
+        Ok(expanded) => expanded,
+        Err(err) => err.write_errors().into(),
+    }
+}
@@ -134,6 +134,19 @@ pub fn generate(object_args: &args::MergedObject) -> GeneratorResult<TokenStream
         quote! { #(#checks)* }
     };
 
+    let flat_find_entities = {
+        let calls: Vec<_> = (0..types.len())
+            .rev()
+            .map(|i| {
+                let n = LitInt::new(&format!("{}", i), Span::call_site());
+                quote! {
+                    self.#n.find_entities(ctx, representations, entities).await;
+                }
+            })
+            .collect();
+        quote! { #(#calls)* }
+    };
+
     let visible = visible_fn(&object_args.visible);
     let resolve_container = if object_args.serial {
         quote! { #crate_name::resolver_utils::resolve_container_serial(ctx, self).await }
@@ -154,6 +167,15 @@ pub fn generate(object_args: &args::MergedObject) -> GeneratorResult<TokenStream
                 #flat_find_entity
                 ::std::result::Result::Ok(::std::option::Option::None)
             }
+
+            async fn find_entities(
+                &self,
+                ctx: &#crate_name::Context<'_>,
+                representations: &[#crate_name::Value],
+                entities: &mut [::std::option::Option<#crate_name::ServerResult<#crate_name::Value>>],
+            ) {
+                #flat_find_entities
+            }
         }
 
         #[allow(clippy::all, clippy::pedantic)]
@@ -194,6 +216,7 @@ pub fn generate(object_args: &args::MergedObject) -> GeneratorResult<TokenStream
                         rust_typename: ::std::option::Option::Some(::std::any::type_name::<Self>()),
                         directive_invocations: ::std::vec![ #(#directives),* ],
                         requires_scopes: ::std::vec![],
+                        authenticated: false,
                     }
                 })
             }
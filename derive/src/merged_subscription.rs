@@ -99,6 +99,7 @@ pub fn generate(object_args: &args::MergedSubscription) -> GeneratorResult<Token
                         rust_typename: ::std::option::Option::Some(::std::any::type_name::<Self>()),
                         directive_invocations: ::std::default::Default::default(),
                         requires_scopes: ::std::default::Default::default(),
+                        authenticated: false,
                     }
                 })
             }
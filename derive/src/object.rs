@@ -1,8 +1,8 @@
-use std::str::FromStr;
+use std::{num::NonZeroUsize, str::FromStr};
 
 use proc_macro::TokenStream;
 use proc_macro2::Ident;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{
     Block, Error, Expr, FnArg, ImplItem, ItemImpl, Pat, PatIdent, ReturnType, Token, Type,
     TypeReference, ext::IdentExt, punctuated::Punctuated,
@@ -13,9 +13,9 @@ use crate::{
     output_type::OutputType,
     utils::{
         GeneratorResult, extract_input_args, gen_boxed_trait, gen_deprecation, gen_directive_calls,
-        generate_default, generate_guards, get_cfg_attrs, get_crate_path, get_rustdoc,
-        get_type_path_and_name, parse_complexity_expr, parse_graphql_attrs, remove_graphql_attrs,
-        visible_fn,
+        generate_default, generate_guards, generate_result_guard, generate_type_guard,
+        get_cfg_attrs, get_crate_path, get_rustdoc, get_type_path_and_name, get_vec_element_type,
+        parse_complexity_expr, parse_graphql_attrs, remove_graphql_attrs, visible_fn,
     },
     validators::Validators,
 };
@@ -86,6 +86,7 @@ pub fn generate(
     let mut resolvers = Vec::new();
     let mut schema_fields = Vec::new();
     let mut find_entities = Vec::new();
+    let mut find_entities_batch = Vec::new();
     let mut add_keys = Vec::new();
     let mut create_entity_types = Vec::new();
 
@@ -172,6 +173,12 @@ pub fn generate(
             let method_args: args::ObjectField =
                 parse_graphql_attrs(&method.attrs)?.unwrap_or_default();
 
+            if method_args.batch && !method_args.entity {
+                return Err(
+                    Error::new_spanned(method, "`batch` can only be used with `entity`").into(),
+                );
+            }
+
             if method_args.entity {
                 let cfg_attrs = get_cfg_attrs(&method.attrs);
 
@@ -193,12 +200,18 @@ pub fn generate(
                     }
                 };
 
-                let entity_type = ty.value_type();
+                let entity_type = if method_args.batch {
+                    get_vec_element_type(&ty.value_type())?.clone()
+                } else {
+                    ty.value_type()
+                };
                 let mut key_pat = Vec::new();
                 let mut key_getter = Vec::new();
                 let mut use_keys = Vec::new();
                 let mut get_federation_key = Vec::new();
                 let mut requires_getter = Vec::new();
+                let mut batch_key_pat = Vec::new();
+                let mut batch_requires_getter = Vec::new();
                 let all_key = args.iter().all(|(_, _, arg)| !arg.key);
 
                 if args.is_empty() {
@@ -210,6 +223,11 @@ pub fn generate(
                 }
 
                 for (ident, ty, args::Argument { name, key, .. }) in &args {
+                    let ty = if method_args.batch {
+                        get_vec_element_type(ty)?
+                    } else {
+                        ty
+                    };
                     let is_key = all_key || *key;
                     let name = name.clone().unwrap_or_else(|| {
                         object_args
@@ -229,6 +247,10 @@ pub fn generate(
                         key_pat.push(quote! {
                             ::std::option::Option::Some(#ident)
                         });
+                        let item_ident = format_ident!("__item_{}", ident.ident);
+                        batch_key_pat.push(quote! {
+                            ::std::option::Option::Some(#item_ident)
+                        });
                         key_getter.push(quote! {
                             params.get(#name).and_then(|value| {
                                 let value: ::std::option::Option<#ty> = #crate_name::InputType::parse(::std::option::Option::Some(::std::clone::Clone::clone(&value))).ok();
@@ -241,6 +263,11 @@ pub fn generate(
                             let #ident: #ty = #crate_name::InputType::parse(params.get(#name).cloned()).
                                 map_err(|err| err.into_server_error(ctx.item.pos))?;
                         });
+                        let item_ident = format_ident!("__item_{}", ident.ident);
+                        batch_requires_getter.push(quote! {
+                            let #item_ident: #ty = #crate_name::InputType::parse(params.get(#name).cloned()).
+                                map_err(|err| err.into_server_error(ctx.item.pos))?;
+                        });
                     }
                     use_keys.push(ident);
                 }
@@ -270,29 +297,91 @@ pub fn generate(
                         syn::parse2::<ReturnType>(quote! { -> #crate_name::Result<#inner_ty> })
                             .expect("invalid result type");
                 }
-                let do_find = quote! {
-                    self.#field_ident(ctx, #(#use_keys),*)
-                        .await.map_err(|err| ::std::convert::Into::<#crate_name::Error>::into(err)
-                        .into_server_error(ctx.item.pos))
-                };
 
-                find_entities.push((
-                    args.len(),
-                    quote! {
-                        #(#cfg_attrs)*
-                        if typename == &<#entity_type as #crate_name::OutputType>::type_name() {
-                            if let (#(#key_pat),*) = (#(#key_getter),*) {
-                                let f = async move {
-                                    #(#requires_getter)*
-                                    #do_find
-                                };
-                                let obj = f.await.map_err(|err| ctx.set_error_path(err))?;
-                                let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
-                                return #crate_name::OutputType::resolve(&obj, &ctx_obj, ctx.item).await.map(::std::option::Option::Some);
+                if method_args.batch {
+                    // Each argument of a batch entity resolver is a column, the
+                    // values at the same position belong to the same representation.
+                    let item_idents = use_keys
+                        .iter()
+                        .map(|ident| format_ident!("__item_{}", ident.ident))
+                        .collect::<Vec<_>>();
+                    let item_tys = args
+                        .iter()
+                        .map(|(_, ty, _)| get_vec_element_type(ty))
+                        .collect::<GeneratorResult<Vec<_>>>()?;
+                    let key_pat = batch_key_pat;
+                    let requires_getter = batch_requires_getter;
+
+                    find_entities_batch.push((
+                        args.len(),
+                        quote! {
+                            #(#cfg_attrs)*
+                            {
+                                let mut __indexes = ::std::vec::Vec::new();
+                                #(let mut #use_keys: ::std::vec::Vec<#item_tys> = ::std::vec::Vec::new();)*
+                                for (__idx, __params) in representations.iter().enumerate() {
+                                    if entities[__idx].is_some() {
+                                        continue;
+                                    }
+                                    if let ::std::result::Result::Ok(::std::option::Option::Some((params, typename))) =
+                                        #crate_name::resolver_utils::find_entity_params(ctx, __params)
+                                    {
+                                        if typename == &<#entity_type as #crate_name::OutputType>::type_name() {
+                                            if let (#(#key_pat),*) = (#(#key_getter),*) {
+                                                let __item = (|| -> #crate_name::ServerResult<_> {
+                                                    #(#requires_getter)*
+                                                    ::std::result::Result::Ok((#(#item_idents,)*))
+                                                })();
+                                                match __item {
+                                                    ::std::result::Result::Ok((#(#item_idents,)*)) => {
+                                                        __indexes.push(__idx);
+                                                        #(#use_keys.push(#item_idents);)*
+                                                    }
+                                                    ::std::result::Result::Err(err) => {
+                                                        entities[__idx] = ::std::option::Option::Some(
+                                                            ::std::result::Result::Err(ctx.with_index(__idx).set_error_path(err)),
+                                                        );
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                                #crate_name::resolver_utils::resolve_entity_batch(
+                                    ctx,
+                                    entities,
+                                    __indexes,
+                                    self.#field_ident(ctx, #(#use_keys),*),
+                                )
+                                .await;
                             }
-                        }
-                    },
-                ));
+                        },
+                    ));
+                } else {
+                    let do_find = quote! {
+                        self.#field_ident(ctx, #(#use_keys),*)
+                            .await.map_err(|err| ::std::convert::Into::<#crate_name::Error>::into(err)
+                            .into_server_error(ctx.item.pos))
+                    };
+
+                    find_entities.push((
+                        args.len(),
+                        quote! {
+                            #(#cfg_attrs)*
+                            if typename == &<#entity_type as #crate_name::OutputType>::type_name() {
+                                if let (#(#key_pat),*) = (#(#key_getter),*) {
+                                    let f = async move {
+                                        #(#requires_getter)*
+                                        #do_find
+                                    };
+                                    let obj = f.await.map_err(|err| ctx.set_error_path(err))?;
+                                    let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+                                    return #crate_name::OutputType::resolve(&obj, &ctx_obj, ctx.item).await.map(::std::option::Option::Some);
+                                }
+                            }
+                        },
+                    ));
+                }
             } else if !method_args.skip {
                 let is_async = method.sig.asyncness.is_some();
                 let cfg_attrs = get_cfg_attrs(&method.attrs);
@@ -515,6 +604,10 @@ pub fn generate(
                         );
                     }
 
+                    if let Some(validator) = validator.as_ref() {
+                        arg_sets.push(validator.create_constraints(&crate_name, quote!(arg)));
+                    }
+
                     schema_args.push(quote! {
                         {
                             let mut arg = #crate_name::registry::MetaInputValue::new(
@@ -640,6 +733,20 @@ pub fn generate(
                         quote!(field.requires_scopes = ::std::vec![ #(#requires_scopes),* ];),
                     );
                 }
+                if method_args.authenticated {
+                    field_sets.push(quote!(field.authenticated = true;));
+                }
+                if let Some(args::Timeout(millis)) = method_args.timeout {
+                    field_sets.push(quote! {
+                        field.timeout =
+                            ::std::option::Option::Some(::std::time::Duration::from_millis(#millis));
+                    });
+                }
+                if let Some(max_concurrency) = method_args.max_concurrency.map(NonZeroUsize::get) {
+                    field_sets.push(quote! {
+                        field.max_concurrency = ::std::num::NonZeroUsize::new(#max_concurrency);
+                    });
+                }
 
                 schema_fields.push(quote! {
                     #(#cfg_attrs)*
@@ -690,7 +797,12 @@ pub fn generate(
                     None => Default::default(),
                 };
 
-                let resolve_body = if is_async {
+                let result_guard = method_args
+                    .guard_result
+                    .as_ref()
+                    .map(|code| generate_result_guard(&crate_name, code, quote!(&obj)));
+
+                let resolve_body = if is_async && result_guard.is_none() {
                     quote! {
                         return #crate_name::resolver_utils::resolve_field_async(
                             ctx,
@@ -699,24 +811,27 @@ pub fn generate(
                         .await;
                     }
                 } else {
-                    match &ty {
-                        OutputType::Value(_) => {
-                            quote! {
-                                let obj: #schema_ty = self.#field_ident(ctx, #(#use_params),*);
-                                return #crate_name::resolver_utils::resolve_simple_field_value(ctx, &obj).await;
-                            }
+                    let resolve_obj = match &ty {
+                        OutputType::Value(_) if !is_async => {
+                            quote!(self.#field_ident(ctx, #(#use_params),*))
                         }
-                        OutputType::Result(_) => {
+                        _ => {
+                            let await_token = is_async.then(|| quote!(.await));
                             quote! {
-                                let obj: #schema_ty = self.#field_ident(ctx, #(#use_params),*)
+                                self.#field_ident(ctx, #(#use_params),*)
+                                    #await_token
                                     .map_err(|err| {
                                         let err = ::std::convert::Into::<#crate_name::Error>::into(err)
                                             .into_server_error(ctx.item.pos);
                                         ctx.set_error_path(err)
-                                    })?;
-                                return #crate_name::resolver_utils::resolve_simple_field_value(ctx, &obj).await;
+                                    })?
                             }
                         }
+                    };
+                    quote! {
+                        let obj: #schema_ty = #resolve_obj;
+                        #result_guard
+                        return #crate_name::resolver_utils::resolve_simple_field_value(ctx, &obj).await;
                     }
                 };
 
@@ -751,6 +866,11 @@ pub fn generate(
 
     find_entities.sort_by(|(a, _), (b, _)| b.cmp(a));
     let find_entities_iter = find_entities.iter().map(|(_, code)| code);
+    find_entities_batch.sort_by(|(a, _), (b, _)| b.cmp(a));
+    let find_entities_batch_codes = find_entities_batch
+        .iter()
+        .map(|(_, code)| code)
+        .collect::<Vec<_>>();
 
     if resolvers.is_empty() && create_entity_types.is_empty() {
         return Err(Error::new_spanned(
@@ -781,6 +901,13 @@ pub fn generate(
         quote! { #crate_name::resolver_utils::resolve_container(ctx, self).await }
     };
 
+    let (check_type_guard_fn, check_type_guard) = generate_type_guard(
+        &crate_name,
+        object_args.type_guard.as_ref(),
+        object_args.guard_result.as_ref(),
+        None,
+    );
+
     let has_cache_control = object_args.cache_control.no_cache
         || object_args.cache_control.max_age != 0
         || !object_args.cache_control.is_public();
@@ -831,10 +958,27 @@ pub fn generate(
     if has_requires_scopes {
         object_builder.push(quote!(.requires_scopes(::std::vec![ #(#requires_scopes),* ])));
     }
+    if object_args.authenticated {
+        object_builder.push(quote!(.authenticated(true)));
+    }
     let object_builder_concretes = object_builder_base;
 
     let resolve_field_resolver_match = generate_field_match(resolvers)?;
 
+    let has_batch_entities = !find_entities_batch.is_empty();
+    let find_entities_fn = has_batch_entities.then(|| {
+        quote! {
+            async fn find_entities(
+                &self,
+                ctx: &#crate_name::Context<'_>,
+                representations: &[#crate_name::Value],
+                entities: &mut [::std::option::Option<#crate_name::ServerResult<#crate_name::Value>>],
+            ) {
+                #(#find_entities_batch_codes)*
+            }
+        }
+    });
+
     let expanded = if object_args.concretes.is_empty() {
         quote! {
             #item_impl
@@ -860,6 +1004,10 @@ pub fn generate(
                         }
                         ::std::result::Result::Ok(::std::option::Option::None)
                     }
+
+                    #find_entities_fn
+
+                    #check_type_guard_fn
                 }
 
                 #[allow(clippy::all, clippy::pedantic)]
@@ -892,6 +1040,7 @@ pub fn generate(
                         ctx: &#crate_name::ContextSelectionSet<'_>,
                         _field: &#crate_name::Positioned<#crate_name::parser::types::Field>
                     ) -> #crate_name::ServerResult<#crate_name::Value> {
+                        #check_type_guard
                         #resolve_container
                     }
                 }
@@ -901,6 +1050,30 @@ pub fn generate(
         }
     } else {
         let mut codes = Vec::new();
+        let internal_find_entities_fn = has_batch_entities.then(|| {
+            quote! {
+                async fn __internal_find_entities(
+                    &self,
+                    ctx: &#crate_name::Context<'_>,
+                    representations: &[#crate_name::Value],
+                    entities: &mut [::std::option::Option<#crate_name::ServerResult<#crate_name::Value>>],
+                ) {
+                    #(#find_entities_batch_codes)*
+                }
+            }
+        });
+        let concrete_find_entities_fn = has_batch_entities.then(|| {
+            quote! {
+                async fn find_entities(
+                    &self,
+                    ctx: &#crate_name::Context<'_>,
+                    representations: &[#crate_name::Value],
+                    entities: &mut [::std::option::Option<#crate_name::ServerResult<#crate_name::Value>>],
+                ) {
+                    self.__internal_find_entities(ctx, representations, entities).await
+                }
+            }
+        });
 
         codes.push(quote! {
             #item_impl
@@ -941,6 +1114,8 @@ pub fn generate(
                         }
                         ::std::result::Result::Ok(::std::option::Option::None)
                     }
+
+                    #internal_find_entities_fn
                 }
             };
         });
@@ -974,6 +1149,10 @@ pub fn generate(
                     async fn find_entity(&self, ctx: &#crate_name::Context<'_>, params: &#crate_name::Value) -> #crate_name::ServerResult<::std::option::Option<#crate_name::Value>> {
                         self.__internal_find_entity(ctx, params).await
                     }
+
+                    #concrete_find_entities_fn
+
+                    #check_type_guard_fn
                 }
 
                 #boxed_trait
@@ -991,6 +1170,7 @@ pub fn generate(
                         ctx: &#crate_name::ContextSelectionSet<'_>,
                         _field: &#crate_name::Positioned<#crate_name::parser::types::Field>
                     ) -> #crate_name::ServerResult<#crate_name::Value> {
+                        #check_type_guard
                         #resolve_container
                     }
                 }
@@ -6,8 +6,8 @@ use syn::{Error, Type};
 use crate::{
     args::{self, RenameRuleExt, RenameTarget, TypeDirectiveLocation},
     utils::{
-        GeneratorResult, gen_deprecation, gen_directive_calls, get_crate_path, get_rustdoc,
-        visible_fn,
+        GeneratorResult, gen_deprecation, gen_directive_calls, gen_object_validators,
+        get_crate_path, get_rustdoc, visible_fn,
     },
 };
 
@@ -61,6 +61,8 @@ pub fn generate(object_args: &args::OneofObject) -> GeneratorResult<TokenStream>
     let mut enum_names = Vec::new();
     let mut schema_fields = Vec::new();
     let mut parse_item = Vec::new();
+    let object_validators =
+        gen_object_validators(&crate_name, &object_args.object_validators, quote!(value));
     let mut put_fields = Vec::new();
 
     for variant in s {
@@ -154,6 +156,10 @@ pub fn generate(object_args: &args::OneofObject) -> GeneratorResult<TokenStream>
                 );
             }
 
+            if let Some(validator) = &variant.validator {
+                input_sets.push(validator.create_constraints(&crate_name, quote!(input_value)));
+            }
+
             schema_fields.push(quote! {
                 {
                     let mut input_value = #crate_name::registry::MetaInputValue::new(
@@ -179,7 +185,9 @@ pub fn generate(object_args: &args::OneofObject) -> GeneratorResult<TokenStream>
                 if obj.contains_key(#field_name) && obj.len() == 1 {
                     let value = #crate_name::InputType::parse(obj.remove(#field_name)).map_err(#crate_name::InputValueError::propagate)?;
                     #validators
-                    return ::std::result::Result::Ok(Self::#enum_name(value));
+                    let value = Self::#enum_name(value);
+                    #object_validators
+                    return ::std::result::Result::Ok(value);
                 }
             });
 
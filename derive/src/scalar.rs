@@ -49,6 +49,7 @@ pub fn generate(
     let where_clause = &item_impl.generics.where_clause;
     let visible = visible_fn(&scalar_args.visible);
     let inaccessible = scalar_args.inaccessible;
+    let authenticated = scalar_args.authenticated;
     let tags = scalar_args
         .tags
         .iter()
@@ -66,6 +67,19 @@ pub fn generate(
         None => quote! { ::std::option::Option::None },
     };
 
+    // The values of `Int` are checked to be 32-bit when the schema is strict.
+    let resolve_value = if !scalar_args.name_type && scalar_args.name.as_deref() == Some("Int") {
+        quote! {
+            #crate_name::resolver_utils::check_int_value(
+                _ctx,
+                _field.pos,
+                #crate_name::ScalarType::to_value(self),
+            )
+        }
+    } else {
+        quote!(::std::result::Result::Ok(#crate_name::ScalarType::to_value(self)))
+    };
+
     let expanded = quote! {
         #item_impl
 
@@ -88,6 +102,7 @@ pub fn generate(
                     specified_by_url: #specified_by_url,
                     directive_invocations: ::std::vec::Vec::new(),
                     requires_scopes: ::std::vec![ #(#requires_scopes),* ],
+                    authenticated: #authenticated,
                 })
             }
 
@@ -122,15 +137,16 @@ pub fn generate(
                     specified_by_url: #specified_by_url,
                     directive_invocations: ::std::vec::Vec::new(),
                     requires_scopes: ::std::vec![ #(#requires_scopes),* ],
+                    authenticated: #authenticated,
                 })
             }
 
             async fn resolve(
                 &self,
-                _: &#crate_name::ContextSelectionSet<'_>,
+                _ctx: &#crate_name::ContextSelectionSet<'_>,
                 _field: &#crate_name::Positioned<#crate_name::parser::types::Field>
             ) -> #crate_name::ServerResult<#crate_name::Value> {
-                ::std::result::Result::Ok(#crate_name::ScalarType::to_value(self))
+                #resolve_value
             }
         }
     };
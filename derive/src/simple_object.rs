@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{num::NonZeroUsize, str::FromStr};
 
 use darling::ast::Data;
 use proc_macro::TokenStream;
@@ -11,7 +11,8 @@ use crate::{
     },
     utils::{
         GeneratorResult, gen_boxed_trait, gen_deprecation, gen_directive_calls, generate_guards,
-        get_crate_path, get_rustdoc, parse_complexity_expr, visible_fn,
+        generate_result_guard, generate_type_guard, get_crate_path, get_rustdoc,
+        parse_complexity_expr, visible_fn,
     },
 };
 
@@ -302,6 +303,20 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                 field_sets
                     .push(quote!(field.requires_scopes = ::std::vec![ #(#requires_scopes),* ];));
             }
+            if field.authenticated {
+                field_sets.push(quote!(field.authenticated = true;));
+            }
+            if let Some(args::Timeout(millis)) = field.timeout {
+                field_sets.push(quote! {
+                    field.timeout =
+                        ::std::option::Option::Some(::std::time::Duration::from_millis(#millis));
+                });
+            }
+            if let Some(max_concurrency) = field.max_concurrency.map(NonZeroUsize::get) {
+                field_sets.push(quote! {
+                    field.max_concurrency = ::std::num::NonZeroUsize::new(#max_concurrency);
+                });
+            }
 
             schema_fields.push(quote! {
                 let mut field = #crate_name::registry::MetaField::new(
@@ -355,6 +370,13 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
             true => quote! { &#ty },
             false => quote! { #ty },
         };
+        let result_guard = field.guard_result.as_ref().map(|code| {
+            let value = match !owned {
+                true => quote!(obj),
+                false => quote!(&obj),
+            };
+            generate_result_guard(&crate_name, code, value)
+        });
 
         if !field.flatten {
             getters.push(quote! {
@@ -369,6 +391,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                 if ctx.item.node.name.node == #field_name {
                     #guard
                     let obj: #ty = #block;
+                    #result_guard
                     return #crate_name::resolver_utils::resolve_simple_field_value(ctx, &obj).await;
                 }
             });
@@ -484,6 +507,9 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
     if has_requires_scopes {
         object_builder_base.push(quote!(.requires_scopes(::std::vec![ #(#requires_scopes),* ])));
     }
+    if object_args.authenticated {
+        object_builder_base.push(quote!(.authenticated(true)));
+    }
 
     let mut object_builder = object_builder_base.clone();
     if has_keys {
@@ -493,6 +519,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
 
     let mut concat_complex_fields = quote!();
     let mut complex_resolver = quote!();
+    let mut complex_type_guard = None;
 
     if object_args.complex {
         concat_complex_fields = quote! {
@@ -503,6 +530,9 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                 return Ok(Some(value));
             }
         };
+        complex_type_guard = Some(quote! {
+            <Self as #crate_name::ComplexObject>::check_type_guard(self, ctx).await
+        });
     }
 
     let resolve_container = if object_args.serial {
@@ -511,6 +541,13 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
         quote! { #crate_name::resolver_utils::resolve_container(ctx, self).await }
     };
 
+    let (check_type_guard_fn, check_type_guard) = generate_type_guard(
+        &crate_name,
+        object_args.type_guard.as_ref(),
+        object_args.guard_result.as_ref(),
+        complex_type_guard,
+    );
+
     let expanded = if object_args.concretes.is_empty() {
         quote! {
             #[allow(clippy::all, clippy::pedantic)]
@@ -526,6 +563,8 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                     #complex_resolver
                     ::std::result::Result::Ok(::std::option::Option::None)
                 }
+
+                #check_type_guard_fn
             }
 
             #[allow(clippy::all, clippy::pedantic)]
@@ -552,6 +591,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                 }
 
                 async fn resolve(&self, ctx: &#crate_name::ContextSelectionSet<'_>, _field: &#crate_name::Positioned<#crate_name::parser::types::Field>) -> #crate_name::ServerResult<#crate_name::Value> {
+                    #check_type_guard
                     #resolve_container
                 }
             }
@@ -636,6 +676,8 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                         #complex_resolver
                         self.__internal_resolve_field(ctx).await
                     }
+
+                    #check_type_guard_fn
                 }
 
                 #[allow(clippy::all, clippy::pedantic)]
@@ -652,6 +694,7 @@ pub fn generate(object_args: &args::SimpleObject) -> GeneratorResult<TokenStream
                     }
 
                     async fn resolve(&self, ctx: &#crate_name::ContextSelectionSet<'_>, _field: &#crate_name::Positioned<#crate_name::parser::types::Field>) -> #crate_name::ServerResult<#crate_name::Value> {
+                        #check_type_guard
                         #resolve_container
                     }
                 }
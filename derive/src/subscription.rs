@@ -154,6 +154,10 @@ pub fn generate(
                     arg_sets.push(quote!(arg.is_secret = true;));
                 }
 
+                if let Some(validator) = validator.as_ref() {
+                    arg_sets.push(validator.create_constraints(&crate_name, quote!(arg)));
+                }
+
                 schema_args.push(quote! {
                     {
                         let mut arg = #crate_name::registry::MetaInputValue::new(
@@ -282,6 +286,12 @@ pub fn generate(
 
             let has_complexity = field.complexity.is_some();
             let has_directives = !field.directives.is_empty();
+            let has_requires_scopes = !field.requires_scopes.is_empty();
+            let requires_scopes = field
+                .requires_scopes
+                .iter()
+                .map(|scopes| quote!(::std::string::ToString::to_string(#scopes)))
+                .collect::<Vec<_>>();
             let directives = gen_directive_calls(
                 &crate_name,
                 &field.directives,
@@ -305,6 +315,13 @@ pub fn generate(
                 field_sets
                     .push(quote!(field.directive_invocations = ::std::vec![ #(#directives),* ];));
             }
+            if has_requires_scopes {
+                field_sets
+                    .push(quote!(field.requires_scopes = ::std::vec![ #(#requires_scopes),* ];));
+            }
+            if field.authenticated {
+                field_sets.push(quote!(field.authenticated = true;));
+            }
 
             schema_fields.push(quote! {
                 #(#cfg_attrs)*
@@ -472,6 +489,7 @@ pub fn generate(
                     rust_typename: ::std::option::Option::Some(::std::any::type_name::<Self>()),
                     directive_invocations: ::std::vec![ #(#directives),* ],
                     requires_scopes: ::std::vec![],
+                    authenticated: false,
                 })
             }
 
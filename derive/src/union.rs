@@ -9,7 +9,8 @@ use syn::{Error, LifetimeParam, Type, visit::Visit, visit_mut::VisitMut};
 use crate::{
     args::{self, RenameTarget},
     utils::{
-        GeneratorResult, RemoveLifetime, gen_boxed_trait, get_crate_path, get_rustdoc, visible_fn,
+        GeneratorResult, RemoveLifetime, gen_boxed_trait, generate_type_guard, get_crate_path,
+        get_rustdoc, visible_fn,
     },
 };
 
@@ -65,6 +66,7 @@ pub fn generate(union_args: &args::Union) -> GeneratorResult<TokenStream> {
     }
 
     let mut collect_all_fields = Vec::new();
+    let mut check_type_guards = Vec::new();
 
     for variant in s {
         let enum_name = &variant.ident;
@@ -142,6 +144,10 @@ pub fn generate(union_args: &args::Union) -> GeneratorResult<TokenStream> {
             collect_all_fields.push(quote! {
                 #ident::#enum_name(obj) => obj.collect_all_fields(ctx, fields)
             });
+
+            check_type_guards.push(quote! {
+                #ident::#enum_name(obj) => #crate_name::resolver_utils::boxed_check_type_guard(obj, ctx)
+            });
         } else {
             return Err(Error::new_spanned(ty, "Invalid type").into());
         }
@@ -204,6 +210,18 @@ pub fn generate(union_args: &args::Union) -> GeneratorResult<TokenStream> {
     })
     };
 
+    let (check_type_guard_fn, check_type_guard) = generate_type_guard(
+        &crate_name,
+        union_args.type_guard.as_ref(),
+        None,
+        Some(quote! {
+            match self {
+                #(#check_type_guards),*
+            }
+            .await
+        }),
+    );
+
     let expanded = if union_args.concretes.is_empty() {
         let get_introspection_typename = get_introspection_typename(lazy_types.clone());
         let registry_types = registry_types(lazy_types.clone());
@@ -224,6 +242,8 @@ pub fn generate(union_args: &args::Union) -> GeneratorResult<TokenStream> {
                         #(#collect_all_fields),*
                     }
                 }
+
+                #check_type_guard_fn
             }
 
             #[allow(clippy::all, clippy::pedantic)]
@@ -261,6 +281,7 @@ pub fn generate(union_args: &args::Union) -> GeneratorResult<TokenStream> {
                 }
 
                 async fn resolve(&self, ctx: &#crate_name::ContextSelectionSet<'_>, _field: &#crate_name::Positioned<#crate_name::parser::types::Field>) -> #crate_name::ServerResult<#crate_name::Value> {
+                    #check_type_guard
                     #crate_name::resolver_utils::resolve_container(ctx, self).await
                 }
             }
@@ -374,6 +395,8 @@ pub fn generate(union_args: &args::Union) -> GeneratorResult<TokenStream> {
                             #(#collect_all_fields),*
                         }
                     }
+
+                    #check_type_guard_fn
                 }
 
                 #[allow(clippy::all, clippy::pedantic)]
@@ -411,6 +434,7 @@ pub fn generate(union_args: &args::Union) -> GeneratorResult<TokenStream> {
                     }
 
                     async fn resolve(&self, ctx: &#crate_name::ContextSelectionSet<'_>, _field: &#crate_name::Positioned<#crate_name::parser::types::Field>) -> #crate_name::ServerResult<#crate_name::Value> {
+                        #check_type_guard
                         #crate_name::resolver_utils::resolve_container(ctx, self).await
                     }
                 }
@@ -5,8 +5,9 @@ use proc_macro_crate::{FoundCrate, crate_name};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{
-    Attribute, Error, Expr, ExprLit, ExprPath, FnArg, Ident, ImplItemFn, Lifetime, Lit, LitStr,
-    Meta, Pat, PatIdent, Type, TypeGroup, TypeParamBound, TypeReference, parse_quote,
+    Attribute, Error, Expr, ExprLit, ExprPath, FnArg, GenericArgument, Ident, ImplItemFn, Lifetime,
+    Lit, LitStr, Meta, Pat, PatIdent, PathArguments, Type, TypeGroup, TypeParamBound,
+    TypeReference, parse_quote,
     visit::Visit,
     visit_mut::{self, VisitMut},
 };
@@ -59,10 +60,79 @@ pub fn generate_guards(
         #expr
     }};
     Ok(quote! {
-        #crate_name::Guard::check(&#code, &ctx).await #map_err ?;
+        #crate_name::Guard::check(&#code, &ctx)
+            .await
+            .map_err(#crate_name::resolver_utils::guard_error)
+            #map_err ?;
     })
 }
 
+/// Generates the check of a post-resolution guard on `value`, which returns
+/// `null` from the resolver of a field of `Self` if it is redacted.
+pub fn generate_result_guard(
+    crate_name: &syn::Path,
+    expr: &Expr,
+    value: TokenStream,
+) -> TokenStream {
+    quote! {
+        if !#crate_name::resolver_utils::check_result_guard(
+            ctx,
+            &<Self as #crate_name::OutputType>::type_name(),
+            &(#expr),
+            #value,
+        )
+        .await?
+        {
+            return ::std::result::Result::Ok(::std::option::Option::Some(#crate_name::Value::Null));
+        }
+    }
+}
+
+/// Generates the `ContainerType::check_type_guard` method of a type, which
+/// checks its type guard and its post-resolution guard, then `dispatch` if
+/// any, e.g. the check of the internal type for interfaces and unions, and
+/// the call to it in `OutputType::resolve`.
+pub fn generate_type_guard(
+    crate_name: &syn::Path,
+    expr: Option<&Expr>,
+    result_guard: Option<&Expr>,
+    dispatch: Option<TokenStream>,
+) -> (Option<TokenStream>, Option<TokenStream>) {
+    if expr.is_none() && result_guard.is_none() && dispatch.is_none() {
+        return (None, None);
+    }
+    let check = expr.map(|expr| {
+        quote! {
+            #crate_name::Guard::check(&{
+                use #crate_name::GuardExt;
+                #expr
+            }, ctx).await?;
+        }
+    });
+    let check_result = result_guard.map(|expr| {
+        quote! {
+            if #crate_name::ResultGuard::check(&(#expr), ctx, self).await? == #crate_name::GuardAction::Redact {
+                return ::std::result::Result::Ok(#crate_name::GuardAction::Redact);
+            }
+        }
+    });
+    let dispatch = dispatch
+        .unwrap_or_else(|| quote!(::std::result::Result::Ok(#crate_name::GuardAction::Allow)));
+    let check_type_guard_fn = quote! {
+        async fn check_type_guard(&self, ctx: &#crate_name::Context<'_>) -> #crate_name::Result<#crate_name::GuardAction> {
+            #check
+            #check_result
+            #dispatch
+        }
+    };
+    let check_type_guard = quote! {
+        if #crate_name::resolver_utils::check_type_guard(ctx, _field, self).await? == #crate_name::GuardAction::Redact {
+            return #crate_name::resolver_utils::resolve_redacted_container(ctx, self).await;
+        }
+    };
+    (Some(check_type_guard_fn), Some(check_type_guard))
+}
+
 pub fn get_rustdoc(attrs: &[Attribute]) -> GeneratorResult<Option<TokenStream>> {
     let mut full_docs: Vec<TokenStream> = vec![];
     let mut combined_docs_literal = String::new();
@@ -212,6 +282,23 @@ pub fn get_type_path_and_name(ty: &Type) -> GeneratorResult<(&Type, String)> {
     }
 }
 
+pub fn get_vec_element_type(ty: &Type) -> GeneratorResult<&Type> {
+    match ty {
+        Type::Path(path) => {
+            let segment = path.path.segments.last().unwrap();
+            if segment.ident == "Vec"
+                && let PathArguments::AngleBracketed(args) = &segment.arguments
+                && let Some(GenericArgument::Type(elem)) = args.args.first()
+            {
+                return Ok(elem);
+            }
+            Err(Error::new_spanned(ty, "Must be a `Vec`").into())
+        }
+        Type::Group(TypeGroup { elem, .. }) => get_vec_element_type(elem),
+        _ => Err(Error::new_spanned(ty, "Must be a `Vec`").into()),
+    }
+}
+
 pub fn visible_fn(visible: &Option<Visible>) -> TokenStream {
     match visible {
         None | Some(Visible::None) => quote! { ::std::option::Option::None },
@@ -243,6 +330,21 @@ pub fn parse_complexity_expr(expr: Expr) -> GeneratorResult<(HashSet<String>, Ex
     Ok((visit.variables, expr))
 }
 
+pub fn gen_object_validators(
+    crate_name: &syn::Path,
+    validators: &[Expr],
+    value: TokenStream,
+) -> Option<TokenStream> {
+    if validators.is_empty() {
+        return None;
+    }
+    Some(quote! {
+        let mut errors = #crate_name::InputFieldErrors::new();
+        #(#crate_name::InputObjectValidator::validate(&#validators, &#value, &mut errors);)*
+        errors.into_result::<Self>()?;
+    })
+}
+
 pub fn gen_deprecation(deprecation: &Deprecation, crate_name: &syn::Path) -> TokenStream {
     match deprecation {
         Deprecation::NoDeprecated => {
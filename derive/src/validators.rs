@@ -1,7 +1,7 @@
-use darling::FromMeta;
-use proc_macro2::TokenStream;
+use darling::{FromMeta, ast::NestedMeta};
+use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
-use syn::{Expr, Lit, Result};
+use syn::{Expr, Ident, Lit, Result};
 
 #[derive(Clone)]
 pub enum Number {
@@ -28,6 +28,38 @@ impl ToTokens for Number {
     }
 }
 
+#[derive(Clone)]
+pub struct UuidVersion(Option<u8>);
+
+impl FromMeta for UuidVersion {
+    fn from_word() -> darling::Result<Self> {
+        Ok(UuidVersion(None))
+    }
+
+    fn from_value(value: &Lit) -> darling::Result<Self> {
+        match value {
+            Lit::Int(n) => Ok(UuidVersion(Some(n.base10_parse::<u8>()?))),
+            _ => Err(darling::Error::unexpected_lit_type(value)),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct LitList(Vec<Lit>);
+
+impl FromMeta for LitList {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let mut res = Vec::new();
+        for item in items {
+            match item {
+                NestedMeta::Lit(lit) => res.push(lit.clone()),
+                _ => return Err(darling::Error::custom("Invalid literal list")),
+            }
+        }
+        Ok(LitList(res))
+    }
+}
+
 #[derive(FromMeta, Default, Clone)]
 pub struct Validators {
     #[darling(default)]
@@ -50,6 +82,40 @@ pub struct Validators {
     chars_min_length: Option<usize>,
     #[darling(default)]
     regex: Option<String>,
+    #[darling(default)]
+    url: bool,
+    #[darling(default)]
+    uuid: Option<UuidVersion>,
+    #[darling(default)]
+    ip: bool,
+    #[darling(default)]
+    cidr: bool,
+    #[darling(default)]
+    hostname: bool,
+    #[darling(default)]
+    phone: bool,
+    #[darling(default)]
+    date: bool,
+    #[darling(default)]
+    time: bool,
+    #[darling(default)]
+    date_time: bool,
+    #[darling(default)]
+    hex_color: bool,
+    #[darling(default)]
+    ascii: bool,
+    #[darling(default)]
+    alphanumeric: bool,
+    #[darling(default)]
+    not_blank: bool,
+    #[darling(default)]
+    one_of: Option<LitList>,
+    #[darling(default)]
+    starts_with: Option<String>,
+    #[darling(default)]
+    ends_with: Option<String>,
+    #[darling(default)]
+    unique_items: bool,
     #[darling(default, multiple)]
     custom: Vec<Expr>,
     #[darling(default)]
@@ -57,6 +123,104 @@ pub struct Validators {
 }
 
 impl Validators {
+    /// Generates the statements setting the constraints of `input_value`,
+    /// which are exported as the arguments of the `@constraint` directive, or
+    /// of the `@validation` directive when `@constraint` cannot express them.
+    pub fn create_constraints(
+        &self,
+        crate_name: &syn::Path,
+        input_value: TokenStream,
+    ) -> TokenStream {
+        let mut constraints = Vec::new();
+
+        if let Some(n) = self.chars_min_length.or(self.min_length) {
+            constraints.push(("minLength", quote!(#crate_name::Value::from(#n))));
+        }
+        if let Some(n) = self.chars_max_length.or(self.max_length) {
+            constraints.push(("maxLength", quote!(#crate_name::Value::from(#n))));
+        }
+        if let Some(prefix) = &self.starts_with {
+            constraints.push(("startsWith", quote!(#crate_name::Value::from(#prefix))));
+        }
+        if let Some(suffix) = &self.ends_with {
+            constraints.push(("endsWith", quote!(#crate_name::Value::from(#suffix))));
+        }
+        if let Some(re) = &self.regex {
+            constraints.push(("pattern", quote!(#crate_name::Value::from(#re))));
+        }
+
+        let format = [
+            (self.url, "uri"),
+            (self.uuid.is_some(), "uuid"),
+            (self.ip, "ip"),
+            (self.cidr, "cidr"),
+            (self.hostname, "hostname"),
+            (self.phone, "e164"),
+            (self.date, "date"),
+            (self.time, "time"),
+            (self.date_time, "date-time"),
+            (self.hex_color, "hex-color"),
+            (self.ascii, "ascii"),
+            (self.alphanumeric, "alphanumeric"),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, format)| enabled.then_some(format))
+        .collect::<Vec<_>>();
+        match format.as_slice() {
+            [] => {}
+            [format] => constraints.push(("format", quote!(#crate_name::Value::from(#format)))),
+            formats => constraints.push((
+                "formats",
+                quote!(#crate_name::Value::List(::std::vec![#(#crate_name::Value::from(#formats)),*])),
+            )),
+        }
+
+        if let Some(n) = &self.minimum {
+            constraints.push(("min", quote!(#crate_name::Value::from(#n))));
+        }
+        if let Some(n) = &self.maximum {
+            constraints.push(("max", quote!(#crate_name::Value::from(#n))));
+        }
+        if let Some(n) = &self.multiple_of {
+            constraints.push(("multipleOf", quote!(#crate_name::Value::from(#n))));
+        }
+        if let Some(n) = &self.min_items {
+            constraints.push(("minItems", quote!(#crate_name::Value::from(#n))));
+        }
+        if let Some(n) = &self.max_items {
+            constraints.push(("maxItems", quote!(#crate_name::Value::from(#n))));
+        }
+        if self.unique_items {
+            constraints.push(("uniqueItems", quote!(#crate_name::Value::from(true))));
+        }
+        if self.not_blank {
+            constraints.push(("notBlank", quote!(#crate_name::Value::from(true))));
+        }
+        if let Some(LitList(values)) = &self.one_of {
+            // The values of a list all have the same type, which selects the
+            // argument typed accordingly.
+            let name = match values.first() {
+                Some(Lit::Int(_)) => Some("oneOfInt"),
+                Some(Lit::Float(_)) => Some("oneOfFloat"),
+                Some(Lit::Str(_)) => Some("oneOf"),
+                _ => None,
+            };
+            if let Some(name) = name {
+                constraints.push((
+                    name,
+                    quote!(#crate_name::Value::List(::std::vec![#(#crate_name::Value::from(#values)),*])),
+                ));
+            }
+        }
+
+        let constraints = constraints.into_iter().map(|(name, value)| {
+            quote! {
+                #input_value.constraints.insert(::std::string::ToString::to_string(#name), #value);
+            }
+        });
+        quote!(#(#constraints)*)
+    }
+
     pub fn create_validators(
         &self,
         crate_name: &syn::Path,
@@ -79,6 +243,12 @@ impl Validators {
             });
         }
 
+        if self.unique_items {
+            list_validators.push(quote! {
+                #crate_name::validators::unique_items(__raw_value)
+            });
+        }
+
         if let Some(n) = &self.multiple_of {
             elem_validators.push(quote! {
                 #crate_name::validators::multiple_of(__raw_value, #n)
@@ -127,6 +297,56 @@ impl Validators {
             });
         }
 
+        for (enabled, name) in [
+            (self.url, "url"),
+            (self.ip, "ip"),
+            (self.cidr, "cidr"),
+            (self.hostname, "hostname"),
+            (self.phone, "phone"),
+            (self.date, "date"),
+            (self.time, "time"),
+            (self.date_time, "date_time"),
+            (self.hex_color, "hex_color"),
+            (self.ascii, "ascii"),
+            (self.alphanumeric, "alphanumeric"),
+            (self.not_blank, "not_blank"),
+        ] {
+            if enabled {
+                let name = Ident::new(name, Span::call_site());
+                elem_validators.push(quote! {
+                    #crate_name::validators::#name(__raw_value)
+                });
+            }
+        }
+
+        if let Some(UuidVersion(version)) = &self.uuid {
+            let version = match version {
+                Some(version) => quote!(::std::option::Option::Some(#version)),
+                None => quote!(::std::option::Option::None),
+            };
+            elem_validators.push(quote! {
+                #crate_name::validators::uuid(__raw_value, #version)
+            });
+        }
+
+        if let Some(LitList(values)) = &self.one_of {
+            elem_validators.push(quote! {
+                #crate_name::validators::one_of(__raw_value, &[#(#values),*])
+            });
+        }
+
+        if let Some(prefix) = &self.starts_with {
+            elem_validators.push(quote! {
+                #crate_name::validators::starts_with(__raw_value, #prefix)
+            });
+        }
+
+        if let Some(suffix) = &self.ends_with {
+            elem_validators.push(quote! {
+                #crate_name::validators::ends_with(__raw_value, #suffix)
+            });
+        }
+
         if !list_validators.is_empty() {
             codes.push(quote! {
                 if let ::std::option::Option::Some(__raw_value) = #crate_name::InputType::as_raw_value(#value) {
@@ -48,6 +48,12 @@ mod en {
     mod subscription {}
     #[doc = include_str!("../../en/src/field_guard.md")]
     mod field_guard {}
+    #[doc = include_str!("../../en/src/timeouts.md")]
+    mod timeouts {}
+    #[doc = include_str!("../../en/src/cancellation.md")]
+    mod cancellation {}
+    #[doc = include_str!("../../en/src/concurrency_limits.md")]
+    mod concurrency_limits {}
     #[doc = include_str!("../../en/src/input_value_validators.md")]
     mod input_value_validators {}
     #[doc = include_str!("../../en/src/cache_control.md")]
@@ -2,6 +2,10 @@
 
 #[cfg(feature = "opentelemetry")]
 mod opentelemetry;
+#[cfg(feature = "opentelemetry")]
+mod opentelemetry_metrics;
 
 #[cfg(feature = "opentelemetry")]
 pub use self::opentelemetry::OpenTelemetry;
+#[cfg(feature = "opentelemetry")]
+pub use self::opentelemetry_metrics::OpenTelemetryMetrics;
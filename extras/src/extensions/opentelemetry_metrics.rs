@@ -0,0 +1,176 @@
+use std::time::Duration;
+
+use async_graphql::extensions::{MetricsPhase, MetricsRecorder};
+use opentelemetry::{
+    Key, KeyValue,
+    metrics::{Counter, Histogram, Meter},
+};
+
+const KEY_CODE: Key = Key::from_static_str("graphql.error.code");
+const KEY_PHASE: Key = Key::from_static_str("graphql.phase");
+const KEY_PARENT_TYPE: Key = Key::from_static_str("graphql.parentType");
+const KEY_FIELD: Key = Key::from_static_str("graphql.field");
+
+/// A [`MetricsRecorder`] reporting to OpenTelemetry metrics instruments.
+///
+/// # Example
+///
+/// ```ignore
+/// use async_graphql::extensions::Metrics;
+/// use async_graphql_extras::extensions::OpenTelemetryMetrics;
+///
+/// let meter = opentelemetry::global::meter("graphql");
+///
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .extension(Metrics::new(OpenTelemetryMetrics::new(&meter)))
+///     .finish();
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "opentelemetry")))]
+pub struct OpenTelemetryMetrics {
+    requests: Counter<u64>,
+    errors: Counter<u64>,
+    request_duration: Histogram<f64>,
+    phase_duration: Histogram<f64>,
+    field_duration: Histogram<f64>,
+    complexity: Histogram<u64>,
+    depth: Histogram<u64>,
+}
+
+impl OpenTelemetryMetrics {
+    /// Create the instruments with `meter`.
+    #[must_use]
+    pub fn new(meter: &Meter) -> Self {
+        Self {
+            requests: meter
+                .u64_counter("graphql.requests")
+                .with_description("The number of requests.")
+                .build(),
+            errors: meter
+                .u64_counter("graphql.errors")
+                .with_description("The number of errors, by code.")
+                .build(),
+            request_duration: meter
+                .f64_histogram("graphql.request.duration")
+                .with_description("The duration of the requests.")
+                .with_unit("s")
+                .build(),
+            phase_duration: meter
+                .f64_histogram("graphql.phase.duration")
+                .with_description("The duration of the parse, validation and execute phases.")
+                .with_unit("s")
+                .build(),
+            field_duration: meter
+                .f64_histogram("graphql.field.duration")
+                .with_description("The duration of the field resolutions.")
+                .with_unit("s")
+                .build(),
+            complexity: meter
+                .u64_histogram("graphql.query.complexity")
+                .with_description("The complexity of the queries.")
+                .build(),
+            depth: meter
+                .u64_histogram("graphql.query.depth")
+                .with_description("The depth of the queries.")
+                .build(),
+        }
+    }
+}
+
+impl MetricsRecorder for OpenTelemetryMetrics {
+    fn record_request(&self, duration: Duration, error_codes: &[&str]) {
+        self.requests.add(1, &[]);
+        self.request_duration.record(duration.as_secs_f64(), &[]);
+        for code in error_codes {
+            self.errors
+                .add(1, &[KeyValue::new(KEY_CODE, (*code).to_string())]);
+        }
+    }
+
+    fn record_phase(&self, phase: MetricsPhase, duration: Duration) {
+        self.phase_duration.record(
+            duration.as_secs_f64(),
+            &[KeyValue::new(KEY_PHASE, phase.as_str())],
+        );
+    }
+
+    fn record_field(&self, parent_type: &str, field_name: &str, duration: Duration) {
+        self.field_duration.record(
+            duration.as_secs_f64(),
+            &[
+                KeyValue::new(KEY_PARENT_TYPE, parent_type.to_string()),
+                KeyValue::new(KEY_FIELD, field_name.to_string()),
+            ],
+        );
+    }
+
+    fn record_query(&self, complexity: usize, depth: usize) {
+        self.complexity.record(complexity as u64, &[]);
+        self.depth.record(depth as u64, &[]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema, extensions::Metrics};
+    use opentelemetry::metrics::MeterProvider;
+    use opentelemetry_sdk::metrics::{
+        InMemoryMetricExporter, PeriodicReader, SdkMeterProvider,
+        data::{AggregatedMetrics, MetricData, ResourceMetrics, ScopeMetrics},
+    };
+
+    use super::*;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    fn data_points(metrics: &AggregatedMetrics) -> usize {
+        match metrics {
+            AggregatedMetrics::U64(MetricData::Sum(sum)) => sum.data_points().count(),
+            AggregatedMetrics::U64(MetricData::Histogram(histogram)) => {
+                histogram.data_points().count()
+            }
+            AggregatedMetrics::F64(MetricData::Histogram(histogram)) => {
+                histogram.data_points().count()
+            }
+            _ => 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_opentelemetry_metrics() {
+        let exporter = InMemoryMetricExporter::default();
+        let provider = SdkMeterProvider::builder()
+            .with_reader(PeriodicReader::builder(exporter.clone()).build())
+            .build();
+        let meter = provider.meter("graphql");
+
+        let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+            .extension(Metrics::new(OpenTelemetryMetrics::new(&meter)))
+            .finish();
+        let resp = schema.execute("{ value }").await;
+        assert!(resp.errors.is_empty());
+
+        provider.force_flush().unwrap();
+        let finished = exporter.get_finished_metrics().unwrap();
+        let metrics = finished
+            .iter()
+            .flat_map(ResourceMetrics::scope_metrics)
+            .flat_map(ScopeMetrics::metrics)
+            .map(|metric| (metric.name().to_string(), data_points(metric.data())))
+            .collect::<std::collections::HashMap<_, _>>();
+
+        assert_eq!(metrics.get("graphql.requests"), Some(&1));
+        assert_eq!(metrics.get("graphql.request.duration"), Some(&1));
+        assert_eq!(metrics.get("graphql.phase.duration"), Some(&3));
+        assert_eq!(metrics.get("graphql.field.duration"), Some(&1));
+        assert_eq!(metrics.get("graphql.query.complexity"), Some(&1));
+        assert_eq!(metrics.get("graphql.query.depth"), Some(&1));
+        assert!(!metrics.contains_key("graphql.errors"));
+    }
+}
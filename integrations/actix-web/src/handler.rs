@@ -51,8 +51,12 @@ impl<E: Executor> Handler<(HttpRequest, GraphQLRequest)> for GraphQL<E> {
                         .map(Ok::<_, actix_web::Error>),
                     )
             } else {
-                GraphQLResponse(executor.execute(graphql_req.into_inner()).await.into())
-                    .respond_to(&http_req)
+                // Dropping the future when the client disconnects cancels the request.
+                let request = graphql_req.into_inner();
+                let cancel = request.cancellation_token.clone().drop_guard();
+                let resp = executor.execute(request).await;
+                cancel.disarm();
+                GraphQLResponse(resp.into()).respond_to(&http_req)
             }
         }
         .boxed_local()
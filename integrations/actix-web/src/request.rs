@@ -27,6 +27,11 @@ use futures_util::{
 ///
 /// `async_graphql::http::MultipartOptions` allows to configure extraction
 /// process.
+///
+/// Unlike the [`GraphQL`](crate::GraphQL) handler, the extractor does not
+/// cancel the request when the client disconnects. To cancel it, hold the
+/// guard returned by `request.cancellation_token.clone().drop_guard()` while
+/// executing the request.
 pub struct GraphQLRequest(pub async_graphql::Request);
 
 impl GraphQLRequest {
@@ -59,6 +64,10 @@ impl FromRequest for GraphQLRequest {
 ///
 /// `async_graphql::http::MultipartOptions` allows to configure extraction
 /// process.
+///
+/// The extractor does not cancel the requests when the client disconnects.
+/// To cancel them, set a token with `BatchRequest::cancellation_token` and
+/// hold its drop guard while executing them.
 pub struct GraphQLBatchRequest(pub async_graphql::BatchRequest);
 
 impl GraphQLBatchRequest {
@@ -104,7 +113,7 @@ impl FromRequest for GraphQLBatchRequest {
 
             Box::pin(async move {
                 Ok(GraphQLBatchRequest(
-                    async_graphql::http::receive_batch_body(
+                    async_graphql::http::receive_batch_body_streaming(
                         content_type,
                         rx.map_err(|e| match e {
                             PayloadError::Incomplete(Some(e)) | PayloadError::Io(e) => e,
@@ -9,6 +9,9 @@ use axum::{
 use tokio_util::compat::TokioAsyncReadCompatExt;
 
 /// Extractor for GraphQL request.
+///
+/// The `async_graphql::http::MultipartOptions` in the request extensions
+/// allows to configure extraction process.
 pub struct GraphQLRequest<R = rejection::GraphQLRejection>(
     pub async_graphql::Request,
     PhantomData<R>,
@@ -76,6 +79,9 @@ where
 }
 
 /// Extractor for GraphQL batch request.
+///
+/// The `async_graphql::http::MultipartOptions` in the request extensions
+/// allows to configure extraction process.
 pub struct GraphQLBatchRequest<R = rejection::GraphQLRejection>(
     pub async_graphql::BatchRequest,
     PhantomData<R>,
@@ -113,18 +119,19 @@ where
                 .get(http::header::CONTENT_TYPE)
                 .and_then(|value| value.to_str().ok())
                 .map(ToString::to_string);
+            let opts = req
+                .extensions()
+                .get::<MultipartOptions>()
+                .copied()
+                .unwrap_or_default();
             let body_stream = req
                 .into_body()
                 .into_data_stream()
                 .map_err(|err| std::io::Error::other(err.to_string()));
             let body_reader = tokio_util::io::StreamReader::new(body_stream).compat();
             Ok(Self(
-                async_graphql::http::receive_batch_body(
-                    content_type,
-                    body_reader,
-                    MultipartOptions::default(),
-                )
-                .await?,
+                async_graphql::http::receive_batch_body_streaming(content_type, body_reader, opts)
+                    .await?,
                 PhantomData,
             ))
         }
@@ -5,7 +5,7 @@ use std::{
 };
 
 use async_graphql::{
-    Executor,
+    CancellationToken, Executor,
     http::{create_multipart_mixed_stream, is_accept_multipart_mixed},
     runtime::TokioTimer,
 };
@@ -87,7 +87,14 @@ where
                         Ok(req) => req,
                         Err(err) => return Ok(err.into_response()),
                     };
-                Ok(GraphQLResponse(executor.execute_batch(req.0).await).into_response())
+                // Dropping the future when the client disconnects cancels the requests.
+                let token = CancellationToken::new();
+                let cancel = token.clone().drop_guard();
+                let resp = executor
+                    .execute_batch(req.0.cancellation_token(token))
+                    .await;
+                cancel.disarm();
+                Ok(GraphQLResponse(resp).into_response())
             }
         })
     }
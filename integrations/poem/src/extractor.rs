@@ -12,6 +12,9 @@ use tokio_util::compat::TokioAsyncReadCompatExt;
 /// recommend using the [`GraphQL`](crate::GraphQL) endpoint because it is
 /// easier to integrate.
 ///
+/// The `async_graphql::http::MultipartOptions` in the request data allows to
+/// configure extraction process.
+///
 /// # Example
 ///
 /// ```
@@ -58,6 +61,9 @@ impl<'a> FromRequest<'a> for GraphQLRequest {
 }
 
 /// An extractor for GraphQL batch request.
+///
+/// The `async_graphql::http::MultipartOptions` in the request data allows to
+/// configure extraction process.
 pub struct GraphQLBatchRequest(pub async_graphql::BatchRequest);
 
 impl<'a> FromRequest<'a> for GraphQLBatchRequest {
@@ -73,11 +79,12 @@ impl<'a> FromRequest<'a> for GraphQLBatchRequest {
                 .get(header::CONTENT_TYPE)
                 .and_then(|value| value.to_str().ok())
                 .map(ToString::to_string);
+            let opts = req.data::<MultipartOptions>().copied().unwrap_or_default();
             Ok(Self(
-                async_graphql::http::receive_batch_body(
+                async_graphql::http::receive_batch_body_streaming(
                     content_type,
                     body.take()?.into_async_read().compat(),
-                    MultipartOptions::default(),
+                    opts,
                 )
                 .await
                 .map_err(BadRequest)?,
@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 use async_graphql::{
-    Executor,
+    CancellationToken, Executor,
     http::{create_multipart_mixed_stream, is_accept_multipart_mixed},
     runtime::TokioTimer,
 };
@@ -73,7 +73,15 @@ where
         } else {
             let (req, mut body) = req.split();
             let req = GraphQLBatchRequest::from_request(&req, &mut body).await?;
-            Ok(GraphQLBatchResponse(self.executor.execute_batch(req.0).await).into_response())
+            // Dropping the future when the client disconnects cancels the requests.
+            let token = CancellationToken::new();
+            let cancel = token.clone().drop_guard();
+            let resp = self
+                .executor
+                .execute_batch(req.0.cancellation_token(token))
+                .await;
+            cancel.disarm();
+            Ok(GraphQLBatchResponse(resp).into_response())
         }
     }
 }
@@ -5,7 +5,8 @@
 //!
 //! To configure options for sending and receiving multipart requests, add your
 //! instance of `MultipartOptions` to the state managed by Rocket
-//! (`.manage(your_multipart_options)`).
+//! (`.manage(your_multipart_options)`). The uploaded files cannot be streamed,
+//! the requests are rejected if `MultipartOptions::stream_uploads` is enabled.
 //!
 //! **[Full Example](<https://github.com/async-graphql/examples/blob/master/rocket/starwars/src/main.rs>)**
 
@@ -16,7 +17,7 @@
 use core::any::Any;
 use std::io::Cursor;
 
-use async_graphql::{Executor, ParseRequestError, http::MultipartOptions};
+use async_graphql::{CancellationToken, Executor, ParseRequestError, http::MultipartOptions};
 use rocket::{
     data::{self, Data, FromData, ToByteUnit},
     form::FromForm,
@@ -40,11 +41,20 @@ pub struct GraphQLBatchRequest(pub async_graphql::BatchRequest);
 
 impl GraphQLBatchRequest {
     /// Shortcut method to execute the request on the executor.
+    ///
+    /// The requests are cancelled if the returned future is dropped before
+    /// completing, see [`CancellationToken`].
     pub async fn execute<E>(self, executor: &E) -> GraphQLResponse
     where
         E: Executor,
     {
-        GraphQLResponse(executor.execute_batch(self.0).await)
+        let token = CancellationToken::new();
+        let cancel = token.clone().drop_guard();
+        let resp = executor
+            .execute_batch(self.0.cancellation_token(token))
+            .await;
+        cancel.disarm();
+        GraphQLResponse(resp)
     }
 }
 
@@ -95,11 +105,17 @@ pub struct GraphQLRequest(pub async_graphql::Request);
 
 impl GraphQLRequest {
     /// Shortcut method to execute the request on the schema.
+    ///
+    /// The request is cancelled if the returned future is dropped before
+    /// completing, see [`CancellationToken`].
     pub async fn execute<E>(self, executor: &E) -> GraphQLResponse
     where
         E: Executor,
     {
-        GraphQLResponse(executor.execute(self.0).await.into())
+        let cancel = self.0.cancellation_token.clone().drop_guard();
+        let resp = executor.execute(self.0).await;
+        cancel.disarm();
+        GraphQLResponse(resp.into())
     }
 
     /// Insert some data for this request.
@@ -14,6 +14,10 @@ use crate::GraphQLBadRequest;
 ///
 /// It outputs a tuple containing the `async_graphql::Executor` and
 /// `async_graphql::BatchRequest`.
+///
+/// To cancel the requests when the client disconnects, set a token with
+/// `BatchRequest::cancellation_token` and hold its drop guard while executing
+/// them.
 pub fn graphql_batch<E>(
     executor: E,
 ) -> impl Filter<Extract = ((E, BatchRequest),), Error = Rejection> + Clone
@@ -44,7 +48,7 @@ where
             .and(warp::header::optional::<String>("content-type"))
             .and(warp::body::stream())
             .and_then(move |content_type, body| async move {
-                async_graphql::http::receive_batch_body(
+                async_graphql::http::receive_batch_body_streaming(
                     content_type,
                     TryStreamExt::map_err(body, io::Error::other)
                         .map_ok(|mut buf| {
@@ -1,4 +1,12 @@
 //! Async-graphql integration with Warp
+//!
+//! # Cancellation
+//!
+//! The filters only extract the requests, so they do not cancel the
+//! [`CancellationToken`](async_graphql::CancellationToken) of a request when
+//! the client disconnects. To cancel it, hold the guard returned by
+//! `request.cancellation_token.clone().drop_guard()` while executing the
+//! request, and disarm it once the response is ready, see [`graphql`].
 
 #![allow(clippy::type_complexity)]
 #![forbid(unsafe_code)]
@@ -34,9 +34,11 @@ use crate::{GraphQLBadRequest, GraphQLBatchResponse, graphql_batch_opts};
 /// let schema = Schema::new(QueryRoot, EmptyMutation, EmptySubscription);
 /// let filter = async_graphql_warp::graphql(schema).and_then(
 ///     |(schema, request): (MySchema, async_graphql::Request)| async move {
-///         Ok::<_, Infallible>(async_graphql_warp::GraphQLResponse::from(
-///             schema.execute(request).await,
-///         ))
+///         // Dropping the future when the client disconnects cancels the request.
+///         let cancel = request.cancellation_token.clone().drop_guard();
+///         let resp = schema.execute(request).await;
+///         cancel.disarm();
+///         Ok::<_, Infallible>(async_graphql_warp::GraphQLResponse::from(resp))
 ///     },
 /// );
 /// warp::serve(filter).run(([0, 0, 0, 0], 8000)).await;
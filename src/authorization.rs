@@ -0,0 +1,234 @@
+use std::{collections::HashSet, sync::Arc};
+
+use crate::{
+    Context, ErrorCode, ServerError, ServerResult, Value,
+    registry::{MetaField, MetaType, MetaTypeName, Registry},
+};
+
+/// The scopes granted to the caller of a request, see [`ScopesFromData`].
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Scopes(HashSet<String>);
+
+impl Scopes {
+    /// Create a set of scopes.
+    pub fn new<I: IntoIterator<Item = T>, T: Into<String>>(scopes: I) -> Self {
+        Self(scopes.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns `true` if the scope is granted.
+    pub fn contains(&self, scope: &str) -> bool {
+        self.0.contains(scope)
+    }
+
+    /// Returns an iterator over the granted scopes.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+}
+
+impl<T: Into<String>> FromIterator<T> for Scopes {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// Provides the scopes granted to the caller of a request, see
+/// [`Authorization`].
+pub trait ScopeProvider: Send + Sync + 'static {
+    /// Returns the scopes granted to the caller, or `None` if the caller is
+    /// not authenticated.
+    fn scopes<'a>(&self, ctx: &Context<'a>) -> Option<&'a Scopes>;
+}
+
+/// A [`ScopeProvider`] reading the [`Scopes`] from the context data, the
+/// caller is authenticated when the data contains them.
+pub struct ScopesFromData;
+
+impl ScopeProvider for ScopesFromData {
+    fn scopes<'a>(&self, ctx: &Context<'a>) -> Option<&'a Scopes> {
+        ctx.data_opt::<Scopes>()
+    }
+}
+
+/// Enforces `@authenticated` and `@requiresScopes` at execution time, see
+/// [`SchemaBuilder::authorization`](crate::SchemaBuilder::authorization).
+///
+/// The requirements follow the Apollo Federation directives: a field can be
+/// accessed when both the requirements of the field and of the type it returns
+/// are satisfied. Each item of `requires_scopes` is a space separated list of
+/// scopes which must all be granted, and at least one of the items must be
+/// satisfied.
+///
+/// An unauthorized field resolves to `null` with an
+/// `UNAUTHORIZED_FIELD_OR_TYPE` error, which is propagated to the parent field
+/// if the field is non-null.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn public(&self) -> i32 {
+///         1
+///     }
+///
+///     #[graphql(requires_scopes = "read:secret")]
+///     async fn secret(&self) -> Option<i32> {
+///         Some(2)
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .authorization(Authorization::new(ScopesFromData))
+///     .finish();
+///
+/// let request = Request::new("{ public secret }").data(Scopes::new(["read:secret"]));
+/// assert_eq!(
+///     schema.execute(request).await.data,
+///     value!({ "public": 1, "secret": 2 })
+/// );
+///
+/// let resp = schema.execute("{ public secret }").await;
+/// assert_eq!(resp.data, value!({ "public": 1, "secret": null }));
+/// assert_eq!(resp.errors[0].message, "Unauthorized field or type");
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct Authorization {
+    provider: Arc<dyn ScopeProvider>,
+    hide_unauthorized: bool,
+}
+
+impl Authorization {
+    /// Create an `Authorization` getting the scopes of the caller from
+    /// `provider`.
+    pub fn new(provider: impl ScopeProvider) -> Self {
+        Self {
+            provider: Arc::new(provider),
+            hide_unauthorized: false,
+        }
+    }
+
+    /// Hide the fields and types the caller cannot access from introspection.
+    #[must_use]
+    pub fn hide_unauthorized(self) -> Self {
+        Self {
+            hide_unauthorized: true,
+            ..self
+        }
+    }
+
+    /// Returns `true` if the caller can access the field, checking the
+    /// requirements of the field and of the type it returns.
+    pub(crate) fn is_field_authorized(
+        &self,
+        ctx: &Context<'_>,
+        registry: &Registry,
+        field: &MetaField,
+    ) -> bool {
+        let scopes = self.provider.scopes(ctx);
+        is_satisfied(scopes, field.authenticated, &field.requires_scopes)
+            && registry
+                .types
+                .get(MetaTypeName::concrete_typename(&field.ty))
+                .is_none_or(|ty| {
+                    let (authenticated, requires_scopes) = ty.access_requirements();
+                    is_satisfied(scopes, authenticated, requires_scopes)
+                })
+    }
+
+    /// Returns `true` if the caller can access the type.
+    pub(crate) fn is_type_authorized(&self, ctx: &Context<'_>, ty: &MetaType) -> bool {
+        let (authenticated, requires_scopes) = ty.access_requirements();
+        is_satisfied(self.provider.scopes(ctx), authenticated, requires_scopes)
+    }
+
+    #[inline]
+    pub(crate) fn hides_unauthorized(&self) -> bool {
+        self.hide_unauthorized
+    }
+}
+
+fn is_satisfied(scopes: Option<&Scopes>, authenticated: bool, requires_scopes: &[String]) -> bool {
+    if !authenticated && requires_scopes.is_empty() {
+        return true;
+    }
+    let Some(scopes) = scopes else {
+        return false;
+    };
+    requires_scopes.is_empty()
+        || requires_scopes
+            .iter()
+            .any(|group| group.split_whitespace().all(|scope| scopes.contains(scope)))
+}
+
+/// Returns the error of the field of `ctx` on the object `parent_type` if the
+/// caller cannot access it, with the definition of the field.
+///
+/// The requirements of the field on the interfaces implemented by the object
+/// are checked too.
+fn field_access_error<'a>(
+    ctx: &Context<'a>,
+    parent_type: &str,
+) -> Option<(ServerError, &'a MetaField)> {
+    let authorization = ctx.schema_env.authorization.as_ref()?;
+    if ctx.is_for_introspection {
+        return None;
+    }
+    let registry = &ctx.schema_env.registry;
+    let field_name = ctx.item.node.name.node.as_str();
+    let field = registry.types.get(parent_type)?.field_by_name(field_name)?;
+    let authorized = authorization.is_field_authorized(ctx, registry, field)
+        && registry
+            .implements
+            .get(parent_type)
+            .into_iter()
+            .flatten()
+            .filter_map(|interface| registry.types.get(interface)?.field_by_name(field_name))
+            .all(|field| authorization.is_field_authorized(ctx, registry, field));
+    if authorized {
+        return None;
+    }
+
+    let err = ctx.set_error_path(
+        ServerError::new("Unauthorized field or type", Some(ctx.item.pos))
+            .with_code(ErrorCode::UnauthorizedFieldOrType),
+    );
+    Some((err, field))
+}
+
+/// Checks the access to the field of `ctx` on the object `parent_type`.
+///
+/// Returns `None` if the field can be resolved, otherwise the value of the
+/// unauthorized field.
+pub(crate) fn check_field_access(
+    ctx: &Context<'_>,
+    parent_type: &str,
+) -> Option<ServerResult<Value>> {
+    let (err, field) = field_access_error(ctx, parent_type)?;
+    if MetaTypeName::create(&field.ty).is_non_null() {
+        Some(Err(err))
+    } else {
+        ctx.add_error(err);
+        Some(Ok(Value::Null))
+    }
+}
+
+/// Checks the access to the root field of `ctx` on the subscription type
+/// `parent_type`.
+///
+/// Returns the error if the caller cannot subscribe to the field.
+pub(crate) fn check_subscription_field_access(
+    ctx: &Context<'_>,
+    parent_type: &str,
+) -> ServerResult<()> {
+    match field_access_error(ctx, parent_type) {
+        Some((err, _)) => Err(err),
+        None => Ok(()),
+    }
+}
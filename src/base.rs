@@ -8,8 +8,8 @@ use std::{
 use async_graphql_value::ConstValue;
 
 use crate::{
-    ContainerType, Context, ContextSelectionSet, Error, InputValueError, InputValueResult,
-    Positioned, Result, ServerResult, Value,
+    ContainerType, Context, ContextSelectionSet, Error, GuardAction, InputValueError,
+    InputValueResult, Positioned, Result, ServerResult, Value,
     parser::types::Field,
     registry::{self, Registry},
 };
@@ -300,4 +300,13 @@ pub trait ComplexObject {
         &self,
         ctx: &Context<'_>,
     ) -> impl Future<Output = ServerResult<Option<Value>>> + Send;
+
+    #[cfg(feature = "boxed-trait")]
+    async fn check_type_guard(&self, ctx: &Context<'_>) -> Result<GuardAction>;
+
+    #[cfg(not(feature = "boxed-trait"))]
+    fn check_type_guard(
+        &self,
+        ctx: &Context<'_>,
+    ) -> impl Future<Output = Result<GuardAction>> + Send;
 }
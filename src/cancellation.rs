@@ -0,0 +1,206 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Formatter},
+    future::Future,
+    pin::Pin,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::{Context, Poll, Waker},
+};
+
+#[derive(Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    waiters: Mutex<Waiters>,
+}
+
+#[derive(Default)]
+struct Waiters {
+    next_key: usize,
+    wakers: HashMap<usize, Waker>,
+}
+
+/// A token to cooperatively cancel the execution of a request.
+///
+/// Each [`Request`](crate::Request) carries a token, which the integrations
+/// cancel when the client disconnects before the response is sent, or when a
+/// WebSocket client completes the operation. The resolvers can observe it
+/// with [`Context::cancellation_token`](crate::ContextBase::cancellation_token)
+/// to stop long running work, and the extensions with
+/// [`ExtensionContext::cancellation_token`](crate::extensions::ExtensionContext::cancellation_token).
+///
+/// Cloning the token returns a handle to the same token.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn count(&self, ctx: &Context<'_>) -> i32 {
+///         let mut count = 0;
+///         while count < 1000 && !ctx.is_cancelled() {
+///             count += 1;
+///         }
+///         count
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+///
+/// let token = CancellationToken::new();
+/// token.cancel();
+/// let request = Request::new("{ count }").cancellation_token(token);
+/// assert_eq!(schema.execute(request).await.data, value!({ "count": 0 }));
+/// # });
+/// ```
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<Inner>);
+
+impl Debug for CancellationToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+impl CancellationToken {
+    /// Create a token which is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the token, waking up the tasks waiting for it.
+    pub fn cancel(&self) {
+        if self.0.cancelled.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let wakers = std::mem::take(&mut self.0.waiters.lock().unwrap().wakers);
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+    }
+
+    /// Returns `true` if the token is cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Returns a future which completes when the token is cancelled.
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled {
+            token: self,
+            key: None,
+        }
+    }
+
+    /// Returns a guard which cancels the token when it is dropped, unless it
+    /// is disarmed first.
+    ///
+    /// The integrations hold a guard while executing a request, so that
+    /// dropping the request when the client disconnects cancels it.
+    pub fn drop_guard(self) -> CancelOnDrop {
+        CancelOnDrop(Some(self))
+    }
+}
+
+/// The future returned by [`CancellationToken::cancelled`].
+pub struct Cancelled<'a> {
+    token: &'a CancellationToken,
+    key: Option<usize>,
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        let mut waiters = self.token.0.waiters.lock().unwrap();
+        // The token may have been cancelled before the lock was acquired.
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        let key = match self.key {
+            Some(key) => key,
+            None => {
+                let key = waiters.next_key;
+                waiters.next_key = waiters.next_key.wrapping_add(1);
+                key
+            }
+        };
+        waiters.wakers.insert(key, cx.waker().clone());
+        drop(waiters);
+        self.key = Some(key);
+        Poll::Pending
+    }
+}
+
+impl Drop for Cancelled<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.token.0.waiters.lock().unwrap().wakers.remove(&key);
+        }
+    }
+}
+
+/// A guard which cancels a [`CancellationToken`] when it is dropped, see
+/// [`CancellationToken::drop_guard`].
+#[derive(Debug)]
+pub struct CancelOnDrop(Option<CancellationToken>);
+
+impl CancelOnDrop {
+    /// Returns the token without cancelling it.
+    pub fn disarm(mut self) -> CancellationToken {
+        self.0.take().unwrap()
+    }
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if let Some(token) = &self.0 {
+            token.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn cancel_wakes_waiters() {
+        let token = CancellationToken::new();
+        let waiter = tokio::spawn({
+            let token = token.clone();
+            async move { token.cancelled().await }
+        });
+        tokio::task::yield_now().await;
+        assert!(!token.is_cancelled());
+
+        token.cancel();
+        waiter.await.unwrap();
+        assert!(token.is_cancelled());
+        assert!(token.0.waiters.lock().unwrap().wakers.is_empty());
+    }
+
+    #[test]
+    fn drop_guard() {
+        let token = CancellationToken::new();
+        drop(token.clone().drop_guard());
+        assert!(token.is_cancelled());
+
+        let token = CancellationToken::new();
+        token.clone().drop_guard().disarm();
+        assert!(!token.is_cancelled());
+    }
+}
@@ -0,0 +1,48 @@
+use std::future::Future;
+
+use futures_util::{StreamExt, TryStreamExt, stream};
+
+use crate::{Context, ContextBase, ServerResult};
+
+/// Sets the concurrency limit of `ctx` to the `max_concurrency` of its field
+/// on the object `parent_type`.
+pub(crate) fn field_concurrency_limit(ctx: &mut Context<'_>, parent_type: &str) {
+    if ctx.is_for_introspection {
+        return;
+    }
+    ctx.concurrency_limit = ctx
+        .schema_env
+        .registry
+        .types
+        .get(parent_type)
+        .and_then(|ty| ty.field_by_name(ctx.item.node.name.node.as_str()))
+        .and_then(|field| field.max_concurrency);
+}
+
+/// Awaits all the `futures`, polling at most the lowest of the concurrency
+/// limits of the field of `ctx` and of the schema at once.
+///
+/// The results are returned in order, and the first error is returned as soon
+/// as it is reached.
+pub(crate) async fn try_join_limited<T, I, F, V>(
+    ctx: &ContextBase<'_, T>,
+    futures: I,
+) -> ServerResult<Vec<V>>
+where
+    I: IntoIterator<Item = F>,
+    F: Future<Output = ServerResult<V>>,
+{
+    let limit = match (ctx.concurrency_limit, ctx.schema_env.concurrency_limit) {
+        (Some(field_limit), Some(schema_limit)) => Some(field_limit.min(schema_limit)),
+        (field_limit, schema_limit) => field_limit.or(schema_limit),
+    };
+    match limit {
+        Some(limit) => {
+            stream::iter(futures)
+                .buffered(limit.get())
+                .try_collect()
+                .await
+        }
+        None => futures_util::future::try_join_all(futures).await,
+    }
+}
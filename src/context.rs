@@ -4,8 +4,10 @@ use std::{
     any::{Any, TypeId},
     collections::HashMap,
     fmt::{self, Debug, Display, Formatter},
+    num::NonZeroUsize,
     ops::Deref,
     sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 
 use async_graphql_parser::types::ConstDirective;
@@ -18,13 +20,15 @@ use serde::{
 };
 
 use crate::{
-    Error, InputType, Lookahead, Name, OneofObjectType, PathSegment, Pos, Positioned, Result,
-    ServerError, ServerResult, UploadValue, Value,
+    CancellationToken, Error, ErrorCode, InputType, Lookahead, Name, OneofObjectType, PathSegment,
+    Pos, Positioned, Result, ServerError, ServerResult, UploadValue, Value,
     extensions::Extensions,
+    http::MultipartUploads,
     parser::types::{
         Directive, Field, FragmentDefinition, OperationDefinition, Selection, SelectionSet,
     },
     schema::{IntrospectionMode, SchemaEnv},
+    timeout::run_until_deadline,
 };
 
 /// Data related functions of the context.
@@ -244,6 +248,12 @@ pub struct ContextBase<'a, T> {
     pub query_env: &'a QueryEnv,
     #[doc(hidden)]
     pub execute_data: Option<&'a Data>,
+    /// The deadline of the request, or of the fields being resolved if they
+    /// have a timeout.
+    pub(crate) deadline: Option<Instant>,
+    /// The maximum number of list items and fields of the value of the
+    /// current field resolved concurrently, if the field has one.
+    pub(crate) concurrency_limit: Option<NonZeroUsize>,
 }
 
 #[doc(hidden)]
@@ -254,11 +264,13 @@ pub struct QueryEnvInner {
     pub operation: Positioned<OperationDefinition>,
     pub fragments: HashMap<Name, Positioned<FragmentDefinition>>,
     pub uploads: Vec<UploadValue>,
+    pub(crate) upload_stream: Option<Arc<MultipartUploads>>,
     pub session_data: Arc<Data>,
     pub query_data: Arc<Data>,
     pub http_headers: Mutex<http::HeaderMap>,
     pub introspection_mode: IntrospectionMode,
     pub errors: Mutex<Vec<ServerError>>,
+    pub deadline: Option<Instant>,
 }
 
 #[doc(hidden)]
@@ -294,6 +306,8 @@ impl QueryEnv {
             schema_env,
             query_env: self,
             execute_data,
+            deadline: self.deadline,
+            concurrency_limit: None,
         }
     }
 }
@@ -328,6 +342,8 @@ impl<'a, T> ContextBase<'a, T> {
             schema_env: self.schema_env,
             query_env: self.query_env,
             execute_data: self.execute_data,
+            deadline: self.deadline,
+            concurrency_limit: None,
         }
     }
 
@@ -343,6 +359,8 @@ impl<'a, T> ContextBase<'a, T> {
             schema_env: self.schema_env,
             query_env: self.query_env,
             execute_data: self.execute_data,
+            deadline: self.deadline,
+            concurrency_limit: self.concurrency_limit,
         }
     }
 
@@ -409,6 +427,63 @@ impl<'a, T> ContextBase<'a, T> {
             .and_then(|d| d.downcast_ref::<D>())
     }
 
+    /// Returns the instant the current field must be resolved by, from the
+    /// deadline of the request and the timeouts of the fields.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Returns the time left before the deadline of the current field, see
+    /// [`ContextBase::deadline`].
+    pub fn remaining_time(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Awaits `fut`, failing with a `TIMEOUT` error if the deadline of the
+    /// current field passes first.
+    ///
+    /// The deadline is only enforced when a timer is set with
+    /// [`SchemaBuilder::timer`](crate::SchemaBuilder::timer).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use async_graphql::*;
+    ///
+    /// struct Query;
+    ///
+    /// #[Object]
+    /// impl Query {
+    ///     async fn slow(&self, ctx: &Context<'_>) -> Result<i32> {
+    ///         ctx.until_deadline(async {
+    ///             tokio::time::sleep(Duration::from_secs(10)).await;
+    ///             10
+    ///         })
+    ///         .await
+    ///     }
+    /// }
+    /// ```
+    pub async fn until_deadline<F: Future>(&self, fut: F) -> Result<F::Output> {
+        run_until_deadline(self.schema_env.timer.as_deref(), self.deadline, fut)
+            .await
+            .ok_or_else(|| Error::new("Deadline exceeded").with_code(ErrorCode::Timeout))
+    }
+
+    /// Returns the token cancelled when the client does not wait for the
+    /// response anymore, see [`CancellationToken`].
+    pub fn cancellation_token(&self) -> &'a CancellationToken {
+        self.query_env.extensions.cancellation_token()
+    }
+
+    /// Returns `true` if the request is cancelled, so that long running
+    /// resolvers can stop early.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation_token().is_cancelled()
+    }
+
     /// Returns whether the HTTP header `key` is currently set on the response
     ///
     /// # Examples
@@ -560,6 +635,7 @@ impl<'a, T> ContextBase<'a, T> {
             .find(|def| def.node.name.node == name)
             .ok_or_else(|| {
                 ServerError::new(format!("Variable {} is not defined.", name), Some(pos))
+                    .with_code(ErrorCode::BadUserInput)
             })?;
 
         // Preserve omitted variables as `None`; only explicit variable defaults become
@@ -663,6 +739,8 @@ impl<'a, T> ContextBase<'a, T> {
             schema_env: self.schema_env,
             query_env: self.query_env,
             execute_data: self.execute_data,
+            deadline: self.deadline,
+            concurrency_limit: self.concurrency_limit,
         }
     }
 }
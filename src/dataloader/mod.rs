@@ -70,23 +70,28 @@ use std::{
         Arc,
         atomic::{AtomicBool, Ordering},
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 pub use cache::{CacheFactory, CacheStorage, HashMapCache, LruCache, NoCache};
 use futures_channel::oneshot;
-use futures_util::task::{Spawn, SpawnExt};
+use futures_util::{
+    future::{Either, join_all, select},
+    task::{Spawn, SpawnExt},
+};
 use rustc_hash::FxBuildHasher;
 #[cfg(feature = "tracing")]
 use tracing::{Instrument, info_span, instrument};
 
-use crate::runtime::Timer;
+use crate::{CancellationToken, Context, runtime::Timer};
 
 type FxHashMap<K, V> = scc::HashMap<K, V, FxBuildHasher>;
 
 #[allow(clippy::type_complexity)]
 struct ResSender<K: Send + Sync + Hash + Eq + Clone + 'static, T: Loader<K>> {
     use_cache_values: HashMap<K, T::Value>,
+    deadline: Option<Instant>,
+    cancellation_token: Option<CancellationToken>,
     tx: oneshot::Sender<Result<HashMap<K, T::Value>, T::Error>>,
 }
 
@@ -136,6 +141,38 @@ pub trait Loader<K: Send + Sync + Hash + Eq + Clone + 'static>: Send + Sync + 's
         &self,
         keys: &[K],
     ) -> impl Future<Output = Result<HashMap<K, Self::Value>, Self::Error>> + Send;
+
+    /// Load the data set specified by the `keys`, before the `deadline`.
+    ///
+    /// The deadline is the latest deadline of the loads of the batch made with
+    /// [`DataLoader::load_one_with_context`] or
+    /// [`DataLoader::load_many_with_context`], or `None` if one of them has no
+    /// deadline. The default implementation calls [`Loader::load`].
+    #[cfg(feature = "boxed-trait")]
+    async fn load_until(
+        &self,
+        keys: &[K],
+        deadline: Option<Instant>,
+    ) -> Result<HashMap<K, Self::Value>, Self::Error> {
+        let _ = deadline;
+        self.load(keys).await
+    }
+
+    /// Load the data set specified by the `keys`, before the `deadline`.
+    ///
+    /// The deadline is the latest deadline of the loads of the batch made with
+    /// [`DataLoader::load_one_with_context`] or
+    /// [`DataLoader::load_many_with_context`], or `None` if one of them has no
+    /// deadline. The default implementation calls [`Loader::load`].
+    #[cfg(not(feature = "boxed-trait"))]
+    fn load_until(
+        &self,
+        keys: &[K],
+        deadline: Option<Instant>,
+    ) -> impl Future<Output = Result<HashMap<K, Self::Value>, Self::Error>> + Send {
+        let _ = deadline;
+        self.load(keys)
+    }
 }
 
 struct DataLoaderInner<T> {
@@ -145,15 +182,42 @@ struct DataLoaderInner<T> {
 
 impl<T> DataLoaderInner<T> {
     #[cfg_attr(feature = "tracing", instrument(skip_all))]
-    async fn do_load<K>(&self, disable_cache: bool, (keys, senders): KeysAndSender<K, T>)
+    async fn do_load<K>(&self, disable_cache: bool, (keys, mut senders): KeysAndSender<K, T>)
     where
         K: Send + Sync + Hash + Eq + Clone + 'static,
         T: Loader<K>,
     {
         let tid = TypeId::of::<K>();
         let keys = keys.into_iter().collect::<Vec<_>>();
+        // The batch is loaded for all the senders, so until the latest deadline.
+        let deadline = senders
+            .iter()
+            .map(|(_, sender)| sender.deadline)
+            .collect::<Option<Vec<_>>>()
+            .and_then(|deadlines| deadlines.into_iter().max());
+
+        // Stop loading once all the loads waiting for the batch are dropped or
+        // their request is cancelled.
+        let all_cancelled = join_all(senders.iter_mut().map(|(_, sender)| {
+            let dropped = sender.tx.cancellation();
+            let cancellation_token = sender.cancellation_token.as_ref();
+            async move {
+                match cancellation_token {
+                    Some(token) => {
+                        select(dropped, std::pin::pin!(token.cancelled())).await;
+                    }
+                    None => dropped.await,
+                }
+            }
+        }));
+        let load = self.loader.load_until(&keys, deadline);
+        futures_util::pin_mut!(load);
+        let res = match select(all_cancelled, load).await {
+            Either::Left(_) => return,
+            Either::Right((res, _)) => res,
+        };
 
-        match self.loader.load(&keys).await {
+        match res {
             Ok(values) => {
                 // update cache
                 let mut entry = self.requests.get_async(&tid).await.unwrap();
@@ -300,6 +364,61 @@ impl<T, C: CacheFactory> DataLoader<T, C> {
     /// Use this `DataLoader` to load some data.
     #[cfg_attr(feature = "tracing", instrument(skip_all))]
     pub async fn load_many<K, I>(&self, keys: I) -> Result<HashMap<K, T::Value>, T::Error>
+    where
+        K: Send + Sync + Hash + Eq + Clone + 'static,
+        I: IntoIterator<Item = K>,
+        T: Loader<K>,
+    {
+        self.load_many_until(keys, None, None).await
+    }
+
+    /// Use this `DataLoader` load a data for the field of `ctx`.
+    ///
+    /// The deadline of the field is passed to [`Loader::load_until`]. If the
+    /// request is cancelled, the batch stops loading once no other request
+    /// waits for it, and `None` is returned.
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    pub async fn load_one_with_context<K>(
+        &self,
+        ctx: &Context<'_>,
+        key: K,
+    ) -> Result<Option<T::Value>, T::Error>
+    where
+        K: Send + Sync + Hash + Eq + Clone + 'static,
+        T: Loader<K>,
+    {
+        let mut values = self
+            .load_many_with_context(ctx, std::iter::once(key.clone()))
+            .await?;
+        Ok(values.remove(&key))
+    }
+
+    /// Use this `DataLoader` to load some data for the field of `ctx`.
+    ///
+    /// The deadline of the field is passed to [`Loader::load_until`]. If the
+    /// request is cancelled, the batch stops loading once no other request
+    /// waits for it, and no values are returned.
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    pub async fn load_many_with_context<K, I>(
+        &self,
+        ctx: &Context<'_>,
+        keys: I,
+    ) -> Result<HashMap<K, T::Value>, T::Error>
+    where
+        K: Send + Sync + Hash + Eq + Clone + 'static,
+        I: IntoIterator<Item = K>,
+        T: Loader<K>,
+    {
+        self.load_many_until(keys, ctx.deadline(), Some(ctx.cancellation_token().clone()))
+            .await
+    }
+
+    async fn load_many_until<K, I>(
+        &self,
+        keys: I,
+        deadline: Option<Instant>,
+        cancellation_token: Option<CancellationToken>,
+    ) -> Result<HashMap<K, T::Value>, T::Error>
     where
         K: Send + Sync + Hash + Eq + Clone + 'static,
         I: IntoIterator<Item = K>,
@@ -352,6 +471,8 @@ impl<T, C: CacheFactory> DataLoader<T, C> {
                 keys_set,
                 ResSender {
                     use_cache_values,
+                    deadline,
+                    cancellation_token: cancellation_token.clone(),
                     tx,
                 },
             ));
@@ -408,7 +529,18 @@ impl<T, C: CacheFactory> DataLoader<T, C> {
             Action::Delay => {}
         }
 
-        rx.await.unwrap()
+        match rx.await {
+            Ok(res) => res,
+            // The batch is dropped when all the requests waiting for it are cancelled.
+            Err(_)
+                if cancellation_token
+                    .as_ref()
+                    .is_some_and(CancellationToken::is_cancelled) =>
+            {
+                Ok(HashMap::new())
+            }
+            Err(err) => panic!("the batch load was dropped: {err}"),
+        }
     }
 
     /// Feed some data into the cache.
@@ -579,6 +711,53 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_load_until() {
+        struct DeadlineLoader(std::sync::Mutex<Vec<Option<Instant>>>);
+
+        #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+        impl Loader<i32> for DeadlineLoader {
+            type Value = i32;
+            type Error = ();
+
+            async fn load(&self, _keys: &[i32]) -> Result<HashMap<i32, Self::Value>, Self::Error> {
+                unreachable!()
+            }
+
+            async fn load_until(
+                &self,
+                keys: &[i32],
+                deadline: Option<Instant>,
+            ) -> Result<HashMap<i32, Self::Value>, Self::Error> {
+                self.0.lock().unwrap().push(deadline);
+                Ok(keys.iter().copied().map(|k| (k, k)).collect())
+            }
+        }
+
+        let loader = DataLoader::new(
+            DeadlineLoader(Default::default()),
+            TokioSpawner::current(),
+            TokioTimer::default(),
+        );
+        let now = Instant::now();
+        let early = now + Duration::from_secs(1);
+        let late = now + Duration::from_secs(2);
+
+        futures_util::future::try_join(
+            loader.load_many_until([1], Some(early), None),
+            loader.load_many_until([2], Some(late), None),
+        )
+        .await
+        .unwrap();
+        futures_util::future::try_join(
+            loader.load_many_until([3], Some(early), None),
+            loader.load_many_until([4], None, None),
+        )
+        .await
+        .unwrap();
+        assert_eq!(*loader.loader().0.lock().unwrap(), [Some(late), None]);
+    }
+
     #[tokio::test]
     async fn test_duplicate_keys() {
         let loader = Arc::new(
@@ -791,4 +970,40 @@ mod tests {
         handle.abort();
         loader.load_many(vec![4, 5, 6]).await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_dataloader_stops_loading_without_waiters() {
+        struct SlowLoader(Arc<AtomicBool>);
+
+        #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+        impl Loader<i32> for SlowLoader {
+            type Value = i32;
+            type Error = ();
+
+            async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, Self::Value>, Self::Error> {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+                self.0.store(true, Ordering::SeqCst);
+                Ok(keys.iter().copied().map(|k| (k, k)).collect())
+            }
+        }
+
+        let loaded = Arc::new(AtomicBool::new(false));
+        let loader = Arc::new(DataLoader::new(
+            SlowLoader(loaded.clone()),
+            TokioSpawner::current(),
+            TokioTimer::default(),
+        ));
+        let handle = tokio::spawn({
+            let loader = loader.clone();
+            async move { loader.load_one(1).await }
+        });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        handle.abort();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(!loaded.load(Ordering::SeqCst));
+
+        assert_eq!(loader.load_one(2).await, Ok(Some(2)));
+        assert!(loaded.load(Ordering::SeqCst));
+    }
 }
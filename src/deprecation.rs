@@ -0,0 +1,124 @@
+use std::time::SystemTime;
+
+use indexmap::IndexMap;
+
+use crate::{DeprecatedUsage, ErrorCode, Name, Response, ServerError, Value};
+
+/// Reports the usage of deprecated fields, arguments, input fields and enum
+/// values, see
+/// [`SchemaBuilder::deprecation_reporting`](crate::SchemaBuilder::deprecation_reporting).
+///
+/// The usage of the executed operation is available to the extensions with
+/// [`ExtensionContext::deprecated_usage`](crate::extensions::ExtensionContext::deprecated_usage).
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     #[graphql(deprecation = "Use `value`")]
+///     async fn old_value(&self) -> i32 {
+///         10
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .deprecation_reporting(DeprecationReporting::new().warn())
+///     .finish();
+///
+/// let resp = schema.execute("{ oldValue }").await;
+/// assert_eq!(
+///     resp.extensions["deprecations"],
+///     value!([{
+///         "coordinate": "Query.oldValue",
+///         "reason": "Use `value`",
+///         "locations": [{ "line": 1, "column": 3 }],
+///     }])
+/// );
+/// # });
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DeprecationReporting {
+    warn: bool,
+    reject_after: Option<SystemTime>,
+}
+
+impl DeprecationReporting {
+    /// Create a `DeprecationReporting`, which only collects the usage for the
+    /// extensions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the usage to the `deprecations` extension of the responses.
+    #[must_use]
+    pub fn warn(self) -> Self {
+        Self { warn: true, ..self }
+    }
+
+    /// Reject the operations using deprecated elements after the sunset date.
+    #[must_use]
+    pub fn reject_after(self, sunset: SystemTime) -> Self {
+        Self {
+            reject_after: Some(sunset),
+            ..self
+        }
+    }
+
+    pub(crate) fn check(&self, deprecations: &[DeprecatedUsage]) -> Result<(), Vec<ServerError>> {
+        match self.reject_after {
+            Some(sunset) if !deprecations.is_empty() && SystemTime::now() >= sunset => {
+                Err(deprecations
+                    .iter()
+                    .map(|usage| {
+                        let mut message =
+                            format!("\"{}\" is no longer supported.", usage.coordinate);
+                        if let Some(reason) = &usage.reason {
+                            message.push(' ');
+                            message.push_str(reason);
+                        }
+                        ServerError::new(message, Some(usage.pos))
+                            .with_code(ErrorCode::GraphqlValidationFailed)
+                    })
+                    .collect())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn report(&self, deprecations: &[DeprecatedUsage], resp: &mut Response) {
+        if !self.warn || deprecations.is_empty() {
+            return;
+        }
+        let deprecations = deprecations
+            .iter()
+            .map(|usage| {
+                let mut location = IndexMap::new();
+                location.insert(Name::new("line"), Value::from(usage.pos.line));
+                location.insert(Name::new("column"), Value::from(usage.pos.column));
+
+                let mut map = IndexMap::new();
+                map.insert(
+                    Name::new("coordinate"),
+                    Value::from(usage.coordinate.as_str()),
+                );
+                map.insert(
+                    Name::new("reason"),
+                    usage.reason.as_deref().map(Value::from).unwrap_or_default(),
+                );
+                map.insert(
+                    Name::new("locations"),
+                    Value::List(vec![Value::Object(location)]),
+                );
+                Value::Object(map)
+            })
+            .collect();
+        resp.extensions
+            .insert("deprecations".to_string(), Value::List(deprecations));
+    }
+}
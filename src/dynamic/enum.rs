@@ -55,6 +55,7 @@ pub struct Enum {
     tags: Vec<String>,
     pub(crate) directives: Vec<Directive>,
     requires_scopes: Vec<String>,
+    authenticated: bool,
 }
 
 impl Enum {
@@ -69,6 +70,7 @@ impl Enum {
             tags: Vec::new(),
             directives: Vec::new(),
             requires_scopes: Vec::new(),
+            authenticated: false,
         }
     }
 
@@ -94,6 +96,8 @@ impl Enum {
 
     impl_set_inaccessible!();
     impl_set_tags!();
+    impl_set_requires_scopes!();
+    impl_set_authenticated!();
 
     /// Returns the type name
     #[inline]
@@ -131,6 +135,7 @@ impl Enum {
                 rust_typename: None,
                 directive_invocations: to_meta_directive_invocation(self.directives.clone()),
                 requires_scopes: self.requires_scopes.clone(),
+                authenticated: self.authenticated,
             },
         );
 
@@ -2,7 +2,9 @@ use std::{
     any::Any,
     borrow::Cow,
     fmt::{self, Debug},
+    num::NonZeroUsize,
     ops::Deref,
+    time::Duration,
 };
 
 use futures_util::{Future, FutureExt, future::BoxFuture};
@@ -10,7 +12,7 @@ use indexmap::IndexMap;
 
 use super::Directive;
 use crate::{
-    Context, Error, Result, Value,
+    Context, Error, GuardAction, Result, Value,
     dynamic::{InputValue, ObjectAccessor, TypeRef},
     registry::Deprecation,
 };
@@ -316,6 +318,51 @@ impl<'a> FieldFuture<'a> {
 pub(crate) type BoxResolverFn =
     Box<dyn for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync>;
 
+pub(crate) type BoxResultGuardFn =
+    Box<dyn Fn(&Context<'_>, &FieldValue<'_>) -> Result<GuardAction> + Send + Sync>;
+
+pub(crate) type BoxTypeGuardFn = Box<dyn Fn(&Context<'_>) -> Result<()> + Send + Sync>;
+
+/// The guard of an object, interface or union type.
+pub(crate) struct TypeGuardFn(pub(crate) BoxTypeGuardFn);
+
+impl Debug for TypeGuardFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TypeGuardFn")
+    }
+}
+
+type BoxBatchEntityFut<'a> = BoxFuture<'a, Result<Vec<Result<Option<FieldValue<'a>>>>>>;
+
+/// A future that returned from batch entity resolver
+///
+/// It resolves to one entry per representation, in the same order as the
+/// representations were passed to the resolver.
+pub struct BatchEntityFuture<'a>(pub(crate) BoxBatchEntityFut<'a>);
+
+impl<'a> BatchEntityFuture<'a> {
+    /// Create a `BatchEntityFuture` from a `Future`
+    pub fn new<Fut, R>(future: Fut) -> Self
+    where
+        Fut: Future<Output = Result<Vec<Result<Option<R>>>>> + Send + 'a,
+        R: Into<FieldValue<'a>> + Send,
+    {
+        Self(
+            async move {
+                let res = future.await?;
+                Ok(res
+                    .into_iter()
+                    .map(|item| item.map(|value| value.map(Into::into)))
+                    .collect())
+            }
+            .boxed(),
+        )
+    }
+}
+
+pub(crate) type BoxBatchEntityResolverFn =
+    Box<dyn for<'a> Fn(ResolverContext<'a>) -> BatchEntityFuture<'a> + Send + Sync>;
+
 /// A GraphQL field
 pub struct Field {
     pub(crate) name: String,
@@ -334,6 +381,10 @@ pub struct Field {
     pub(crate) override_from: Option<String>,
     pub(crate) directives: Vec<Directive>,
     pub(crate) requires_scopes: Vec<String>,
+    pub(crate) authenticated: bool,
+    pub(crate) guard_result: Option<BoxResultGuardFn>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) max_concurrency: Option<NonZeroUsize>,
 }
 
 impl Debug for Field {
@@ -374,6 +425,10 @@ impl Field {
             override_from: None,
             directives: Vec::new(),
             requires_scopes: Vec::new(),
+            authenticated: false,
+            guard_result: None,
+            timeout: None,
+            max_concurrency: None,
         }
     }
 
@@ -385,6 +440,8 @@ impl Field {
     impl_set_shareable!();
     impl_set_inaccessible!();
     impl_set_tags!();
+    impl_set_requires_scopes!();
+    impl_set_authenticated!();
     impl_set_override_from!();
     impl_directive!();
 
@@ -394,4 +451,48 @@ impl Field {
         self.arguments.insert(input_value.name.clone(), input_value);
         self
     }
+
+    /// Set a post-resolution guard, which is checked with the resolved value
+    /// of the field, see [`ResultGuard`](crate::ResultGuard).
+    ///
+    /// The guard is not called if the resolver returns `None`.
+    #[inline]
+    pub fn guard_result<F>(self, f: F) -> Self
+    where
+        F: Fn(&Context<'_>, &FieldValue<'_>) -> Result<GuardAction> + Send + Sync + 'static,
+    {
+        Self {
+            guard_result: Some(Box::new(f)),
+            ..self
+        }
+    }
+
+    /// Set the maximum time the field is allowed to take to resolve.
+    ///
+    /// Only enforced when a timer is set with
+    /// [`SchemaBuilder::timer`](crate::dynamic::SchemaBuilder::timer).
+    #[inline]
+    pub fn timeout(self, timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Set the maximum number of list items and fields of the value of the
+    /// field resolved concurrently. It can only lower
+    /// [`SchemaBuilder::limit_concurrency`](crate::dynamic::SchemaBuilder::limit_concurrency).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_concurrency` is zero.
+    #[inline]
+    pub fn max_concurrency(self, max_concurrency: usize) -> Self {
+        Self {
+            max_concurrency: Some(
+                NonZeroUsize::new(max_concurrency).expect("the concurrency limit must not be zero"),
+            ),
+            ..self
+        }
+    }
 }
@@ -1,11 +1,19 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
 use indexmap::IndexMap;
 
 use super::{Directive, directive::to_meta_directive_invocation};
 use crate::{
-    dynamic::InputValue,
+    InputFieldErrors,
+    dynamic::{InputValue, ObjectAccessor},
     registry::{MetaInputValue, MetaType, Registry},
 };
 
+type InputObjectValidatorFn = Arc<dyn Fn(&ObjectAccessor<'_>, &mut InputFieldErrors) + Send + Sync>;
+
 /// A GraphQL input object type
 ///
 /// # Examples
@@ -50,17 +58,31 @@ use crate::{
 /// # Ok::<_, SchemaError>(())
 /// # }).unwrap();
 /// ```
-#[derive(Debug)]
 pub struct InputObject {
     pub(crate) name: String,
     pub(crate) description: Option<String>,
     pub(crate) fields: IndexMap<String, InputValue>,
     pub(crate) oneof: bool,
+    pub(crate) validator: Option<InputObjectValidatorFn>,
     inaccessible: bool,
     tags: Vec<String>,
     directives: Vec<Directive>,
 }
 
+impl Debug for InputObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InputObject")
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .field("fields", &self.fields)
+            .field("oneof", &self.oneof)
+            .field("inaccessible", &self.inaccessible)
+            .field("tags", &self.tags)
+            .field("directives", &self.directives)
+            .finish()
+    }
+}
+
 impl InputObject {
     /// Create a GraphQL input object type
     #[inline]
@@ -70,6 +92,7 @@ impl InputObject {
             description: None,
             fields: Default::default(),
             oneof: false,
+            validator: None,
             inaccessible: false,
             tags: Vec::new(),
             directives: Vec::new(),
@@ -101,6 +124,22 @@ impl InputObject {
         }
     }
 
+    /// Set a validator checking several fields together, see
+    /// [`InputObjectValidator`](crate::InputObjectValidator).
+    ///
+    /// It is called with the value of the input object before the resolver
+    /// of a field using it in its arguments, and the field fails with all the
+    /// reported errors.
+    pub fn validator(
+        self,
+        validator: impl Fn(&ObjectAccessor<'_>, &mut InputFieldErrors) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            validator: Some(Arc::new(validator)),
+            ..self
+        }
+    }
+
     /// Returns the type name
     #[inline]
     pub fn type_name(&self) -> &str {
@@ -124,6 +163,7 @@ impl InputObject {
                     tags: self.tags.clone(),
                     is_secret: false,
                     directive_invocations: to_meta_directive_invocation(field.directives.clone()),
+                    constraints: Default::default(),
                 },
             );
         }
@@ -149,7 +189,7 @@ impl InputObject {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Pos, ServerError, Value, dynamic::*, value};
+    use crate::{ErrorExtensionValues, PathSegment, Pos, ServerError, Value, dynamic::*, value};
 
     #[tokio::test]
     async fn input_object() {
@@ -259,7 +299,12 @@ mod tests {
                 source: None,
                 locations: vec![Pos { column: 9, line: 1 }],
                 path: vec![],
-                extensions: None,
+                extensions: Some({
+                    let mut extensions = ErrorExtensionValues::default();
+                    extensions.set("code", "GRAPHQL_VALIDATION_FAILED");
+                    extensions.set("rule", "ArgumentsOfCorrectType");
+                    extensions
+                }),
             }]
         );
 
@@ -274,7 +319,12 @@ mod tests {
                 source: None,
                 locations: vec![Pos { column: 9, line: 1 }],
                 path: vec![],
-                extensions: None,
+                extensions: Some({
+                    let mut extensions = ErrorExtensionValues::default();
+                    extensions.set("code", "GRAPHQL_VALIDATION_FAILED");
+                    extensions.set("rule", "ArgumentsOfCorrectType");
+                    extensions
+                }),
             }]
         );
     }
@@ -328,4 +378,71 @@ mod tests {
             "Field \"MyInput.b\" must not have a default value".to_string()
         );
     }
+
+    #[tokio::test]
+    async fn input_object_validator() {
+        let range = InputObject::new("Range")
+            .field(InputValue::new("start", TypeRef::named_nn(TypeRef::INT)))
+            .field(InputValue::new("end", TypeRef::named_nn(TypeRef::INT)))
+            .validator(|obj, errors| {
+                let start = obj.try_get("start").and_then(|value| value.i64());
+                let end = obj.try_get("end").and_then(|value| value.i64());
+                if let (Ok(start), Ok(end)) = (start, end) {
+                    errors.ensure(end > start, "end", "must be after start");
+                }
+            });
+        let query = Object::new("Query").field(
+            Field::new("count", TypeRef::named_nn(TypeRef::INT), |ctx| {
+                FieldFuture::new(async move {
+                    let ranges = ctx.args.try_get("ranges")?.list()?;
+                    Ok(Some(Value::from(ranges.len())))
+                })
+            })
+            .argument(InputValue::new(
+                "ranges",
+                TypeRef::named_nn_list_nn(range.type_name()),
+            )),
+        );
+        let schema = Schema::build(query.type_name(), None, None)
+            .register(range)
+            .register(query)
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            schema
+                .execute("{ count(ranges: [{ start: 1, end: 2 }]) }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({ "count": 1 })
+        );
+
+        assert_eq!(
+            schema
+                .execute("{ count(ranges: [{ start: 1, end: 2 }, { start: 2, end: 1 }]) }")
+                .await
+                .into_result()
+                .unwrap_err(),
+            vec![ServerError {
+                message: r#"Failed to parse "Range": end: must be after start"#.to_owned(),
+                source: None,
+                locations: vec![Pos {
+                    column: 17,
+                    line: 1
+                }],
+                path: vec![PathSegment::Field("count".to_owned())],
+                extensions: Some({
+                    let mut extensions = ErrorExtensionValues::default();
+                    extensions.set("code", "BAD_USER_INPUT");
+                    extensions.set(
+                        "validationErrors",
+                        value!([{ "path": ["end"], "message": "must be after start" }]),
+                    );
+                    extensions
+                }),
+            }]
+        );
+    }
 }
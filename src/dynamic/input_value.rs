@@ -64,6 +64,7 @@ impl InputValue {
             tags: self.tags.clone(),
             is_secret: false,
             directive_invocations: to_meta_directive_invocation(self.directives.clone()),
+            constraints: Default::default(),
         }
     }
 }
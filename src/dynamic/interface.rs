@@ -2,7 +2,7 @@ use indexmap::{IndexMap, IndexSet};
 
 use super::{Directive, directive::to_meta_directive_invocation};
 use crate::{
-    dynamic::{InputValue, SchemaError, TypeRef},
+    dynamic::{InputValue, SchemaError, TypeRef, field::TypeGuardFn},
     registry::{Deprecation, MetaField, MetaType, Registry},
 };
 
@@ -98,6 +98,7 @@ pub struct InterfaceField {
     pub(crate) override_from: Option<String>,
     pub(crate) directives: Vec<Directive>,
     pub(crate) requires_scopes: Vec<String>,
+    pub(crate) authenticated: bool,
 }
 
 impl InterfaceField {
@@ -118,6 +119,7 @@ impl InterfaceField {
             override_from: None,
             directives: Vec::new(),
             requires_scopes: Vec::new(),
+            authenticated: false,
         }
     }
 
@@ -129,6 +131,8 @@ impl InterfaceField {
     impl_set_shareable!();
     impl_set_inaccessible!();
     impl_set_tags!();
+    impl_set_requires_scopes!();
+    impl_set_authenticated!();
     impl_set_override_from!();
     impl_directive!();
 
@@ -153,6 +157,8 @@ pub struct Interface {
     tags: Vec<String>,
     pub(crate) directives: Vec<Directive>,
     requires_scopes: Vec<String>,
+    authenticated: bool,
+    pub(crate) guard: Option<TypeGuardFn>,
 }
 
 impl Interface {
@@ -170,6 +176,8 @@ impl Interface {
             tags: Vec::new(),
             directives: Vec::new(),
             requires_scopes: Vec::new(),
+            authenticated: false,
+            guard: None,
         }
     }
 
@@ -177,6 +185,9 @@ impl Interface {
     impl_set_extends!();
     impl_set_inaccessible!();
     impl_set_tags!();
+    impl_set_requires_scopes!();
+    impl_set_authenticated!();
+    impl_set_guard!();
     impl_directive!();
 
     /// Add a field to the interface type
@@ -253,6 +264,9 @@ impl Interface {
                     compute_complexity: None,
                     directive_invocations: to_meta_directive_invocation(field.directives.clone()),
                     requires_scopes: field.requires_scopes.clone(),
+                    authenticated: field.authenticated,
+                    timeout: None,
+                    max_concurrency: None,
                 },
             );
         }
@@ -276,6 +290,7 @@ impl Interface {
                 rust_typename: None,
                 directive_invocations: to_meta_directive_invocation(self.directives.clone()),
                 requires_scopes: self.requires_scopes.clone(),
+                authenticated: self.authenticated,
             },
         );
 
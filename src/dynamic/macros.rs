@@ -162,6 +162,59 @@ macro_rules! impl_set_override_from {
     };
 }
 
+macro_rules! impl_set_requires_scopes {
+    () => {
+        /// Indicates to composition that the target element is accessible only
+        /// to the authenticated supergraph users with the appropriate JWT scopes
+        /// when using Apollo Federation.
+        ///
+        /// Each item is a space separated list of scopes which must all be
+        /// granted, at least one of the items must be satisfied.
+        #[inline]
+        pub fn requires_scopes<I: IntoIterator<Item = T>, T: Into<String>>(
+            self,
+            scopes: I,
+        ) -> Self {
+            Self {
+                requires_scopes: scopes.into_iter().map(Into::into).collect(),
+                ..self
+            }
+        }
+    };
+}
+
+macro_rules! impl_set_authenticated {
+    () => {
+        /// Indicates to composition that the target element is accessible only
+        /// to the authenticated supergraph users when using Apollo Federation.
+        #[inline]
+        pub fn authenticated(self) -> Self {
+            Self {
+                authenticated: true,
+                ..self
+            }
+        }
+    };
+}
+
+macro_rules! impl_set_guard {
+    () => {
+        /// Set a guard for the type, which is checked once for every value of
+        /// this type before any of its fields is resolved, with the context of
+        /// the field returning it.
+        #[inline]
+        pub fn guard<F>(self, f: F) -> Self
+        where
+            F: Fn(&crate::Context<'_>) -> crate::Result<()> + Send + Sync + 'static,
+        {
+            Self {
+                guard: Some(crate::dynamic::field::TypeGuardFn(Box::new(f))),
+                ..self
+            }
+        }
+    };
+}
+
 macro_rules! impl_directive {
     () => {
         /// Attach directive to the entity
@@ -55,7 +55,7 @@ mod value_accessor;
 pub use directive::Directive;
 pub use r#enum::{Enum, EnumItem};
 pub use error::SchemaError;
-pub use field::{Field, FieldFuture, FieldValue, ResolverContext};
+pub use field::{BatchEntityFuture, Field, FieldFuture, FieldValue, ResolverContext};
 pub use indexmap;
 pub use input_object::InputObject;
 pub use input_value::InputValue;
@@ -2,7 +2,7 @@ use indexmap::{IndexMap, IndexSet};
 
 use super::{Directive, directive::to_meta_directive_invocation};
 use crate::{
-    dynamic::{Field, SchemaError},
+    dynamic::{Field, SchemaError, field::TypeGuardFn},
     registry::{MetaField, MetaType, Registry},
 };
 
@@ -51,6 +51,8 @@ pub struct Object {
     tags: Vec<String>,
     pub(crate) directives: Vec<Directive>,
     requires_scopes: Vec<String>,
+    authenticated: bool,
+    pub(crate) guard: Option<TypeGuardFn>,
 }
 
 impl Object {
@@ -71,6 +73,8 @@ impl Object {
             tags: Vec::new(),
             directives: Vec::new(),
             requires_scopes: Vec::new(),
+            authenticated: false,
+            guard: None,
         }
     }
 
@@ -80,6 +84,9 @@ impl Object {
     impl_set_inaccessible!();
     impl_set_interface_object!();
     impl_set_tags!();
+    impl_set_requires_scopes!();
+    impl_set_authenticated!();
+    impl_set_guard!();
     impl_directive!();
 
     /// Add an field to the object
@@ -192,6 +199,9 @@ impl Object {
                     compute_complexity: None,
                     directive_invocations: to_meta_directive_invocation(field.directives.clone()),
                     requires_scopes: field.requires_scopes.clone(),
+                    authenticated: field.authenticated,
+                    timeout: field.timeout,
+                    max_concurrency: field.max_concurrency,
                 },
             );
         }
@@ -219,6 +229,7 @@ impl Object {
                 rust_typename: None,
                 directive_invocations: to_meta_directive_invocation(self.directives.clone()),
                 requires_scopes: self.requires_scopes.clone(),
+                authenticated: self.authenticated,
             },
         );
 
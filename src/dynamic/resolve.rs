@@ -6,15 +6,18 @@ use futures_util::{Future, FutureExt, future::BoxFuture};
 use indexmap::IndexMap;
 
 use crate::{
-    Context, ContextSelectionSet, Error, IntrospectionMode, Name, SDLExportOptions, ServerError,
-    ServerResult, Value,
+    Context, ContextSelectionSet, Error, GuardAction, InputFieldErrors, IntrospectionMode, Name,
+    Pos, SDLExportOptions, ServerError, ServerResult, Value,
+    authorization::check_field_access,
+    concurrency::{field_concurrency_limit, try_join_limited},
     dynamic::{
         FieldFuture, FieldValue, Object, ObjectAccessor, ResolverContext, Schema, Type, TypeRef,
-        field::FieldValueInner,
+        field::{FieldValueInner, TypeGuardFn},
     },
     extensions::ResolveInfo,
     parser::types::Selection,
-    resolver_utils::create_value_object,
+    resolver_utils::{check_int_value, create_value_object, guard_error},
+    timeout::{field_deadline, resolve_until_deadline},
 };
 
 /// Federation service
@@ -37,7 +40,7 @@ pub(crate) async fn resolve_container(
     collect_fields(&mut fields, schema, object, ctx, parent_value)?;
 
     let res = if !serial {
-        futures_util::future::try_join_all(fields).await?
+        try_join_limited(ctx, fields).await?
     } else {
         let mut results = Vec::with_capacity(fields.len());
         for field in fields {
@@ -74,15 +77,7 @@ fn collect_schema_field<'a>(
     fields.push(
         async move {
             let ctx_field = ctx.with_field(field);
-            let mut ctx_obj = ctx.with_selection_set(&ctx_field.item.node.selection_set);
-            ctx_obj.is_for_introspection = true;
-            let visible_types = ctx.schema_env.registry.find_visible_types(&ctx_field);
-            let value = crate::OutputType::resolve(
-                &crate::model::__Schema::new(&ctx.schema_env.registry, &visible_types),
-                &ctx_obj,
-                ctx_field.item,
-            )
-            .await?;
+            let value = crate::model::resolve_schema(&ctx_field).await?;
             Ok((field.node.response_key().node.clone(), value))
         }
         .boxed(),
@@ -167,35 +162,40 @@ fn collect_entities_field<'a>(
     fields.push(
         async move {
             let ctx_field = ctx.with_field(field);
-            let entity_resolver = schema.0.entity_resolver.as_ref().ok_or_else(|| {
-                ctx_field.set_error_path(
+            if schema.0.entity_resolver.is_none() && schema.0.batch_entity_resolvers.is_empty() {
+                return Err(ctx_field.set_error_path(
                     Error::new("internal: missing entity resolver")
                         .into_server_error(ctx_field.item.pos),
-                )
-            })?;
+                ));
+            }
             let entity_type = TypeRef::named_list_nn("_Entity");
 
-            let arguments = ObjectAccessor(Cow::Owned(
-                field
-                    .node
-                    .arguments
-                    .iter()
-                    .map(|(name, value)| {
-                        // Drop omitted variable-backed arguments instead of materializing them as
-                        // `null`.
-                        ctx_field
-                            .resolve_input_value(value.clone())
-                            .map(|value| value.map(|value| (name.node.clone(), value)))
-                    })
-                    .collect::<ServerResult<Vec<_>>>()?
-                    .into_iter()
-                    .flatten()
-                    .collect::<IndexMap<_, _>>(),
-            ));
+            let arguments = field
+                .node
+                .arguments
+                .iter()
+                .map(|(name, value)| {
+                    // Drop omitted variable-backed arguments instead of materializing them as
+                    // `null`.
+                    ctx_field
+                        .resolve_input_value(value.clone())
+                        .map(|value| value.map(|value| (name.node.clone(), value)))
+                })
+                .collect::<ServerResult<Vec<_>>>()?
+                .into_iter()
+                .flatten()
+                .collect::<IndexMap<_, _>>();
+
+            if !schema.0.batch_entity_resolvers.is_empty() {
+                let value =
+                    resolve_batch_entities(schema, &ctx_field, parent_value, arguments).await?;
+                return Ok((field.node.response_key().node.clone(), value));
+            }
 
+            let entity_resolver = schema.0.entity_resolver.as_ref().unwrap();
             let field_future = (entity_resolver)(ResolverContext {
                 ctx: &ctx_field,
-                args: arguments,
+                args: ObjectAccessor(Cow::Owned(arguments)),
                 parent_value,
             });
 
@@ -214,6 +214,171 @@ fn collect_entities_field<'a>(
     );
 }
 
+/// Resolves the `_entities` field by passing the representations of each type
+/// to its batch entity resolver, and the rest to the entity resolver.
+async fn resolve_batch_entities<'a>(
+    schema: &'a Schema,
+    ctx: &'a Context<'a>,
+    parent_value: &'a FieldValue<'a>,
+    arguments: IndexMap<Name, Value>,
+) -> ServerResult<Value> {
+    let representations = match arguments.get("representations") {
+        Some(Value::List(representations)) => representations.as_slice(),
+        _ => &[],
+    };
+
+    // Group the positions of the representations by the resolver that handles them.
+    let mut batches = IndexMap::<&str, Vec<usize>>::new();
+    let mut rest = Vec::new();
+    for (idx, representation) in representations.iter().enumerate() {
+        match representation {
+            Value::Object(representation) => match representation.get("__typename") {
+                Some(Value::String(typename))
+                    if schema.0.batch_entity_resolvers.contains_key(typename) =>
+                {
+                    batches.entry(typename.as_str()).or_default().push(idx);
+                }
+                _ => rest.push(idx),
+            },
+            _ => rest.push(idx),
+        }
+    }
+
+    let make_args = |indexes: &[usize]| {
+        let mut args = arguments.clone();
+        args.insert(
+            Name::new("representations"),
+            Value::List(
+                indexes
+                    .iter()
+                    .map(|idx| representations[*idx].clone())
+                    .collect(),
+            ),
+        );
+        ObjectAccessor(Cow::Owned(args))
+    };
+    let mut entities = representations
+        .iter()
+        .map(|_| None)
+        .collect::<Vec<Option<Result<Option<FieldValue>, Error>>>>();
+
+    let batch_results =
+        futures_util::future::join_all(batches.iter().map(|(typename, indexes)| {
+            let resolver_fn = &schema.0.batch_entity_resolvers[*typename];
+            (resolver_fn)(ResolverContext {
+                ctx,
+                args: make_args(indexes),
+                parent_value,
+            })
+            .0
+        }))
+        .await;
+    for (indexes, res) in batches.values().zip(batch_results) {
+        match res {
+            Ok(values) if values.len() == indexes.len() => {
+                for (idx, value) in indexes.iter().zip(values) {
+                    entities[*idx] = Some(value);
+                }
+            }
+            Ok(values) => {
+                let err = Error::new(format!(
+                    "Batch entity resolver returned {} entities for {} representations.",
+                    values.len(),
+                    indexes.len()
+                ));
+                for idx in indexes {
+                    entities[*idx] = Some(Err(err.clone()));
+                }
+            }
+            Err(err) => {
+                for idx in indexes {
+                    entities[*idx] = Some(Err(err.clone()));
+                }
+            }
+        }
+    }
+
+    if !rest.is_empty() {
+        match &schema.0.entity_resolver {
+            Some(entity_resolver) => {
+                let field_future = (entity_resolver)(ResolverContext {
+                    ctx,
+                    args: make_args(&rest),
+                    parent_value,
+                });
+                let field_value = match field_future {
+                    FieldFuture::Future(fut) => fut
+                        .await
+                        .map_err(|err| err.into_server_error(ctx.item.pos))?,
+                    FieldFuture::Value(value) => value,
+                };
+                let values = match field_value {
+                    Some(FieldValue(FieldValueInner::List(values))) => values,
+                    Some(FieldValue(FieldValueInner::Value(Value::List(values)))) => {
+                        values.into_iter().map(FieldValue::value).collect()
+                    }
+                    Some(_) => {
+                        return Err(ctx.set_error_path(
+                            Error::new("internal: expects an array")
+                                .into_server_error(ctx.item.pos),
+                        ));
+                    }
+                    None => Vec::new(),
+                };
+                if values.len() == rest.len() {
+                    for (idx, value) in rest.iter().zip(values) {
+                        entities[*idx] = Some(Ok(Some(value)));
+                    }
+                } else {
+                    let err = Error::new(format!(
+                        "Entity resolver returned {} entities for {} representations.",
+                        values.len(),
+                        rest.len()
+                    ));
+                    for idx in &rest {
+                        entities[*idx] = Some(Err(err.clone()));
+                    }
+                }
+            }
+            None => {
+                for idx in &rest {
+                    entities[*idx] = Some(Err(Error::new("internal: missing entity resolver")));
+                }
+            }
+        }
+    }
+
+    let entity_type = &TypeRef::named("_Entity");
+    let values =
+        futures_util::future::try_join_all(entities.iter().enumerate().map(
+            |(idx, entity)| async move {
+                let ctx_idx = ctx.with_index(idx);
+                match entity {
+                    Some(Ok(value)) => Ok::<_, ServerError>(
+                        resolve(schema, &ctx_idx, entity_type, value.as_ref())
+                            .await?
+                            .unwrap_or_default(),
+                    ),
+                    Some(Err(err)) => {
+                        ctx_idx.add_error(
+                            ctx_idx.set_error_path(err.clone().into_server_error(ctx.item.pos)),
+                        );
+                        Ok(Value::Null)
+                    }
+                    None => {
+                        ctx_idx.add_error(ctx_idx.set_error_path(ServerError::new(
+                            "Entity not found.",
+                            Some(ctx.item.pos),
+                        )));
+                        Ok(Value::Null)
+                    }
+                }
+            },
+        ))
+        .await?;
+    Ok(Value::List(values))
+}
+
 fn collect_field<'a>(
     fields: &mut Vec<BoxFieldFuture<'a>>,
     schema: &'a Schema,
@@ -226,7 +391,12 @@ fn collect_field<'a>(
     let ctx = ctx.clone();
     fields.push(
         async move {
-            let ctx_field = ctx.with_field(field);
+            let mut ctx_field = ctx.with_field(field);
+            if let Some(res) = check_field_access(&ctx_field, &object.name) {
+                return res.map(|value| (field.node.response_key().node.clone(), value));
+            }
+            let meta_field = field_deadline(&mut ctx_field, &object.name);
+            field_concurrency_limit(&mut ctx_field, &object.name);
             let arguments = ObjectAccessor(Cow::Owned({
                 let mut args = field
                     .node
@@ -252,6 +422,15 @@ fn collect_field<'a>(
                 });
                 args
             }));
+            for (name, arg) in &field_def.arguments {
+                if let Some(value) = arguments.get(name) {
+                    let pos = field
+                        .node
+                        .get_argument(name)
+                        .map_or(field.pos, |value| value.pos);
+                    validate_input_objects(schema, &ctx_field, pos, &arg.ty, value.as_value())?;
+                }
+            }
 
             let resolve_info = ResolveInfo {
                 path_node: ctx_field.path_node.as_ref().unwrap(),
@@ -276,6 +455,22 @@ fn collect_field<'a>(
                         .map_err(|err| err.into_server_error(field.pos))?,
                 };
 
+                let field_value = match (&field_def.guard_result, field_value) {
+                    (Some(guard), Some(field_value)) => {
+                        let forbidden = |err: Error| {
+                            ctx_field.set_error_path(guard_error(err).into_server_error(field.pos))
+                        };
+                        match guard(&ctx_field, &field_value).map_err(forbidden)? {
+                            GuardAction::Allow => Some(field_value),
+                            GuardAction::Redact if matches!(field_def.ty, TypeRef::NonNull(_)) => {
+                                return Err(forbidden(Error::new("Forbidden")));
+                            }
+                            GuardAction::Redact => None,
+                        }
+                    }
+                    (_, field_value) => field_value,
+                };
+
                 let value =
                     resolve(schema, &ctx_field, &field_def.ty, field_value.as_ref()).await?;
 
@@ -283,18 +478,62 @@ fn collect_field<'a>(
             };
             futures_util::pin_mut!(resolve_fut);
 
-            let res_value = ctx_field
-                .query_env
-                .extensions
-                .resolve(resolve_info, &mut resolve_fut)
-                .await?
-                .unwrap_or_default();
+            let resolve_value = async {
+                Ok(ctx_field
+                    .query_env
+                    .extensions
+                    .resolve(resolve_info, &mut resolve_fut)
+                    .await?
+                    .unwrap_or_default())
+            };
+            let res_value = match meta_field {
+                Some(meta_field) => {
+                    resolve_until_deadline(&ctx_field, meta_field, resolve_value).await?
+                }
+                None => resolve_value.await?,
+            };
             Ok((field.node.response_key().node.clone(), res_value))
         }
         .boxed(),
     );
 }
 
+/// Runs the validators of the input objects in an argument value.
+fn validate_input_objects(
+    schema: &Schema,
+    ctx: &Context<'_>,
+    pos: Pos,
+    ty: &TypeRef,
+    value: &Value,
+) -> ServerResult<()> {
+    match (ty, value) {
+        (TypeRef::NonNull(ty), _) => validate_input_objects(schema, ctx, pos, ty, value),
+        (TypeRef::List(ty), Value::List(values)) => values
+            .iter()
+            .try_for_each(|value| validate_input_objects(schema, ctx, pos, ty, value)),
+        (TypeRef::List(ty), _) => validate_input_objects(schema, ctx, pos, ty, value),
+        (TypeRef::Named(type_name), Value::Object(values)) => {
+            let Some(Type::InputObject(object)) = schema.0.types.get(type_name.as_ref()) else {
+                return Ok(());
+            };
+            for (name, field) in &object.fields {
+                if let Some(value) = values.get(name.as_str()) {
+                    validate_input_objects(schema, ctx, pos, &field.ty, value)?;
+                }
+            }
+            if let Some(validator) = &object.validator {
+                let mut errors = InputFieldErrors::new();
+                validator(&ObjectAccessor(Cow::Borrowed(values)), &mut errors);
+                if !errors.is_empty() {
+                    return Err(ctx.set_error_path(errors.into_server_error(&object.name, pos)));
+                }
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
 fn collect_fields<'a>(
     fields: &mut Vec<BoxFieldFuture<'a>>,
     schema: &'a Schema,
@@ -351,6 +590,26 @@ fn collect_fields<'a>(
                     continue;
                 }
 
+                // Fields removed by a contract must not be executed, even if the
+                // validation is skipped.
+                if ctx.schema_env.registry.contract.is_some()
+                    && ctx
+                        .schema_env
+                        .registry
+                        .types
+                        .get(&object.name)
+                        .and_then(|ty| ty.field_by_name(field.node.name.node.as_str()))
+                        .is_none()
+                {
+                    return Err(ServerError::new(
+                        format!(
+                            r#"Cannot query field "{}" on type "{}"."#,
+                            field.node.name.node, object.name
+                        ),
+                        Some(field.pos),
+                    ));
+                }
+
                 if let Some(field_def) = object.fields.get(field.node.name.node.as_str()) {
                     collect_field(fields, schema, object, ctx, parent_value, field_def, field);
                 }
@@ -492,10 +751,18 @@ async fn resolve_list<'a>(
             Ok::<_, ServerError>(res_value.unwrap_or_default())
         });
     }
-    let values = futures_util::future::try_join_all(futures).await?;
+    let values = try_join_limited(ctx, futures).await?;
     Ok(Some(Value::List(values)))
 }
 
+fn check_type_guard(ctx: &Context<'_>, guard: Option<&TypeGuardFn>) -> ServerResult<()> {
+    match guard {
+        Some(guard) => (guard.0)(ctx)
+            .map_err(|err| ctx.set_error_path(guard_error(err).into_server_error(ctx.item.pos))),
+        None => Ok(()),
+    }
+}
+
 async fn resolve_value(
     schema: &Schema,
     ctx: &Context<'_>,
@@ -504,6 +771,9 @@ async fn resolve_value(
 ) -> ServerResult<Option<Value>> {
     match (field_type, &value.0) {
         (Type::Scalar(scalar), FieldValueInner::Value(value)) if scalar.validate(value) => {
+            if scalar.name == "Int" {
+                return check_int_value(ctx, ctx.item.pos, value.clone()).map(Some);
+            }
             Ok(Some(value.clone()))
         }
         (Type::Scalar(scalar), _) => Err(ctx.set_error_path(
@@ -515,6 +785,7 @@ async fn resolve_value(
         )),
 
         (Type::Object(object), _) => {
+            check_type_guard(ctx, object.guard.as_ref())?;
             resolve_container(
                 schema,
                 object,
@@ -597,6 +868,8 @@ async fn resolve_value(
                     )
                 })?;
 
+            check_type_guard(ctx, interface.guard.as_ref())?;
+            check_type_guard(ctx, object_type.guard.as_ref())?;
             resolve_container(
                 schema,
                 object_type,
@@ -643,6 +916,8 @@ async fn resolve_value(
                     )
                 })?;
 
+            check_type_guard(ctx, union.guard.as_ref())?;
+            check_type_guard(ctx, object_type.guard.as_ref())?;
             resolve_container(
                 schema,
                 object_type,
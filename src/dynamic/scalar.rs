@@ -52,6 +52,7 @@ pub struct Scalar {
     tags: Vec<String>,
     pub(crate) directives: Vec<Directive>,
     requires_scopes: Vec<String>,
+    authenticated: bool,
 }
 
 impl Debug for Scalar {
@@ -63,6 +64,7 @@ impl Debug for Scalar {
             .field("inaccessible", &self.inaccessible)
             .field("tags", &self.tags)
             .field("requires_scopes", &self.requires_scopes)
+            .field("authenticated", &self.authenticated)
             .finish()
     }
 }
@@ -80,12 +82,15 @@ impl Scalar {
             tags: Vec::new(),
             directives: Vec::new(),
             requires_scopes: Vec::new(),
+            authenticated: false,
         }
     }
 
     impl_set_description!();
     impl_set_inaccessible!();
     impl_set_tags!();
+    impl_set_requires_scopes!();
+    impl_set_authenticated!();
     impl_directive!();
 
     /// Set the validator
@@ -133,6 +138,7 @@ impl Scalar {
                 specified_by_url: self.specified_by_url.clone(),
                 directive_invocations: to_meta_directive_invocation(self.directives.clone()),
                 requires_scopes: self.requires_scopes.clone(),
+                authenticated: self.authenticated,
             },
         );
         Ok(())
@@ -1,20 +1,27 @@
-use std::{any::Any, collections::HashMap, fmt::Debug, sync::Arc};
+use std::{any::Any, collections::HashMap, fmt::Debug, num::NonZeroUsize, sync::Arc};
 
 use async_graphql_parser::types::OperationType;
 use futures_util::{StreamExt, TryFutureExt, stream::BoxStream};
 use indexmap::IndexMap;
 
+#[cfg(feature = "introspection_cache")]
+use crate::model::IntrospectionCache;
 use crate::{
-    Data, Executor, IntrospectionMode, QueryEnv, Request, Response, SDLExportOptions, SchemaEnv,
+    Authorization, CancellationToken, Data, DeprecationReporting, ErrorCode, ErrorPolicy, Executor,
+    IntrospectionMode, QueryAnalysis, QueryEnv, Request, Response, SDLExportOptions, SchemaEnv,
     ServerError, ServerResult, ValidationMode,
     dynamic::{
-        DynamicRequest, FieldFuture, FieldValue, Object, ResolverContext, Scalar, SchemaError,
-        Subscription, TypeRef, Union, field::BoxResolverFn, resolve::resolve_container,
+        BatchEntityFuture, DynamicRequest, FieldFuture, FieldValue, Object, ResolverContext,
+        Scalar, SchemaError, Subscription, TypeRef, Union,
+        field::{BoxBatchEntityResolverFn, BoxResolverFn},
+        resolve::resolve_container,
         r#type::Type,
     },
+    error_policy::apply_error_policy,
     extensions::{ExtensionFactory, Extensions},
-    registry::{MetaType, Registry},
-    schema::{SchemaEnvInner, prepare_request},
+    registry::{Contract, MetaType, Registry},
+    runtime::Timer,
+    schema::{SchemaEnvInner, analyze_request, prepare_request},
 };
 
 /// Dynamic schema builder
@@ -30,10 +37,22 @@ pub struct SchemaBuilder {
     max_directives: Option<usize>,
     complexity: Option<usize>,
     depth: Option<usize>,
+    introspection_depth: Option<usize>,
+    introspection_fields: Option<usize>,
+    #[cfg(feature = "introspection_cache")]
+    introspection_cache: Option<usize>,
+    contract: Option<Contract>,
+    error_policy: Option<Arc<dyn ErrorPolicy>>,
+    deprecation_reporting: Option<DeprecationReporting>,
+    authorization: Option<Authorization>,
+    strict_int: bool,
+    timer: Option<Arc<dyn Timer>>,
+    concurrency_limit: Option<NonZeroUsize>,
     enable_suggestions: bool,
     introspection_mode: IntrospectionMode,
     enable_federation: bool,
     entity_resolver: Option<BoxResolverFn>,
+    batch_entity_resolvers: HashMap<String, BoxBatchEntityResolverFn>,
 }
 
 impl SchemaBuilder {
@@ -91,6 +110,90 @@ impl SchemaBuilder {
         self
     }
 
+    /// Set the maximum nesting of `__Type` selections in an introspection
+    /// query, such as `ofType { ofType { ... } }` chains. By default, there is
+    /// no limit.
+    ///
+    /// The introspection fields are still counted by
+    /// [`limit_complexity`](Self::limit_complexity) and
+    /// [`limit_depth`](Self::limit_depth).
+    #[must_use]
+    pub fn limit_introspection_depth(mut self, depth: usize) -> Self {
+        self.introspection_depth = Some(depth);
+        self
+    }
+
+    /// Set the maximum number of `__schema` and `__type` fields an operation
+    /// can select. By default, there is no limit.
+    #[must_use]
+    pub fn limit_introspection_fields(mut self, count: usize) -> Self {
+        self.introspection_fields = Some(count);
+        self
+    }
+
+    /// Build a contract schema, filtered by tags.
+    ///
+    /// See [`Contract`] for the filtering rules.
+    #[must_use]
+    pub fn contract(mut self, contract: Contract) -> Self {
+        self.contract = Some(contract);
+        self
+    }
+
+    /// Set the policy classifying the errors returned by the resolvers, the
+    /// messages of internal errors are masked before being sent to the
+    /// client.
+    ///
+    /// Parse and validation errors are always sent as is. See
+    /// [`MaskInternalErrors`](crate::MaskInternalErrors).
+    #[must_use]
+    pub fn error_policy(mut self, policy: impl ErrorPolicy) -> Self {
+        self.error_policy = Some(Arc::new(policy));
+        self
+    }
+
+    /// Collect the usage of deprecated fields, arguments, input fields and
+    /// enum values, see [`DeprecationReporting`].
+    #[must_use]
+    pub fn deprecation_reporting(mut self, reporting: DeprecationReporting) -> Self {
+        self.deprecation_reporting = Some(reporting);
+        self
+    }
+
+    /// Enforce the `@authenticated` and `@requiresScopes` requirements of the
+    /// fields and types at execution time, see [`Authorization`].
+    #[must_use]
+    pub fn authorization(mut self, authorization: Authorization) -> Self {
+        self.authorization = Some(authorization);
+        self
+    }
+
+    /// Set the timer used to enforce the field timeouts and the request
+    /// deadlines. Without a timer they are not enforced.
+    #[must_use]
+    pub fn timer(mut self, timer: impl Timer) -> Self {
+        self.timer = Some(Arc::new(timer));
+        self
+    }
+
+    /// Reject the values of `Int` fields outside of the 32-bit signed range
+    /// with a field error, as required by the GraphQL specification.
+    #[must_use]
+    pub fn enable_strict_int(mut self) -> Self {
+        self.strict_int = true;
+        self
+    }
+
+    /// Cache the results of `__schema` queries, at most `capacity` results
+    /// are kept and the least recently used ones are evicted.
+    #[cfg(feature = "introspection_cache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "introspection_cache")))]
+    #[must_use]
+    pub fn enable_introspection_cache(mut self, capacity: usize) -> Self {
+        self.introspection_cache = Some(capacity);
+        self
+    }
+
     /// Set the maximum number of directives on a single field. (default: no
     /// limit)
     pub fn limit_directives(mut self, max_directives: usize) -> Self {
@@ -98,6 +201,25 @@ impl SchemaBuilder {
         self
     }
 
+    /// Set the maximum number of items of each list and sibling fields of
+    /// each object resolved concurrently. (default: no limit)
+    ///
+    /// The limit applies to every list and selection set separately, so
+    /// nested lists can resolve up to the product of their limits at once.
+    /// It can be lowered for the value of a field with
+    /// [`Field::max_concurrency`](crate::dynamic::Field::max_concurrency).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_concurrency` is zero.
+    #[must_use]
+    pub fn limit_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.concurrency_limit = Some(
+            NonZeroUsize::new(max_concurrency).expect("the concurrency limit must not be zero"),
+        );
+        self
+    }
+
     /// Set the validation mode, default is `ValidationMode::Strict`.
     #[must_use]
     pub fn validation_mode(mut self, validation_mode: ValidationMode) -> Self {
@@ -146,6 +268,24 @@ impl SchemaBuilder {
         }
     }
 
+    /// Set a batch entity resolver for federation
+    ///
+    /// The resolver is called once per request with all the representations
+    /// of the `type_name` type in the `representations` argument, and must
+    /// return one entry per representation in the same order. An error entry
+    /// only affects its own representation.
+    ///
+    /// Representations of types without a batch entity resolver are passed
+    /// to the resolver set by [`SchemaBuilder::entity_resolver`].
+    pub fn batch_entity_resolver<F>(mut self, type_name: impl Into<String>, resolver_fn: F) -> Self
+    where
+        F: for<'a> Fn(ResolverContext<'a>) -> BatchEntityFuture<'a> + Send + Sync + 'static,
+    {
+        self.batch_entity_resolvers
+            .insert(type_name.into(), Box::new(resolver_fn));
+        self
+    }
+
     /// Consumes this builder and returns a schema.
     pub fn finish(mut self) -> Result<Schema, SchemaError> {
         let mut registry = Registry {
@@ -156,10 +296,14 @@ impl SchemaBuilder {
             mutation_type: self.mutation_type,
             subscription_type: self.subscription_type,
             introspection_mode: self.introspection_mode,
+            introspection_depth: self.introspection_depth,
+            introspection_fields: self.introspection_fields,
             enable_federation: false,
             federation_subscription: false,
             ignore_name_conflicts: Default::default(),
             enable_suggestions: self.enable_suggestions,
+            contract: None,
+            constraint_directives: false,
         };
         registry.add_system_types();
 
@@ -203,11 +347,23 @@ impl SchemaBuilder {
                 .insert("_Entity".to_string(), Type::Union(entity));
         }
 
+        if let Some(contract) = self.contract.take() {
+            registry.apply_contract(contract);
+        }
+
         let inner = SchemaInner {
             env: SchemaEnv(Arc::new(SchemaEnvInner {
                 registry,
                 data: self.data,
                 custom_directives: Default::default(),
+                #[cfg(feature = "introspection_cache")]
+                introspection_cache: self.introspection_cache.and_then(IntrospectionCache::new),
+                error_policy: self.error_policy,
+                deprecation_reporting: self.deprecation_reporting,
+                authorization: self.authorization,
+                strict_int: self.strict_int,
+                timer: self.timer,
+                concurrency_limit: self.concurrency_limit,
             })),
             extensions: self.extensions,
             types: self.types,
@@ -217,6 +373,7 @@ impl SchemaBuilder {
             depth: self.depth,
             validation_mode: self.validation_mode,
             entity_resolver: self.entity_resolver,
+            batch_entity_resolvers: self.batch_entity_resolvers,
         };
         inner.check()?;
         Ok(Schema(Arc::new(inner)))
@@ -245,6 +402,7 @@ pub struct SchemaInner {
     depth: Option<usize>,
     validation_mode: ValidationMode,
     pub(crate) entity_resolver: Option<BoxResolverFn>,
+    pub(crate) batch_entity_resolvers: HashMap<String, BoxBatchEntityResolverFn>,
 }
 
 impl Schema {
@@ -262,18 +420,35 @@ impl Schema {
             max_directives: None,
             complexity: None,
             depth: None,
+            introspection_depth: None,
+            introspection_fields: None,
+            #[cfg(feature = "introspection_cache")]
+            introspection_cache: None,
+            contract: None,
+            error_policy: None,
+            deprecation_reporting: None,
+            authorization: None,
+            strict_int: false,
+            timer: None,
+            concurrency_limit: None,
             enable_suggestions: true,
             introspection_mode: IntrospectionMode::Enabled,
             entity_resolver: None,
+            batch_entity_resolvers: Default::default(),
             enable_federation: false,
         }
     }
 
-    fn create_extensions(&self, session_data: Arc<Data>) -> Extensions {
+    fn create_extensions(
+        &self,
+        session_data: Arc<Data>,
+        cancellation_token: CancellationToken,
+    ) -> Extensions {
         Extensions::new(
             self.0.extensions.iter().map(|f| f.create()),
             self.0.env.clone(),
             session_data,
+            cancellation_token,
         )
     }
 
@@ -348,7 +523,8 @@ impl Schema {
             OperationType::Subscription => Err(ServerError::new(
                 "Subscriptions are not supported on this transport.",
                 None,
-            )),
+            )
+            .with_code(ErrorCode::OperationResolutionFailure)),
         };
 
         let mut resp = match res {
@@ -359,13 +535,32 @@ impl Schema {
 
         resp.errors
             .extend(std::mem::take(&mut *env.errors.lock().unwrap()));
+        apply_error_policy(self.0.env.error_policy.as_deref(), &mut resp.errors);
+        if let Some(deprecation_reporting) = &self.0.env.deprecation_reporting {
+            deprecation_reporting.report(env.extensions.deprecations(), &mut resp);
+        }
         resp
     }
 
+    /// Parses and validates a query without executing it, see
+    /// [`Schema::analyze`](crate::Schema::analyze).
+    pub fn analyze(
+        &self,
+        request: impl Into<DynamicRequest>,
+    ) -> Result<QueryAnalysis, Vec<ServerError>> {
+        analyze_request(
+            request.into().inner,
+            &self.0.env.registry,
+            self.0.recursive_depth,
+            self.0.max_directives,
+        )
+    }
+
     /// Execute a GraphQL query.
     pub async fn execute(&self, request: impl Into<DynamicRequest>) -> Response {
         let request = request.into();
-        let extensions = self.create_extensions(Default::default());
+        let extensions =
+            self.create_extensions(Default::default(), request.inner.cancellation_token.clone());
         let request_fut = {
             let extensions = extensions.clone();
             async move {
@@ -379,6 +574,7 @@ impl Schema {
                     self.0.max_directives,
                     self.0.complexity,
                     self.0.depth,
+                    self.0.env.deprecation_reporting.as_ref(),
                 )
                 .await
                 {
@@ -413,7 +609,10 @@ impl Schema {
     ) -> BoxStream<'static, Response> {
         let schema = self.clone();
         let request = request.into();
-        let extensions = self.create_extensions(session_data.clone());
+        let extensions = self.create_extensions(
+            session_data.clone(),
+            request.inner.cancellation_token.clone(),
+        );
 
         let stream = {
             let extensions = extensions.clone();
@@ -437,6 +636,7 @@ impl Schema {
                     schema.0.max_directives,
                     schema.0.complexity,
                     schema.0.depth,
+                    schema.0.env.deprecation_reporting.as_ref(),
                 )
                 .await
                 {
@@ -464,7 +664,8 @@ impl Schema {
                 subscription.collect_streams(&schema, &ctx, &mut streams, &request.root_value);
 
                 let mut stream = futures_util::stream::select_all(streams);
-                while let Some(resp) = stream.next().await {
+                while let Some(mut resp) = stream.next().await {
+                    apply_error_policy(schema.0.env.error_policy.as_deref(), &mut resp.errors);
                     yielder.yield_item(resp).await;
                 }
             })
@@ -539,8 +740,8 @@ mod tests {
     use tokio::sync::Mutex;
 
     use crate::{
-        PathSegment, Request, Response, ServerError, ServerResult, ValidationResult, Value,
-        dynamic::*, extensions::*, value,
+        Context, GuardAction, PathSegment, Request, Response, ServerError, ServerResult,
+        ValidationResult, Value, dynamic::*, extensions::*, value,
     };
 
     #[tokio::test]
@@ -781,6 +982,223 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn guard_result() {
+        fn redact_negative(_: &Context<'_>, value: &FieldValue<'_>) -> crate::Result<GuardAction> {
+            match value.try_to_value()? {
+                Value::Number(n) if n.as_i64().unwrap_or_default() < 0 => Ok(GuardAction::Redact),
+                _ => Ok(GuardAction::Allow),
+            }
+        }
+
+        let query = Object::new("Query")
+            .field(
+                Field::new("valueA", TypeRef::named(TypeRef::INT), |_| {
+                    FieldFuture::new(async { Ok(Some(Value::from(-1))) })
+                })
+                .guard_result(redact_negative),
+            )
+            .field(
+                Field::new("valueB", TypeRef::named(TypeRef::INT), |_| {
+                    FieldFuture::new(async { Ok(Some(Value::from(1))) })
+                })
+                .guard_result(redact_negative),
+            )
+            .field(
+                Field::new("valueC", TypeRef::named_nn(TypeRef::INT), |_| {
+                    FieldFuture::new(async { Ok(Some(Value::from(-1))) })
+                })
+                .guard_result(redact_negative),
+            )
+            .field(
+                Field::new("valueD", TypeRef::named(TypeRef::INT), |_| {
+                    FieldFuture::new(async { Ok(Some(Value::from(1))) })
+                })
+                .guard_result(|_, _| Err("no access".into())),
+            );
+        let schema = Schema::build("Query", None, None)
+            .register(query)
+            .finish()
+            .unwrap();
+
+        assert_eq!(
+            schema
+                .execute("{ valueA valueB }")
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({
+                "valueA": null,
+                "valueB": 1,
+            })
+        );
+
+        let errors = schema
+            .execute("{ valueC }")
+            .await
+            .into_result()
+            .unwrap_err();
+        assert_eq!(errors[0].message, "Forbidden");
+        assert_eq!(
+            errors[0].path,
+            vec![PathSegment::Field("valueC".to_owned())]
+        );
+
+        let errors = schema
+            .execute("{ valueD }")
+            .await
+            .into_result()
+            .unwrap_err();
+        assert_eq!(errors[0].message, "no access");
+        assert_eq!(
+            errors[0]
+                .extensions
+                .as_ref()
+                .and_then(|ext| ext.get("code")),
+            Some(&Value::from("FORBIDDEN"))
+        );
+    }
+
+    #[tokio::test]
+    async fn type_guard() {
+        struct Admin;
+
+        let only_admin = |ctx: &Context<'_>| match ctx.data_opt::<Admin>() {
+            Some(_) => Ok(()),
+            None => Err("Forbidden".into()),
+        };
+
+        let panel = Object::new("AdminPanel")
+            .field(Field::new("id", TypeRef::named_nn(TypeRef::INT), |_| {
+                FieldFuture::new(async { Ok(Some(Value::from(1))) })
+            }))
+            .implement("Node")
+            .guard(only_admin);
+        let node = Interface::new("Node")
+            .field(InterfaceField::new("id", TypeRef::named_nn(TypeRef::INT)));
+        let query = Object::new("Query")
+            .field(Field::new("panel", TypeRef::named("AdminPanel"), |_| {
+                FieldFuture::new(async { Ok(Some(FieldValue::NULL)) })
+            }))
+            .field(Field::new("node", TypeRef::named("Node"), |_| {
+                FieldFuture::new(async { Ok(Some(FieldValue::NULL.with_type("AdminPanel"))) })
+            }));
+        let schema = Schema::build("Query", None, None)
+            .register(panel)
+            .register(node)
+            .register(query)
+            .finish()
+            .unwrap();
+
+        let query = "{ panel { id } node { id } }";
+        assert_eq!(
+            schema
+                .execute(Request::new(query).data(Admin))
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({
+                "panel": { "id": 1 },
+                "node": { "id": 1 },
+            })
+        );
+
+        for field in ["panel", "node"] {
+            let errors = schema
+                .execute(format!("{{ {field} {{ id }} }}"))
+                .await
+                .into_result()
+                .unwrap_err();
+            assert_eq!(errors[0].message, "Forbidden");
+            assert_eq!(errors[0].path, vec![PathSegment::Field(field.to_owned())]);
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn field_timeout() {
+        use std::time::Duration;
+
+        let query = Object::new("Query")
+            .field(Field::new("fast", TypeRef::named_nn(TypeRef::INT), |_| {
+                FieldFuture::new(async { Ok(Some(Value::from(1))) })
+            }))
+            .field(
+                Field::new("slow", TypeRef::named(TypeRef::INT), |_| {
+                    FieldFuture::new(async {
+                        tokio::time::sleep(Duration::from_secs(5)).await;
+                        Ok(Some(Value::from(2)))
+                    })
+                })
+                .timeout(Duration::from_millis(50)),
+            );
+        let schema = Schema::build("Query", None, None)
+            .register(query)
+            .timer(crate::runtime::TokioTimer::default())
+            .finish()
+            .unwrap();
+
+        let resp = schema.execute("{ fast slow }").await;
+        assert_eq!(resp.data, value!({ "fast": 1, "slow": null }));
+        assert_eq!(resp.errors[0].message, "Field timed out");
+        assert_eq!(
+            resp.errors[0].path,
+            vec![PathSegment::Field("slow".to_owned())]
+        );
+    }
+
+    #[tokio::test]
+    async fn field_max_concurrency() {
+        use std::sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        };
+
+        let current = Arc::new(AtomicUsize::new(0));
+        let max = Arc::new(AtomicUsize::new(0));
+        let item =
+            Object::new("Item").field(Field::new("value", TypeRef::named_nn(TypeRef::INT), {
+                let current = current.clone();
+                let max = max.clone();
+                move |ctx| {
+                    let current = current.clone();
+                    let max = max.clone();
+                    FieldFuture::new(async move {
+                        let value = *ctx.parent_value.try_downcast_ref::<i32>()?;
+                        max.fetch_max(current.fetch_add(1, Ordering::SeqCst) + 1, Ordering::SeqCst);
+                        for _ in 0..10 {
+                            tokio::task::yield_now().await;
+                        }
+                        current.fetch_sub(1, Ordering::SeqCst);
+                        Ok(Some(Value::from(value)))
+                    })
+                }
+            }));
+        let query = Object::new("Query").field(
+            Field::new("items", TypeRef::named_nn_list_nn("Item"), |_| {
+                FieldFuture::new(async {
+                    Ok(Some(FieldValue::list((0..20).map(FieldValue::owned_any))))
+                })
+            })
+            .max_concurrency(3),
+        );
+        let schema = Schema::build("Query", None, None)
+            .register(item)
+            .register(query)
+            .limit_concurrency(8)
+            .finish()
+            .unwrap();
+
+        let resp = schema.execute("{ items { value } }").await;
+        assert_eq!(
+            resp.data,
+            value!({ "items": (0..20).map(|value| value!({ "value": value })).collect::<Vec<_>>() })
+        );
+        assert_eq!(max.load(Ordering::SeqCst), 3);
+    }
+
     #[tokio::test]
     async fn list() {
         let query = Object::new("Query")
@@ -1118,4 +1536,156 @@ mod tests {
             })
         );
     }
+
+    #[tokio::test]
+    async fn federation_batch_entity_resolver() {
+        let user = Object::new("User")
+            .field(Field::new(
+                "name",
+                TypeRef::named_nn(TypeRef::STRING),
+                |ctx| {
+                    FieldFuture::new(async move {
+                        Ok(Some(FieldValue::value(
+                            ctx.parent_value.try_downcast_ref::<String>()?.clone(),
+                        )))
+                    })
+                },
+            ))
+            .key("name");
+        let product = Object::new("Product")
+            .field(Field::new(
+                "upc",
+                TypeRef::named_nn(TypeRef::STRING),
+                |_| FieldFuture::new(async { Ok(Some(FieldValue::value("p1"))) }),
+            ))
+            .key("upc");
+
+        let query =
+            Object::new("Query").field(Field::new("value", TypeRef::named(TypeRef::INT), |_| {
+                FieldFuture::new(async { Ok(Some(Value::from(100))) })
+            }));
+
+        let schema = Schema::build("Query", None, None)
+            .register(query)
+            .register(user)
+            .register(product)
+            .batch_entity_resolver("User", |ctx| {
+                BatchEntityFuture::new(async move {
+                    let representations = ctx.args.try_get("representations")?.list()?;
+                    Ok(representations
+                        .iter()
+                        .map(|item| {
+                            let item = item.object()?;
+                            let name = item.try_get("name")?.string()?;
+                            if name == "unknown" {
+                                return Err("User not found".into());
+                            }
+                            Ok(Some(
+                                FieldValue::owned_any(name.to_string()).with_type("User"),
+                            ))
+                        })
+                        .collect())
+                })
+            })
+            .entity_resolver(|_| {
+                FieldFuture::new(async move {
+                    // Always returns a single entity.
+                    Ok(Some(FieldValue::list([
+                        FieldValue::borrowed_any(&()).with_type("Product")
+                    ])))
+                })
+            })
+            .finish()
+            .unwrap();
+
+        let resp = schema
+            .execute(
+                r#"
+            {
+                _entities(representations: [
+                    {__typename: "User", name: "a"},
+                    {__typename: "Product", upc: "p1"},
+                    {__typename: "User", name: "unknown"},
+                    {__typename: "User", name: "b"}
+                ]) {
+                    __typename
+                    ... on User { name }
+                    ... on Product { upc }
+                }
+            }
+            "#,
+            )
+            .await;
+        assert_eq!(
+            resp.data,
+            value!({
+                "_entities": [
+                    { "__typename": "User", "name": "a" },
+                    { "__typename": "Product", "upc": "p1" },
+                    null,
+                    { "__typename": "User", "name": "b" },
+                ],
+            })
+        );
+        assert_eq!(resp.errors.len(), 1);
+        assert_eq!(resp.errors[0].message, "User not found");
+        assert_eq!(
+            resp.errors[0].path,
+            vec![
+                PathSegment::Field("_entities".to_string()),
+                PathSegment::Index(2)
+            ]
+        );
+
+        let resp = schema
+            .execute(
+                r#"
+            {
+                _entities(representations: [
+                    {__typename: "Product", upc: "p1"},
+                    {__typename: "Product", upc: "p2"}
+                ]) {
+                    ... on Product { upc }
+                }
+            }
+            "#,
+            )
+            .await;
+        assert_eq!(resp.data, value!({ "_entities": [null, null] }));
+        assert_eq!(
+            resp.errors[0].message,
+            "Entity resolver returned 1 entities for 2 representations."
+        );
+    }
+
+    #[tokio::test]
+    async fn contract() {
+        let query = Object::new("Query")
+            .field(Field::new("value", TypeRef::named(TypeRef::INT), |_| {
+                FieldFuture::new(async { Ok(Some(Value::from(100))) })
+            }))
+            .field(
+                Field::new("secret", TypeRef::named(TypeRef::INT), |_| {
+                    FieldFuture::new(async { Ok(Some(Value::from(200))) })
+                })
+                .tags(["internal"]),
+            );
+        let schema = Schema::build("Query", None, None)
+            .register(query)
+            .contract(crate::Contract::new().exclude_tag("internal"))
+            .validation_mode(crate::ValidationMode::Fast)
+            .finish()
+            .unwrap();
+
+        assert!(schema.sdl().contains("type Query {\n\tvalue: Int\n}"));
+        assert_eq!(
+            schema
+                .execute("{ value secret }")
+                .await
+                .into_result()
+                .unwrap_err()[0]
+                .message,
+            r#"Cannot query field "secret" on type "Query"."#
+        );
+    }
 }
@@ -8,6 +8,7 @@ use indexmap::IndexMap;
 use crate::{
     ContextSelectionSet, Data, QueryPathNode, QueryPathSegment, Response, Result, ServerResult,
     Value,
+    authorization::check_subscription_field_access,
     dynamic::{
         FieldValue, InputValue, ObjectAccessor, ResolverContext, Schema, SchemaError, TypeRef,
         resolve::resolve,
@@ -52,6 +53,8 @@ pub struct SubscriptionField {
     pub(crate) ty: TypeRef,
     pub(crate) resolver_fn: BoxResolverFn,
     pub(crate) deprecation: Deprecation,
+    pub(crate) requires_scopes: Vec<String>,
+    pub(crate) authenticated: bool,
 }
 
 impl SubscriptionField {
@@ -69,11 +72,15 @@ impl SubscriptionField {
             ty: ty.into(),
             resolver_fn: Arc::new(resolver_fn),
             deprecation: Deprecation::NoDeprecated,
+            requires_scopes: Vec::new(),
+            authenticated: false,
         }
     }
 
     impl_set_description!();
     impl_set_deprecation!();
+    impl_set_requires_scopes!();
+    impl_set_authenticated!();
 
     /// Add an argument to the subscription field
     #[inline]
@@ -163,7 +170,10 @@ impl Subscription {
                     override_from: None,
                     compute_complexity: None,
                     directive_invocations: vec![],
-                    requires_scopes: vec![],
+                    requires_scopes: field.requires_scopes.clone(),
+                    authenticated: field.authenticated,
+                    timeout: None,
+                    max_concurrency: None,
                 },
             );
         }
@@ -187,6 +197,7 @@ impl Subscription {
                 rust_typename: None,
                 directive_invocations: vec![],
                 requires_scopes: vec![],
+                authenticated: false,
             },
         );
 
@@ -205,6 +216,7 @@ impl Subscription {
                 && let Some(field_def) = self.fields.get(field.node.name.node.as_str())
             {
                 let schema = schema.clone();
+                let self_name = self.name.clone();
                 let field_type = field_def.ty.clone();
                 let resolver_fn = field_def.resolver_fn.clone();
                 let ctx = ctx.clone();
@@ -213,6 +225,7 @@ impl Subscription {
                     asynk_strim::try_stream_fn(move |mut yielder| async move {
                         let ctx_field = ctx.with_field(field);
                         let field_name = ctx_field.item.node.response_key().node.clone();
+                        check_subscription_field_access(&ctx_field, &self_name)?;
                         let arguments = ObjectAccessor(Cow::Owned(
                             field
                                 .node
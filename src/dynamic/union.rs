@@ -2,7 +2,7 @@ use indexmap::IndexSet;
 
 use super::{Directive, directive::to_meta_directive_invocation};
 use crate::{
-    dynamic::SchemaError,
+    dynamic::{SchemaError, field::TypeGuardFn},
     registry::{MetaType, Registry},
 };
 
@@ -86,6 +86,7 @@ pub struct Union {
     inaccessible: bool,
     tags: Vec<String>,
     pub(crate) directives: Vec<Directive>,
+    pub(crate) guard: Option<TypeGuardFn>,
 }
 
 impl Union {
@@ -99,12 +100,14 @@ impl Union {
             inaccessible: false,
             tags: Vec::new(),
             directives: Vec::new(),
+            guard: None,
         }
     }
 
     impl_set_description!();
     impl_set_inaccessible!();
     impl_set_tags!();
+    impl_set_guard!();
     impl_directive!();
 
     /// Add a possible type to the union that must be an object
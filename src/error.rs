@@ -33,6 +33,90 @@ impl ErrorExtensionValues {
     }
 }
 
+/// The standard codes set in the `code` extension of the errors generated by
+/// async-graphql.
+///
+/// The errors returned by the resolvers keep their own extensions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// The query could not be parsed, `GRAPHQL_PARSE_FAILED`.
+    GraphqlParseFailed,
+    /// The query is invalid against the schema, `GRAPHQL_VALIDATION_FAILED`.
+    ///
+    /// The `rule` extension contains the name of the failed validation rule.
+    GraphqlValidationFailed,
+    /// The operation to execute could not be determined,
+    /// `OPERATION_RESOLUTION_FAILURE`.
+    OperationResolutionFailure,
+    /// An argument or variable value is invalid, `BAD_USER_INPUT`.
+    BadUserInput,
+    /// A guard rejected the field, `FORBIDDEN`.
+    Forbidden,
+    /// The query exceeds a complexity limit, `QUERY_TOO_COMPLEX`.
+    QueryTooComplex,
+    /// The query exceeds a depth limit, `QUERY_TOO_DEEP`.
+    QueryTooDeep,
+    /// The persisted query is not found, `PERSISTED_QUERY_NOT_FOUND`.
+    PersistedQueryNotFound,
+    /// The caller cannot access a field or type, `UNAUTHORIZED_FIELD_OR_TYPE`.
+    UnauthorizedFieldOrType,
+    /// The field was not resolved before its deadline, `TIMEOUT`.
+    Timeout,
+    /// The error is internal, `INTERNAL_SERVER_ERROR`.
+    InternalServerError,
+}
+
+impl ErrorCode {
+    const ALL: &[ErrorCode] = &[
+        ErrorCode::GraphqlParseFailed,
+        ErrorCode::GraphqlValidationFailed,
+        ErrorCode::OperationResolutionFailure,
+        ErrorCode::BadUserInput,
+        ErrorCode::Forbidden,
+        ErrorCode::QueryTooComplex,
+        ErrorCode::QueryTooDeep,
+        ErrorCode::PersistedQueryNotFound,
+        ErrorCode::UnauthorizedFieldOrType,
+        ErrorCode::Timeout,
+        ErrorCode::InternalServerError,
+    ];
+
+    /// Returns the code as it appears in the `code` extension.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::GraphqlParseFailed => "GRAPHQL_PARSE_FAILED",
+            ErrorCode::GraphqlValidationFailed => "GRAPHQL_VALIDATION_FAILED",
+            ErrorCode::OperationResolutionFailure => "OPERATION_RESOLUTION_FAILURE",
+            ErrorCode::BadUserInput => "BAD_USER_INPUT",
+            ErrorCode::Forbidden => "FORBIDDEN",
+            ErrorCode::QueryTooComplex => "QUERY_TOO_COMPLEX",
+            ErrorCode::QueryTooDeep => "QUERY_TOO_DEEP",
+            ErrorCode::PersistedQueryNotFound => "PERSISTED_QUERY_NOT_FOUND",
+            ErrorCode::UnauthorizedFieldOrType => "UNAUTHORIZED_FIELD_OR_TYPE",
+            ErrorCode::Timeout => "TIMEOUT",
+            ErrorCode::InternalServerError => "INTERNAL_SERVER_ERROR",
+        }
+    }
+
+    /// Returns the code with the given name.
+    pub fn from_name(name: &str) -> Option<ErrorCode> {
+        Self::ALL.iter().copied().find(|code| code.as_str() == name)
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<ErrorCode> for Value {
+    fn from(code: ErrorCode) -> Self {
+        Value::String(code.as_str().to_string())
+    }
+}
+
 /// An error in a GraphQL server.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ServerError {
@@ -127,6 +211,24 @@ impl ServerError {
     pub fn with_path(self, path: Vec<PathSegment>) -> Self {
         Self { path, ..self }
     }
+
+    /// Returns the standard code of the error, from its `code` extension.
+    pub fn code(&self) -> Option<ErrorCode> {
+        match self.extensions.as_ref()?.get("code")? {
+            Value::String(code) => ErrorCode::from_name(code),
+            _ => None,
+        }
+    }
+
+    /// Sets the `code` extension, unless the error already has one.
+    #[must_use]
+    pub(crate) fn with_code(mut self, code: ErrorCode) -> Self {
+        let extensions = self.extensions.get_or_insert_with(Default::default);
+        if extensions.get("code").is_none() {
+            extensions.set("code", code);
+        }
+        self
+    }
 }
 
 impl Display for ServerError {
@@ -150,6 +252,7 @@ impl From<parser::Error> for ServerError {
             path: Vec::new(),
             extensions: None,
         }
+        .with_code(ErrorCode::GraphqlParseFailed)
     }
 }
 
@@ -238,11 +341,12 @@ impl<T: InputType> InputValueError<T> {
         self
     }
 
-    /// Convert the error into a server error.
+    /// Convert the error into a server error, with the `BAD_USER_INPUT` code
+    /// unless another code is set.
     pub fn into_server_error(self, pos: Pos) -> ServerError {
         let mut err = ServerError::new(self.message, Some(pos));
         err.extensions = self.extensions;
-        err
+        err.with_code(ErrorCode::BadUserInput)
     }
 }
 
@@ -303,6 +407,14 @@ impl Error {
         }
     }
 
+    pub(crate) fn with_code(mut self, code: ErrorCode) -> Self {
+        let extensions = self.extensions.get_or_insert_with(Default::default);
+        if extensions.get("code").is_none() {
+            extensions.set("code", code);
+        }
+        self
+    }
+
     /// Convert the error to a server error.
     #[must_use]
     pub fn into_server_error(self, pos: Pos) -> ServerError {
@@ -373,6 +485,11 @@ pub enum ParseRequestError {
     /// requests.
     #[error("Batch requests are not supported")]
     UnsupportedBatch,
+
+    /// The uploaded files must be streamed, but the request was not received
+    /// with [`receive_batch_body_streaming`](crate::http::receive_batch_body_streaming).
+    #[error("Streaming uploads are not supported")]
+    UnsupportedStreamingUploads,
 }
 
 impl From<multer::Error> for ParseRequestError {
@@ -0,0 +1,189 @@
+use std::{
+    collections::HashSet,
+    hash::{BuildHasher, Hasher, RandomState},
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use crate::{ErrorCode, ErrorExtensionValues, ServerError, Value};
+
+/// The class of an error.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ErrorClass {
+    /// The error is meant for the client, its message is sent as is.
+    User,
+    /// The error is internal, its message is masked.
+    Internal,
+}
+
+/// Classifies and masks the errors returned by the resolvers, see
+/// [`SchemaBuilder::error_policy`](crate::SchemaBuilder::error_policy).
+///
+/// This trait is implemented for functions classifying an error, such as
+/// `Fn(&ServerError) -> ErrorClass`.
+pub trait ErrorPolicy: Send + Sync + 'static {
+    /// Returns the class of an error.
+    fn classify(&self, err: &ServerError) -> ErrorClass;
+
+    /// Masks an internal error.
+    ///
+    /// The default implementation replaces the message with `Internal server
+    /// error`, sets the `code` extension to `INTERNAL_SERVER_ERROR` and the
+    /// `correlationId` extension to a new correlation id, and keeps the
+    /// original error as the source of the masked error, so that it can be
+    /// logged with `err.source::<ServerError>()`.
+    fn mask(&self, err: ServerError) -> ServerError {
+        mask_error(err, "Internal server error")
+    }
+}
+
+impl<F> ErrorPolicy for F
+where
+    F: Fn(&ServerError) -> ErrorClass + Send + Sync + 'static,
+{
+    fn classify(&self, err: &ServerError) -> ErrorClass {
+        self(err)
+    }
+}
+
+/// An [`ErrorPolicy`] that masks the errors without a user-facing `code`
+/// extension.
+///
+/// By default, any error with a `code` extension, usually set with
+/// [`ErrorExtensions`](crate::ErrorExtensions), is a user error.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn user(&self, id: ID) -> Option<Result<String>> {
+///         Some(Err(
+///             Error::new("User not found").extend_with(|_, e| e.set("code", "NOT_FOUND")),
+///         ))
+///     }
+///
+///     async fn config(&self) -> Option<Result<String>> {
+///         Some(std::fs::read_to_string("/does/not/exist").map_err(Into::into))
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .error_policy(MaskInternalErrors::new())
+///     .finish();
+///
+/// let resp = schema.execute(r#"{ user(id: "1") config }"#).await;
+/// assert_eq!(resp.errors[0].message, "User not found");
+/// assert_eq!(resp.errors[1].message, "Internal server error");
+///
+/// // The original error is kept for the logs.
+/// let original = resp.errors[1].source::<ServerError>().unwrap();
+/// assert!(original.source::<std::io::Error>().is_some());
+/// # });
+/// ```
+pub struct MaskInternalErrors {
+    message: String,
+    user_codes: Option<HashSet<String>>,
+}
+
+impl Default for MaskInternalErrors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MaskInternalErrors {
+    /// Create a `MaskInternalErrors` policy.
+    pub fn new() -> Self {
+        Self {
+            message: "Internal server error".to_string(),
+            user_codes: None,
+        }
+    }
+
+    /// Set the message of the masked errors, defaults to `Internal server
+    /// error`.
+    #[must_use]
+    pub fn message(self, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            ..self
+        }
+    }
+
+    /// Only consider the errors with this `code` extension as user errors,
+    /// can be called several times.
+    #[must_use]
+    pub fn user_code(mut self, code: impl Into<String>) -> Self {
+        self.user_codes
+            .get_or_insert_with(Default::default)
+            .insert(code.into());
+        self
+    }
+}
+
+impl ErrorPolicy for MaskInternalErrors {
+    fn classify(&self, err: &ServerError) -> ErrorClass {
+        let Some(code) = err.extensions.as_ref().and_then(|ext| ext.get("code")) else {
+            return ErrorClass::Internal;
+        };
+        let is_user_code = match (&self.user_codes, code) {
+            (None, _) => true,
+            (Some(user_codes), Value::String(code)) => user_codes.contains(code),
+            (Some(user_codes), Value::Enum(code)) => user_codes.contains(code.as_str()),
+            (Some(user_codes), code) => user_codes.contains(&code.to_string()),
+        };
+        if is_user_code {
+            ErrorClass::User
+        } else {
+            ErrorClass::Internal
+        }
+    }
+
+    fn mask(&self, err: ServerError) -> ServerError {
+        mask_error(err, &self.message)
+    }
+}
+
+fn mask_error(err: ServerError, message: &str) -> ServerError {
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("code", ErrorCode::InternalServerError);
+    extensions.set("correlationId", correlation_id());
+    ServerError {
+        message: message.to_string(),
+        locations: err.locations.clone(),
+        path: err.path.clone(),
+        extensions: Some(extensions),
+        source: Some(Arc::new(err)),
+    }
+}
+
+/// Returns a new random identifier, to find the original error of a masked
+/// error in the logs.
+fn correlation_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+    let high = hasher.finish();
+    hasher.write_u64(high);
+    format!("{:016x}{:016x}", high, hasher.finish())
+}
+
+pub(crate) fn apply_error_policy(policy: Option<&dyn ErrorPolicy>, errors: &mut Vec<ServerError>) {
+    if let Some(policy) = policy {
+        *errors = std::mem::take(errors)
+            .into_iter()
+            .map(|err| match policy.classify(&err) {
+                ErrorClass::User => err,
+                ErrorClass::Internal => policy.mask(err),
+            })
+            .collect();
+    }
+}
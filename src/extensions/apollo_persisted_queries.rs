@@ -7,7 +7,7 @@ use serde::Deserialize;
 use sha2::{Digest, Sha256};
 
 use crate::{
-    Request, ServerError, ServerResult,
+    ErrorCode, Request, ServerError, ServerResult,
     extensions::{Extension, ExtensionContext, ExtensionFactory, NextPrepareRequest},
     from_value,
 };
@@ -108,7 +108,8 @@ impl<T: CacheStorage> Extension for ApolloPersistedQueriesExtension<T> {
                         ..request
                     })
                 } else {
-                    Err(ServerError::new("PersistedQueryNotFound", None))
+                    Err(ServerError::new("PersistedQueryNotFound", None)
+                        .with_code(ErrorCode::PersistedQueryNotFound))
                 }
             } else {
                 let sha256_hash = format!("{:x}", Sha256::digest(request.query.as_bytes()));
@@ -195,7 +196,10 @@ mod tests {
 
         assert_eq!(
             schema.execute(request).await.into_result().unwrap_err(),
-            vec![ServerError::new("PersistedQueryNotFound", None)]
+            vec![
+                ServerError::new("PersistedQueryNotFound", None)
+                    .with_code(ErrorCode::PersistedQueryNotFound)
+            ]
         );
     }
 }
@@ -0,0 +1,397 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::{Arc, Mutex, Weak},
+    time::Duration,
+};
+
+use futures_util::task::{Spawn, SpawnExt};
+
+use crate::{
+    QueryPathSegment, Response, ServerResult, Value,
+    extensions::{
+        Extension, ExtensionContext, ExtensionFactory, NextExecute, NextResolve, ResolveInfo,
+    },
+    runtime::Timer,
+};
+
+/// The name of the client sending a request, used by the [`FieldUsage`]
+/// extension to count the usage per client.
+///
+/// It is usually read from a header, such as `apollographql-client-name`, and
+/// added to the request data.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ClientName(pub String);
+
+/// The number of requests using each field, recorded by the [`FieldUsage`]
+/// extension.
+///
+/// The fields are identified by their `Type.field` coordinate, where `Type` is
+/// the concrete object type resolving the field.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct FieldUsageReport {
+    /// The number of executed requests.
+    pub requests: u64,
+    /// The number of requests using each field.
+    pub fields: BTreeMap<String, u64>,
+    /// The number of requests using each field, by client name.
+    pub clients: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+impl FieldUsageReport {
+    /// Returns `true` if no request has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.requests == 0
+    }
+}
+
+/// Receives the reports flushed by [`FieldUsageStats::flush_every`].
+pub trait FieldUsageSink: Send + Sync + 'static {
+    /// Receives the usage recorded since the previous flush.
+    fn flush(&self, report: FieldUsageReport);
+}
+
+impl<F> FieldUsageSink for F
+where
+    F: Fn(FieldUsageReport) + Send + Sync + 'static,
+{
+    fn flush(&self, report: FieldUsageReport) {
+        self(report)
+    }
+}
+
+/// Aggregates the field usage in memory.
+///
+/// Cloning a `FieldUsageStats` returns a handle to the same counters.
+#[derive(Clone, Default)]
+pub struct FieldUsageStats {
+    report: Arc<Mutex<FieldUsageReport>>,
+}
+
+impl FieldUsageStats {
+    /// Create empty counters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the usage recorded so far.
+    pub fn report(&self) -> FieldUsageReport {
+        self.report.lock().unwrap().clone()
+    }
+
+    /// Returns the usage recorded so far, and resets the counters.
+    pub fn take(&self) -> FieldUsageReport {
+        take_report(&self.report)
+    }
+
+    /// Send the usage to `sink` every `interval`, and reset the counters.
+    ///
+    /// Nothing is sent when no request has been recorded during the
+    /// interval. The task stops once every handle to the counters is dropped.
+    pub fn flush_every<S, TR>(
+        &self,
+        interval: Duration,
+        spawner: S,
+        timer: TR,
+        sink: impl FieldUsageSink,
+    ) where
+        S: Spawn,
+        TR: Timer,
+    {
+        let report = Arc::downgrade(&self.report);
+        spawner
+            .spawn(async move {
+                loop {
+                    timer.delay(interval).await;
+                    let Some(report) = Weak::upgrade(&report) else {
+                        return;
+                    };
+                    let report = take_report(&report);
+                    if !report.is_empty() {
+                        sink.flush(report);
+                    }
+                }
+            })
+            .expect("failed to spawn the field usage flush task");
+    }
+
+    fn record(&self, client_name: Option<&str>, coordinates: &BTreeSet<String>) {
+        let mut report = self.report.lock().unwrap();
+        report.requests += 1;
+        for coordinate in coordinates {
+            *report.fields.entry(coordinate.clone()).or_default() += 1;
+        }
+        if let Some(client_name) = client_name {
+            let client = report.clients.entry(client_name.to_string()).or_default();
+            for coordinate in coordinates {
+                *client.entry(coordinate.clone()).or_default() += 1;
+            }
+        }
+    }
+}
+
+fn take_report(report: &Mutex<FieldUsageReport>) -> FieldUsageReport {
+    std::mem::take(&mut *report.lock().unwrap())
+}
+
+/// Field usage extension
+///
+/// This extension counts the requests using each `Type.field` coordinate, in
+/// total and per [`ClientName`], to a [`FieldUsageStats`]. The fields selected
+/// through fragments and interfaces are counted on the concrete object type
+/// resolving them, and a field is counted once per request however many times
+/// it is resolved.
+///
+/// Only the queries and mutations are counted, and the introspection fields
+/// are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::{
+///     extensions::{ClientName, FieldUsage, FieldUsageStats},
+///     *,
+/// };
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn value(&self) -> i32 {
+///         100
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let stats = FieldUsageStats::new();
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .extension(FieldUsage::new(stats.clone()))
+///     .finish();
+///
+/// schema
+///     .execute(Request::new("{ value }").data(ClientName("web".to_string())))
+///     .await;
+/// let report = stats.report();
+/// assert_eq!(report.fields["Query.value"], 1);
+/// assert_eq!(report.clients["web"]["Query.value"], 1);
+/// # });
+/// ```
+pub struct FieldUsage {
+    stats: FieldUsageStats,
+}
+
+impl FieldUsage {
+    /// Create a field usage extension recording to `stats`.
+    pub fn new(stats: FieldUsageStats) -> Self {
+        Self { stats }
+    }
+}
+
+impl ExtensionFactory for FieldUsage {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(FieldUsageExtension {
+            stats: self.stats.clone(),
+            coordinates: Default::default(),
+        })
+    }
+}
+
+struct FieldUsageExtension {
+    stats: FieldUsageStats,
+    coordinates: Mutex<BTreeSet<String>>,
+}
+
+#[async_trait::async_trait]
+impl Extension for FieldUsageExtension {
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> Response {
+        let resp = next.run(ctx, operation_name).await;
+        let client_name = ctx.data_opt::<ClientName>().map(|name| name.0.as_str());
+        let coordinates = std::mem::take(&mut *self.coordinates.lock().unwrap());
+        self.stats.record(client_name, &coordinates);
+        resp
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        info: ResolveInfo<'_>,
+        next: NextResolve<'_>,
+    ) -> ServerResult<Option<Value>> {
+        if !info.is_for_introspection
+            && !info.name.starts_with("__")
+            && matches!(info.path_node.segment, QueryPathSegment::Name(_))
+        {
+            let coordinate = format!("{}.{}", info.parent_type, info.name);
+            self.coordinates.lock().unwrap().insert(coordinate);
+        }
+        next.run(ctx, info).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures_util::{
+        FutureExt,
+        future::BoxFuture,
+        task::{FutureObj, Spawn, SpawnError},
+    };
+
+    use crate::{
+        extensions::{ClientName, FieldUsage, FieldUsageReport, FieldUsageStats},
+        runtime::Timer,
+        *,
+    };
+
+    #[derive(SimpleObject)]
+    #[graphql(internal)]
+    struct Dog {
+        name: String,
+        barks: bool,
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(internal)]
+    struct Cat {
+        name: String,
+        meows: bool,
+    }
+
+    #[derive(Interface)]
+    #[graphql(internal, field(name = "name", ty = "&String"))]
+    enum Pet {
+        Dog(Dog),
+        Cat(Cat),
+    }
+
+    struct Query;
+
+    #[Object(internal)]
+    impl Query {
+        async fn pets(&self) -> Vec<Pet> {
+            vec![
+                Dog {
+                    name: "Rex".to_string(),
+                    barks: true,
+                }
+                .into(),
+                Dog {
+                    name: "Fido".to_string(),
+                    barks: false,
+                }
+                .into(),
+                Cat {
+                    name: "Tom".to_string(),
+                    meows: true,
+                }
+                .into(),
+            ]
+        }
+
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    #[tokio::test]
+    async fn test_field_usage() {
+        let stats = FieldUsageStats::new();
+        let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+            .extension(FieldUsage::new(stats.clone()))
+            .finish();
+
+        let query = r#"{
+            pets {
+                name
+                ... on Dog { barks }
+                ...CatFields
+            }
+        }
+        fragment CatFields on Cat { meows }"#;
+        schema
+            .execute(Request::new(query).data(ClientName("ios".to_string())))
+            .await
+            .into_result()
+            .unwrap();
+        schema.execute("{ value }").await.into_result().unwrap();
+        schema
+            .execute(Request::new("{ value __typename }").data(ClientName("web".to_string())))
+            .await
+            .into_result()
+            .unwrap();
+        schema
+            .execute("{ __schema { types { name } } }")
+            .await
+            .into_result()
+            .unwrap();
+
+        let report = stats.take();
+        assert_eq!(report.requests, 4);
+        assert_eq!(
+            report.fields.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("Cat.meows".to_string(), 1),
+                ("Cat.name".to_string(), 1),
+                ("Dog.barks".to_string(), 1),
+                ("Dog.name".to_string(), 1),
+                ("Query.pets".to_string(), 1),
+                ("Query.value".to_string(), 2),
+            ]
+        );
+        assert_eq!(report.clients.len(), 2);
+        assert_eq!(report.clients["ios"].len(), 5);
+        assert_eq!(report.clients["ios"]["Dog.name"], 1);
+        assert_eq!(report.clients["web"].len(), 1);
+        assert_eq!(report.clients["web"]["Query.value"], 1);
+        assert!(stats.report().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_field_usage_flush() {
+        struct Spawner;
+
+        impl Spawn for Spawner {
+            fn spawn_obj(&self, future: FutureObj<'static, ()>) -> Result<(), SpawnError> {
+                tokio::spawn(future);
+                Ok(())
+            }
+        }
+
+        struct SleepTimer;
+
+        impl Timer for SleepTimer {
+            fn delay(&self, duration: Duration) -> BoxFuture<'static, ()> {
+                tokio::time::sleep(duration).boxed()
+            }
+        }
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<FieldUsageReport>();
+        let stats = FieldUsageStats::new();
+        stats.flush_every(
+            Duration::from_millis(10),
+            Spawner,
+            SleepTimer,
+            move |report| {
+                tx.send(report).unwrap();
+            },
+        );
+        let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+            .extension(FieldUsage::new(stats.clone()))
+            .finish();
+
+        schema.execute("{ value }").await.into_result().unwrap();
+        let report = rx.recv().await.unwrap();
+        assert_eq!(report.requests, 1);
+        assert_eq!(report.fields["Query.value"], 1);
+        assert!(stats.report().is_empty());
+
+        drop(schema);
+        drop(stats);
+        assert!(rx.recv().await.is_none());
+    }
+}
@@ -0,0 +1,485 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use crate::{
+    Response, ServerError, ServerResult, ValidationResult, Value, Variables,
+    extensions::{
+        Extension, ExtensionContext, ExtensionFactory, NextExecute, NextParseQuery, NextRequest,
+        NextResolve, NextValidation, ResolveInfo,
+    },
+    parser::types::ExecutableDocument,
+};
+
+/// A phase of the execution of a request.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum MetricsPhase {
+    /// Parsing the query.
+    Parse,
+    /// Validating the query.
+    Validation,
+    /// Executing the operation.
+    Execute,
+}
+
+impl MetricsPhase {
+    /// Returns the name of the phase.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MetricsPhase::Parse => "parse",
+            MetricsPhase::Validation => "validation",
+            MetricsPhase::Execute => "execute",
+        }
+    }
+}
+
+/// Receives the metrics recorded by the [`Metrics`] extension.
+pub trait MetricsRecorder: Send + Sync + 'static {
+    /// Records a request, with its duration and the code of each of its
+    /// errors.
+    ///
+    /// The code of an error is the `code` extension of the error, or `UNKNOWN`.
+    fn record_request(&self, duration: Duration, error_codes: &[&str]);
+
+    /// Records the duration of a phase of a request.
+    fn record_phase(&self, phase: MetricsPhase, duration: Duration);
+
+    /// Records the duration of a field resolution.
+    fn record_field(&self, parent_type: &str, field_name: &str, duration: Duration);
+
+    /// Records the complexity and depth of a query.
+    fn record_query(&self, complexity: usize, depth: usize);
+}
+
+/// Metrics extension
+///
+/// This extension records the requests, their errors by code, the duration of
+/// their phases and of the field resolutions, and the complexity and depth of
+/// the queries to a [`MetricsRecorder`], such as [`OpenMetrics`].
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::{extensions::{Metrics, OpenMetrics}, *};
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn value(&self) -> i32 {
+///         100
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let metrics = OpenMetrics::new();
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .extension(Metrics::new(metrics.clone()))
+///     .finish();
+///
+/// schema.execute("{ value }").await;
+/// assert!(metrics.encode().contains("graphql_requests_total 1"));
+/// # });
+/// ```
+pub struct Metrics {
+    recorder: Arc<dyn MetricsRecorder>,
+    field_sampling: u64,
+    resolve_count: Arc<AtomicU64>,
+}
+
+impl Metrics {
+    /// Create a metrics extension recording to `recorder`.
+    pub fn new(recorder: impl MetricsRecorder) -> Self {
+        Self {
+            recorder: Arc::new(recorder),
+            field_sampling: 1,
+            resolve_count: Default::default(),
+        }
+    }
+
+    /// Only record the duration of one field resolution out of `n`, `0`
+    /// disables the field metrics. Defaults to `1`.
+    ///
+    /// The fields of introspection queries are never recorded.
+    #[must_use]
+    pub fn field_sampling(self, n: u64) -> Self {
+        Self {
+            field_sampling: n,
+            ..self
+        }
+    }
+}
+
+impl ExtensionFactory for Metrics {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(MetricsExtension {
+            recorder: self.recorder.clone(),
+            field_sampling: self.field_sampling,
+            resolve_count: self.resolve_count.clone(),
+        })
+    }
+}
+
+struct MetricsExtension {
+    recorder: Arc<dyn MetricsRecorder>,
+    field_sampling: u64,
+    resolve_count: Arc<AtomicU64>,
+}
+
+fn error_code(err: &ServerError) -> &str {
+    match err.extensions.as_ref().and_then(|ext| ext.get("code")) {
+        Some(Value::String(code)) => code,
+        Some(Value::Enum(code)) => code.as_str(),
+        _ => "UNKNOWN",
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for MetricsExtension {
+    async fn request(&self, ctx: &ExtensionContext<'_>, next: NextRequest<'_>) -> Response {
+        let start = Instant::now();
+        let resp = next.run(ctx).await;
+        let error_codes = resp.errors.iter().map(error_code).collect::<Vec<_>>();
+        self.recorder.record_request(start.elapsed(), &error_codes);
+        resp
+    }
+
+    async fn parse_query(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        query: &str,
+        variables: &Variables,
+        next: NextParseQuery<'_>,
+    ) -> ServerResult<ExecutableDocument> {
+        let start = Instant::now();
+        let res = next.run(ctx, query, variables).await;
+        self.recorder
+            .record_phase(MetricsPhase::Parse, start.elapsed());
+        res
+    }
+
+    async fn validation(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextValidation<'_>,
+    ) -> Result<ValidationResult, Vec<ServerError>> {
+        let start = Instant::now();
+        let res = next.run(ctx).await;
+        self.recorder
+            .record_phase(MetricsPhase::Validation, start.elapsed());
+        if let Ok(res) = &res {
+            self.recorder.record_query(res.complexity, res.depth);
+        }
+        res
+    }
+
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> Response {
+        let start = Instant::now();
+        let resp = next.run(ctx, operation_name).await;
+        self.recorder
+            .record_phase(MetricsPhase::Execute, start.elapsed());
+        resp
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        info: ResolveInfo<'_>,
+        next: NextResolve<'_>,
+    ) -> ServerResult<Option<Value>> {
+        if info.is_for_introspection
+            || info.name.starts_with("__")
+            || self.field_sampling == 0
+            || !self
+                .resolve_count
+                .fetch_add(1, Ordering::Relaxed)
+                .is_multiple_of(self.field_sampling)
+        {
+            return next.run(ctx, info).await;
+        }
+
+        let parent_type = info.parent_type;
+        let field_name = info.name;
+        let start = Instant::now();
+        let res = next.run(ctx, info).await;
+        self.recorder
+            .record_field(parent_type, field_name, start.elapsed());
+        res
+    }
+}
+
+const DURATION_BUCKETS: &[f64] = &[
+    0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+const COMPLEXITY_BUCKETS: &[f64] = &[1.0, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0];
+const DEPTH_BUCKETS: &[f64] = &[1.0, 2.0, 3.0, 5.0, 8.0, 13.0, 21.0];
+
+struct Histogram {
+    buckets: &'static [f64],
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(buckets: &'static [f64]) -> Self {
+        Self {
+            buckets,
+            counts: vec![0; buckets.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        for (bucket, count) in self.buckets.iter().zip(&mut self.counts) {
+            if value <= *bucket {
+                *count += 1;
+            }
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+
+    fn encode(&self, output: &mut String, name: &str, labels: &str) {
+        let separator = if labels.is_empty() { "" } else { "," };
+        for (bucket, count) in self.buckets.iter().zip(&self.counts) {
+            // `Debug` keeps the fractional part, as the canonical `le` values
+            // of OpenMetrics are floats, e.g. `1.0` and not `1`.
+            let _ = writeln!(
+                output,
+                "{}_bucket{{{}{}le=\"{:?}\"}} {}",
+                name, labels, separator, bucket, count
+            );
+        }
+        let _ = writeln!(
+            output,
+            "{}_bucket{{{}{}le=\"+Inf\"}} {}",
+            name, labels, separator, self.count
+        );
+        let labels = if labels.is_empty() {
+            String::new()
+        } else {
+            format!("{{{}}}", labels)
+        };
+        let _ = writeln!(output, "{}_sum{} {}", name, labels, self.sum);
+        let _ = writeln!(output, "{}_count{} {}", name, labels, self.count);
+    }
+}
+
+struct OpenMetricsState {
+    requests: u64,
+    errors: BTreeMap<String, u64>,
+    request_duration: Histogram,
+    phase_duration: BTreeMap<MetricsPhase, Histogram>,
+    field_duration: BTreeMap<(String, String), Histogram>,
+    complexity: Histogram,
+    depth: Histogram,
+}
+
+/// A [`MetricsRecorder`] storing the metrics in memory, to expose them in the
+/// [OpenMetrics](https://openmetrics.io) text format.
+///
+/// Cloning an `OpenMetrics` returns a handle to the same metrics.
+#[derive(Clone)]
+pub struct OpenMetrics {
+    state: Arc<Mutex<OpenMetricsState>>,
+}
+
+impl Default for OpenMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+impl OpenMetrics {
+    /// Create an empty set of metrics.
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(OpenMetricsState {
+                requests: 0,
+                errors: Default::default(),
+                request_duration: Histogram::new(DURATION_BUCKETS),
+                phase_duration: Default::default(),
+                field_duration: Default::default(),
+                complexity: Histogram::new(COMPLEXITY_BUCKETS),
+                depth: Histogram::new(DEPTH_BUCKETS),
+            })),
+        }
+    }
+
+    /// Returns the metrics in the OpenMetrics text format.
+    pub fn encode(&self) -> String {
+        let state = self.state.lock().unwrap();
+        let mut output = String::new();
+
+        output.push_str("# TYPE graphql_requests counter\n");
+        output.push_str("# HELP graphql_requests The number of requests.\n");
+        let _ = writeln!(output, "graphql_requests_total {}", state.requests);
+
+        output.push_str("# TYPE graphql_errors counter\n");
+        output.push_str("# HELP graphql_errors The number of errors, by code.\n");
+        for (code, count) in &state.errors {
+            let _ = writeln!(
+                output,
+                "graphql_errors_total{{code=\"{}\"}} {}",
+                escape_label(code),
+                count
+            );
+        }
+
+        output.push_str("# TYPE graphql_request_duration_seconds histogram\n");
+        output.push_str("# HELP graphql_request_duration_seconds The duration of the requests.\n");
+        state
+            .request_duration
+            .encode(&mut output, "graphql_request_duration_seconds", "");
+
+        output.push_str("# TYPE graphql_phase_duration_seconds histogram\n");
+        output.push_str(
+            "# HELP graphql_phase_duration_seconds The duration of the parse, validation and execute phases.\n",
+        );
+        for (phase, histogram) in &state.phase_duration {
+            histogram.encode(
+                &mut output,
+                "graphql_phase_duration_seconds",
+                &format!("phase=\"{}\"", phase.as_str()),
+            );
+        }
+
+        output.push_str("# TYPE graphql_field_duration_seconds histogram\n");
+        output.push_str(
+            "# HELP graphql_field_duration_seconds The duration of the field resolutions.\n",
+        );
+        for ((parent_type, field_name), histogram) in &state.field_duration {
+            histogram.encode(
+                &mut output,
+                "graphql_field_duration_seconds",
+                &format!(
+                    "parent_type=\"{}\",field=\"{}\"",
+                    escape_label(parent_type),
+                    escape_label(field_name)
+                ),
+            );
+        }
+
+        output.push_str("# TYPE graphql_query_complexity histogram\n");
+        output.push_str("# HELP graphql_query_complexity The complexity of the queries.\n");
+        state
+            .complexity
+            .encode(&mut output, "graphql_query_complexity", "");
+
+        output.push_str("# TYPE graphql_query_depth histogram\n");
+        output.push_str("# HELP graphql_query_depth The depth of the queries.\n");
+        state.depth.encode(&mut output, "graphql_query_depth", "");
+
+        output.push_str("# EOF\n");
+        output
+    }
+}
+
+impl MetricsRecorder for OpenMetrics {
+    fn record_request(&self, duration: Duration, error_codes: &[&str]) {
+        let mut state = self.state.lock().unwrap();
+        state.requests += 1;
+        state.request_duration.observe(duration.as_secs_f64());
+        for code in error_codes {
+            *state.errors.entry(code.to_string()).or_default() += 1;
+        }
+    }
+
+    fn record_phase(&self, phase: MetricsPhase, duration: Duration) {
+        self.state
+            .lock()
+            .unwrap()
+            .phase_duration
+            .entry(phase)
+            .or_insert_with(|| Histogram::new(DURATION_BUCKETS))
+            .observe(duration.as_secs_f64());
+    }
+
+    fn record_field(&self, parent_type: &str, field_name: &str, duration: Duration) {
+        self.state
+            .lock()
+            .unwrap()
+            .field_duration
+            .entry((parent_type.to_string(), field_name.to_string()))
+            .or_insert_with(|| Histogram::new(DURATION_BUCKETS))
+            .observe(duration.as_secs_f64());
+    }
+
+    fn record_query(&self, complexity: usize, depth: usize) {
+        let mut state = self.state.lock().unwrap();
+        state.complexity.observe(complexity as f64);
+        state.depth.observe(depth as f64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        extensions::{Metrics, OpenMetrics},
+        *,
+    };
+
+    #[tokio::test]
+    async fn test_metrics() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn value(&self) -> i32 {
+                100
+            }
+
+            async fn fail(&self) -> Result<i32> {
+                Err(Error::new("failed").extend_with(|_, e| e.set("code", "FAILED")))
+            }
+        }
+
+        let metrics = OpenMetrics::new();
+        let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+            .extension(Metrics::new(metrics.clone()))
+            .finish();
+
+        schema.execute("{ value }").await.into_result().unwrap();
+        schema.execute("{ value fail }").await;
+        schema.execute("{ value").await;
+        schema.execute("{ __schema { types { name } } }").await;
+
+        let output = metrics.encode();
+        assert!(output.contains("graphql_requests_total 4\n"));
+        assert!(output.contains("graphql_errors_total{code=\"FAILED\"} 1\n"));
+        assert!(output.contains("graphql_errors_total{code=\"GRAPHQL_PARSE_FAILED\"} 1\n"));
+        assert!(output.contains("graphql_request_duration_seconds_count 4\n"));
+        assert!(output.contains("graphql_phase_duration_seconds_count{phase=\"parse\"} 4\n"));
+        assert!(output.contains("graphql_phase_duration_seconds_count{phase=\"execute\"} 3\n"));
+        assert!(output.contains(
+            "graphql_field_duration_seconds_count{parent_type=\"Query\",field=\"value\"} 2\n"
+        ));
+        assert!(output.contains(
+            "graphql_field_duration_seconds_count{parent_type=\"Query\",field=\"fail\"} 1\n"
+        ));
+        assert!(!output.contains("__schema"));
+        assert!(output.contains("graphql_query_depth_bucket{le=\"1.0\"} 2\n"));
+        assert!(output.contains("graphql_request_duration_seconds_bucket{le=\"0.005\"}"));
+        assert!(output.ends_with("# EOF\n"));
+    }
+}
@@ -5,8 +5,10 @@ mod analyzer;
 pub mod apollo_persisted_queries;
 #[cfg(feature = "apollo_tracing")]
 mod apollo_tracing;
+mod field_usage;
 #[cfg(feature = "log")]
 mod logger;
+mod metrics;
 
 #[cfg(feature = "tracing")]
 mod tracing;
@@ -22,13 +24,18 @@ use futures_util::{FutureExt, future::BoxFuture, stream::BoxStream};
 pub use self::analyzer::Analyzer;
 #[cfg(feature = "apollo_tracing")]
 pub use self::apollo_tracing::ApolloTracing;
+pub use self::field_usage::{
+    ClientName, FieldUsage, FieldUsageReport, FieldUsageSink, FieldUsageStats,
+};
 #[cfg(feature = "log")]
 pub use self::logger::Logger;
+pub use self::metrics::{Metrics, MetricsPhase, MetricsRecorder, OpenMetrics};
 #[cfg(feature = "tracing")]
 pub use self::tracing::Tracing;
 use crate::{
-    Data, DataContext, Error, QueryPathNode, Request, Response, Result, SDLExportOptions,
-    SchemaEnv, ServerError, ServerResult, ValidationResult, Value, Variables,
+    CancellationToken, Data, DataContext, DeprecatedUsage, Error, QueryPathNode, Request, Response,
+    Result, SDLExportOptions, SchemaEnv, ServerError, ServerResult, ValidationResult, Value,
+    Variables,
     parser::types::{ExecutableDocument, Field},
 };
 
@@ -53,6 +60,10 @@ pub struct ExtensionContext<'a> {
     /// inside the [`Extension::request`], [`Extension::subscribe`], and
     /// [`Extension::prepare_request`] hooks.
     pub query_data: Option<&'a Data>,
+
+    pub(crate) deprecations: &'a [DeprecatedUsage],
+
+    pub(crate) cancellation_token: &'a CancellationToken,
 }
 
 impl<'a> DataContext<'a> for ExtensionContext<'a> {
@@ -80,6 +91,22 @@ impl<'a> ExtensionContext<'a> {
             .unwrap_or_default()
     }
 
+    /// Returns the deprecated fields, arguments, input fields and enum values
+    /// used by the operation.
+    ///
+    /// The usage is only collected with
+    /// [`SchemaBuilder::deprecation_reporting`](crate::SchemaBuilder::deprecation_reporting),
+    /// and is available from the `execute` and `resolve` hooks.
+    pub fn deprecated_usage(&self) -> &'a [DeprecatedUsage] {
+        self.deprecations
+    }
+
+    /// Returns the token cancelled when the client does not wait for the
+    /// response anymore, see [`CancellationToken`].
+    pub fn cancellation_token(&self) -> &'a CancellationToken {
+        self.cancellation_token
+    }
+
     /// Returns SDL(Schema Definition Language) of this schema.
     pub fn sdl(&self) -> String {
         self.schema_env.registry.export_sdl(Default::default())
@@ -133,7 +160,7 @@ pub struct ResolveInfo<'a> {
     /// Current path node, You can go through the entire path.
     pub path_node: &'a QueryPathNode<'a>,
 
-    /// Parent type
+    /// Parent type, the concrete object type for the fields of interfaces
     pub parent_type: &'a str,
 
     /// Current return type, is qualified name.
@@ -459,6 +486,8 @@ pub struct Extensions {
     schema_env: SchemaEnv,
     session_data: Arc<Data>,
     query_data: Option<Arc<Data>>,
+    deprecations: Vec<DeprecatedUsage>,
+    cancellation_token: CancellationToken,
 }
 
 #[doc(hidden)]
@@ -467,12 +496,15 @@ impl Extensions {
         extensions: impl IntoIterator<Item = Arc<dyn Extension>>,
         schema_env: SchemaEnv,
         session_data: Arc<Data>,
+        cancellation_token: CancellationToken,
     ) -> Self {
         Extensions {
             extensions: extensions.into_iter().collect(),
             schema_env,
             session_data,
             query_data: None,
+            deprecations: Vec::new(),
+            cancellation_token,
         }
     }
 
@@ -481,6 +513,21 @@ impl Extensions {
         self.query_data = Some(data);
     }
 
+    #[inline]
+    pub(crate) fn attach_deprecations(&mut self, deprecations: Vec<DeprecatedUsage>) {
+        self.deprecations = deprecations;
+    }
+
+    #[inline]
+    pub(crate) fn deprecations(&self) -> &[DeprecatedUsage] {
+        &self.deprecations
+    }
+
+    #[inline]
+    pub(crate) fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancellation_token
+    }
+
     #[inline]
     pub(crate) fn is_empty(&self) -> bool {
         self.extensions.is_empty()
@@ -492,6 +539,8 @@ impl Extensions {
             schema_env: &self.schema_env,
             session_data: &self.session_data,
             query_data: self.query_data.as_deref(),
+            deprecations: &self.deprecations,
+            cancellation_token: &self.cancellation_token,
         }
     }
 
@@ -29,6 +29,87 @@ where
     }
 }
 
+/// What a [`ResultGuard`] does with the resolved value.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GuardAction {
+    /// Return the value.
+    Allow,
+    /// Return `null` instead of the value.
+    ///
+    /// A forbidden error is returned instead if the field is non-null.
+    Redact,
+}
+
+/// Post-resolution field guard
+///
+/// Unlike [`Guard`], it is checked after the field has been resolved, with
+/// the resolved value. When it is set on an object, it is checked once with
+/// each value of the object, and redacting it redacts all of its fields.
+/// It allows or redacts the value, or denies access by returning an error.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// #[derive(SimpleObject)]
+/// struct Order {
+///     owner: i32,
+///     total: i32,
+/// }
+///
+/// fn only_owner(ctx: &Context<'_>, order: &Option<Order>) -> Result<GuardAction> {
+///     match order {
+///         Some(order) if order.owner != *ctx.data::<i32>()? => Ok(GuardAction::Redact),
+///         _ => Ok(GuardAction::Allow),
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     #[graphql(guard_result = "only_owner")]
+///     async fn order(&self, id: i32) -> Option<Order> {
+///         Some(Order { owner: id, total: 100 })
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+/// let query = "{ order1: order(id: 1) { total } order2: order(id: 2) { total } }";
+/// assert_eq!(
+///     schema.execute(Request::new(query).data(1)).await.data,
+///     value!({ "order1": { "total": 100 }, "order2": null })
+/// );
+/// # });
+/// ```
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+pub trait ResultGuard<T: Send + Sync> {
+    /// Check whether the guard will allow access to the value.
+    #[cfg(feature = "boxed-trait")]
+    async fn check(&self, ctx: &Context<'_>, value: &T) -> Result<GuardAction>;
+
+    /// Check whether the guard will allow access to the value.
+    #[cfg(not(feature = "boxed-trait"))]
+    fn check(
+        &self,
+        ctx: &Context<'_>,
+        value: &T,
+    ) -> impl Future<Output = Result<GuardAction>> + Send;
+}
+
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl<T, F> ResultGuard<T> for F
+where
+    T: Send + Sync,
+    F: Fn(&Context<'_>, &T) -> Result<GuardAction> + Send + Sync + 'static,
+{
+    async fn check(&self, ctx: &Context<'_>, value: &T) -> Result<GuardAction> {
+        self(ctx, value)
+    }
+}
+
 /// An extension trait for `Guard`.
 pub trait GuardExt: Guard + Sized {
     /// Perform `and` operator on two rules
@@ -10,6 +10,7 @@ use futures_util::io::{AsyncRead, AsyncReadExt};
 #[cfg(feature = "graphiql")]
 pub use graphiql_source::{Credentials, GraphiQLSource};
 pub use multipart::MultipartOptions;
+pub(crate) use multipart::{MultipartUploads, ReaderStream};
 pub use multipart_subscribe::{create_multipart_mixed_stream, is_accept_multipart_mixed};
 use serde::Deserialize;
 pub use websocket::{
@@ -65,6 +66,11 @@ pub async fn receive_body(
 }
 
 /// Receive a GraphQL request from a content type and body.
+///
+/// The uploaded files are stored before the request is executed. A multipart
+/// request is rejected with [`ParseRequestError::UnsupportedStreamingUploads`]
+/// if [`MultipartOptions::stream_uploads`] is enabled, use
+/// [`receive_batch_body_streaming`] instead.
 pub async fn receive_batch_body(
     content_type: Option<impl AsRef<str>>,
     body: impl AsyncRead + Send,
@@ -80,6 +86,9 @@ pub async fn receive_batch_body(
 
     match (content_type.type_(), content_type.subtype()) {
         // try to use multipart
+        (mime::MULTIPART, _) if opts.stream_uploads => {
+            Err(ParseRequestError::UnsupportedStreamingUploads)
+        }
         (mime::MULTIPART, _) => {
             if let Some(boundary) = content_type.get_param("boundary") {
                 multipart::receive_batch_multipart(body, boundary.to_string(), opts).await
@@ -94,6 +103,47 @@ pub async fn receive_batch_body(
     }
 }
 
+/// Receive a GraphQL request from a content type and body, streaming the
+/// uploaded files if [`MultipartOptions::stream_uploads`] is enabled.
+pub async fn receive_body_streaming(
+    content_type: Option<impl AsRef<str>>,
+    body: impl AsyncRead + Send + 'static,
+    opts: MultipartOptions,
+) -> Result<Request, ParseRequestError> {
+    receive_batch_body_streaming(content_type, body, opts)
+        .await?
+        .into_single()
+}
+
+/// Receive a GraphQL batch request from a content type and body, streaming the
+/// uploaded files if [`MultipartOptions::stream_uploads`] is enabled.
+///
+/// Unlike [`receive_batch_body`], the body must be owned so that the files can
+/// be read from it while the request is executed.
+pub async fn receive_batch_body_streaming(
+    content_type: Option<impl AsRef<str>>,
+    body: impl AsyncRead + Send + 'static,
+    opts: MultipartOptions,
+) -> Result<BatchRequest, ParseRequestError> {
+    if opts.stream_uploads
+        && let Some(content_type) = &content_type
+    {
+        let content_type: mime::Mime = content_type.as_ref().parse()?;
+        if content_type.type_() == mime::MULTIPART {
+            return match content_type.get_param("boundary") {
+                Some(boundary) => {
+                    multipart::receive_batch_multipart_streaming(body, boundary.to_string(), opts)
+                        .await
+                }
+                None => Err(ParseRequestError::InvalidMultipart(
+                    multer::Error::NoBoundary,
+                )),
+            };
+        }
+    }
+    receive_batch_body(content_type, body, opts).await
+}
+
 /// Receives a GraphQL query which is json but NOT multipart
 /// This method is only to avoid recursive calls with [``receive_batch_body``]
 /// and [``multipart::receive_batch_multipart``]
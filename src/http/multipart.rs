@@ -2,14 +2,16 @@ use std::{
     collections::HashMap,
     io,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
 };
 
-use futures_util::{io::AsyncRead, stream::Stream};
+use futures_util::{TryStreamExt, io::AsyncRead, lock::Mutex, stream::Stream};
+use indexmap::IndexMap;
 use multer::{Constraints, Multipart, SizeLimit};
 use pin_project_lite::pin_project;
 
-use crate::{BatchRequest, ParseRequestError, UploadValue};
+use crate::{BatchRequest, ParseRequestError, UploadStream, UploadValue};
 
 /// Options for `receive_multipart`.
 #[derive(Default, Clone, Copy)]
@@ -19,6 +21,8 @@ pub struct MultipartOptions {
     pub max_file_size: Option<usize>,
     /// The maximum number of files.
     pub max_num_files: Option<usize>,
+    /// Stream the uploaded files instead of buffering them.
+    pub stream_uploads: bool,
 }
 
 impl MultipartOptions {
@@ -39,29 +43,47 @@ impl MultipartOptions {
             ..self
         }
     }
-}
 
-pub(super) async fn receive_batch_multipart(
-    body: impl AsyncRead + Send,
-    boundary: impl Into<String>,
-    opts: MultipartOptions,
-) -> Result<BatchRequest, ParseRequestError> {
-    let mut multipart = Multipart::with_constraints(
-        ReaderStream::new(body),
-        boundary,
+    /// Stream the uploaded files from the request body while the request is
+    /// executed, instead of storing them before the execution starts.
+    ///
+    /// The files must then be read with [`Upload::stream`](crate::Upload::stream),
+    /// one at a time and in the order of the `map` part. The size limits are
+    /// enforced while the files are read.
+    ///
+    /// This requires receiving the request with
+    /// [`receive_batch_body_streaming`](super::receive_batch_body_streaming).
+    #[must_use]
+    pub fn stream_uploads(self, enabled: bool) -> Self {
+        MultipartOptions {
+            stream_uploads: enabled,
+            ..self
+        }
+    }
+
+    fn constraints(&self) -> Constraints {
         Constraints::new().size_limit({
             let mut limit = SizeLimit::new();
             if let (Some(max_file_size), Some(max_num_files)) =
-                (opts.max_file_size, opts.max_num_files)
+                (self.max_file_size, self.max_num_files)
             {
                 limit = limit.whole_stream((max_file_size * max_num_files) as u64);
             }
-            if let Some(max_file_size) = opts.max_file_size {
+            if let Some(max_file_size) = self.max_file_size {
                 limit = limit.per_field(max_file_size as u64);
             }
             limit
-        }),
-    );
+        })
+    }
+}
+
+pub(super) async fn receive_batch_multipart(
+    body: impl AsyncRead + Send,
+    boundary: impl Into<String>,
+    opts: MultipartOptions,
+) -> Result<BatchRequest, ParseRequestError> {
+    let mut multipart =
+        Multipart::with_constraints(ReaderStream::new(body), boundary, opts.constraints());
 
     let mut request = None;
     let mut map = None;
@@ -166,6 +188,155 @@ pub(super) async fn receive_batch_multipart(
     Ok(request)
 }
 
+pub(super) async fn receive_batch_multipart_streaming(
+    body: impl AsyncRead + Send + 'static,
+    boundary: impl Into<String>,
+    opts: MultipartOptions,
+) -> Result<BatchRequest, ParseRequestError> {
+    let mut multipart =
+        Multipart::with_constraints(ReaderStream::new(body), boundary, opts.constraints());
+
+    let mut request = None;
+    let mut map = None;
+
+    // The `operations` and `map` parts come before the files.
+    while request.is_none() || map.is_none() {
+        let Some(field) = multipart.next_field().await? else {
+            break;
+        };
+        let content_type = field
+            .content_type()
+            .unwrap_or(&mime::APPLICATION_JSON)
+            .clone();
+        match field.name() {
+            Some("operations") => {
+                let body = field.bytes().await?;
+                request = Some(
+                    super::receive_batch_body_no_multipart(&content_type, body.as_ref()).await?,
+                )
+            }
+            Some("map") => {
+                let map_bytes = field.bytes().await?;
+                map = Some(
+                    serde_json::from_slice::<IndexMap<String, Vec<String>>>(&map_bytes)
+                        .map_err(|e| ParseRequestError::InvalidFilesMap(Box::new(e)))?,
+                );
+            }
+            _ => break,
+        }
+    }
+
+    let mut request: BatchRequest = request.ok_or(ParseRequestError::MissingOperatorsPart)?;
+    let map = map.ok_or(ParseRequestError::MissingMapPart)?;
+    if opts
+        .max_num_files
+        .is_some_and(|max_num_files| map.len() > max_num_files)
+    {
+        return Err(ParseRequestError::PayloadTooLarge);
+    }
+
+    let uploads = Arc::new(MultipartUploads {
+        state: Mutex::new(MultipartUploadsState {
+            multipart,
+            names: map.keys().cloned().collect(),
+            next: 0,
+        }),
+    });
+
+    for (index, var_paths) in map.into_values().enumerate() {
+        for var_path in var_paths {
+            match &mut request {
+                BatchRequest::Single(request) => {
+                    request.set_upload_stream(&var_path, index, &uploads);
+                }
+                BatchRequest::Batch(requests) => {
+                    let mut s = var_path.splitn(2, '.');
+                    let idx = s.next().and_then(|idx| idx.parse::<usize>().ok());
+                    let path = s.next();
+
+                    if let (Some(idx), Some(path)) = (idx, path)
+                        && let Some(request) = requests.get_mut(idx)
+                    {
+                        request.set_upload_stream(path, index, &uploads);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(request)
+}
+
+/// The files of a multipart request, read from the body while the request is
+/// executed.
+pub(crate) struct MultipartUploads {
+    state: Mutex<MultipartUploadsState>,
+}
+
+struct MultipartUploadsState {
+    multipart: Multipart<'static>,
+    /// The names of the file parts, in the order of the `map` part.
+    names: Vec<String>,
+    /// The index of the next file part in the body.
+    next: usize,
+}
+
+fn multipart_error(err: multer::Error) -> io::Error {
+    match err {
+        multer::Error::LockFailure => io::Error::other(
+            "the previous upload must be read or dropped before reading the next one",
+        ),
+        err => io::Error::other(err),
+    }
+}
+
+impl MultipartUploads {
+    /// Skips the files before the upload at `index` in the body, and returns a
+    /// stream of its content.
+    pub(crate) async fn open(&self, index: usize) -> io::Result<UploadStream> {
+        let mut state = self.state.lock().await;
+        if index < state.next {
+            return Err(io::Error::other(
+                "the upload has already been read, uploads must be read in the order of the map",
+            ));
+        }
+
+        loop {
+            let expected = state.names.get(state.next).cloned().unwrap_or_default();
+            let field = state
+                .multipart
+                .next_field()
+                .await
+                .map_err(multipart_error)?
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        format!("missing the file \"{}\"", expected),
+                    )
+                })?;
+            let Some(filename) = field.file_name().map(ToString::to_string) else {
+                continue;
+            };
+
+            if field.name() != Some(expected.as_str()) {
+                return Err(io::Error::other(format!(
+                    "expected the file \"{}\", files must be sent in the order of the map",
+                    expected
+                )));
+            }
+            state.next += 1;
+
+            if state.next > index {
+                return Ok(UploadStream {
+                    filename,
+                    content_type: field.content_type().map(ToString::to_string),
+                    content: Box::pin(field.map_err(multipart_error)),
+                });
+            }
+        }
+    }
+}
+
 pin_project! {
     pub(crate) struct ReaderStream<T> {
         buf: [u8; 2048],
@@ -17,7 +17,9 @@ use futures_util::{
 use pin_project_lite::pin_project;
 use serde::{Deserialize, Serialize};
 
-use crate::{Data, Error, Executor, Request, Response, Result, runtime::Timer as RtTimer};
+use crate::{
+    CancelOnDrop, Data, Error, Executor, Request, Response, Result, runtime::Timer as RtTimer,
+};
 
 /// All known protocols based on WebSocket.
 pub const ALL_WEBSOCKET_PROTOCOLS: [&str; 2] = ["graphql-transport-ws", "graphql-ws"];
@@ -104,9 +106,20 @@ impl Stream for Timer {
     }
 }
 
+/// An operation started by the client.
+struct Operation {
+    stream: Pin<Box<dyn Stream<Item = Response> + Send>>,
+    // Cancels the request when the client stops the operation or disconnects.
+    cancel: CancelOnDrop,
+}
+
 pin_project! {
     /// A GraphQL connection over websocket.
     ///
+    /// The requests are cancelled when the client completes the operation
+    /// or the connection is closed, see
+    /// [`CancellationToken`](crate::CancellationToken).
+    ///
     /// # References
     ///
     /// - [subscriptions-transport-ws](https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md)
@@ -119,7 +132,7 @@ pin_project! {
         connection_data: Option<Data>,
         data: Option<Arc<Data>>,
         executor: E,
-        streams: HashMap<String, Pin<Box<dyn Stream<Item = Response> + Send>>>,
+        streams: HashMap<String, Operation>,
         #[pin]
         stream: S,
         protocol: Protocols,
@@ -377,9 +390,15 @@ where
                         payload: request,
                     } => {
                         if let Some(data) = this.data.clone() {
+                            let cancel = request.cancellation_token.clone().drop_guard();
                             this.streams.insert(
                                 id,
-                                Box::pin(this.executor.execute_stream(request, Some(data))),
+                                Operation {
+                                    stream: Box::pin(
+                                        this.executor.execute_stream(request, Some(data)),
+                                    ),
+                                    cancel,
+                                },
                             );
                         } else {
                             *this.close = true;
@@ -472,8 +491,8 @@ where
             });
         }
 
-        for (id, stream) in &mut *this.streams {
-            match Pin::new(stream).poll_next(cx) {
+        for (id, operation) in &mut *this.streams {
+            match operation.stream.as_mut().poll_next(cx) {
                 Poll::Ready(Some(payload)) => {
                     return Poll::Ready(Some(WsMessage::Text(
                         serde_json::to_string(&this.protocol.next_message(id, payload)).unwrap(),
@@ -481,7 +500,9 @@ where
                 }
                 Poll::Ready(None) => {
                     let id = id.clone();
-                    this.streams.remove(&id);
+                    if let Some(operation) = this.streams.remove(&id) {
+                        operation.cancel.disarm();
+                    }
                     return Poll::Ready(Some(WsMessage::Text(
                         serde_json::to_string(&ServerMessage::Complete { id: &id }).unwrap(),
                     )));
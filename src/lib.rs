@@ -72,10 +72,13 @@
 //! | **`fast_chemail`**             | Integrate with the [`fast_chemail` crate](https://crates.io/crates/fast_chemail).                                                                                                             |
 //! | **`graphiql`**                 | Enables the [GraphiQL IDE](https://github.com/graphql/graphiql) integration                                                                                                                   |
 //! | **`jiff`**                     | Integrate with the [`jiff` crate](https://crates.io/crates/jiff).                                                                                                                             |
+//! | **`int64_as_long`**            | Expose `i64`, `u64`, `isize` and `usize` as the [`Long`](types/struct.Long.html) scalar instead of `Int`.                                                                                     |
+//! | **`introspection_cache`**      | Enable the cache of introspection results, see `SchemaBuilder::enable_introspection_cache`.                                                                                                   |
 //! | **`log`**                      | Enable the [Logger extension](https://docs.rs/async-graphql/latest/async_graphql/extensions/struct.Logger.html).                                                                              |
 //! | **`opentelemetry`**            | Enable the [OpenTelemetry extension](https://docs.rs/async-graphql/latest/async_graphql/extensions/struct.OpenTelemetry.html).                                                                |
 //! | **`rawvalue`**                 | Support raw values from [`serde_json`](https://crates.io/crates/serde_json)                                                                                                                   |
 //! | **`secrecy`**                  | Integrate with the [`secrecy` crate](https://crates.io/crates/secrecy).                                                                                                                       |
+//! | **`secure_cursor`**            | Enable the [signed and encrypted cursors](types/connection/struct.SignedCursor.html).                                                                                                         |
 //! | **`string_number`**            | Enable the [StringNumber](types/struct.StringNumber.html).                                                                                                                                    |
 //! | **`time`**                     | Integrate with the [`time` crate](https://github.com/time-rs/time).                                                                                                                           |
 //! | **`tracing`**                  | Enable the [Tracing extension](https://docs.rs/async-graphql/latest/async_graphql/extensions/struct.Tracing.html).                                                                            |
@@ -171,9 +174,14 @@
 #![forbid(unsafe_code)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+mod authorization;
 mod base;
+mod cancellation;
+mod concurrency;
 mod custom_directive;
+mod deprecation;
 mod error;
+mod error_policy;
 mod executor;
 mod guard;
 mod look_ahead;
@@ -182,6 +190,7 @@ mod request;
 mod response;
 mod schema;
 mod subscription;
+mod timeout;
 mod validation;
 
 pub mod context;
@@ -209,29 +218,33 @@ pub use async_graphql_value::{
 };
 #[doc(hidden)]
 pub use async_trait;
+pub use authorization::{Authorization, ScopeProvider, Scopes, ScopesFromData};
 pub use base::{
     ComplexObject, Description, InputObjectType, InputType, InterfaceType, ObjectType,
     OneofObjectType, OutputType, TypeName, UnionType,
 };
+pub use cancellation::{CancelOnDrop, CancellationToken, Cancelled};
 #[doc(hidden)]
 pub use context::ContextSelectionSet;
 pub use context::*;
 pub use custom_directive::{CustomDirective, CustomDirectiveFactory, TypeDirective};
+pub use deprecation::DeprecationReporting;
 pub use error::{
-    Error, ErrorExtensionValues, ErrorExtensions, InputValueError, InputValueResult,
+    Error, ErrorCode, ErrorExtensionValues, ErrorExtensions, InputValueError, InputValueResult,
     ParseRequestError, PathSegment, Result, ResultExt, ServerError, ServerResult,
 };
+pub use error_policy::{ErrorClass, ErrorPolicy, MaskInternalErrors};
 pub use executor::Executor;
 pub use extensions::ResolveFut;
 #[doc(hidden)]
 pub use futures_util;
-pub use guard::{Guard, GuardExt};
+pub use guard::{Guard, GuardAction, GuardExt, ResultGuard};
 #[doc(hidden)]
 pub use indexmap;
 pub use look_ahead::Lookahead;
 #[doc(no_inline)]
 pub use parser::{Pos, Positioned};
-pub use registry::{CacheControl, SDLExportOptions};
+pub use registry::{CacheControl, Contract, SDLExportOptions};
 pub use request::{BatchRequest, Request};
 #[doc(no_inline)]
 pub use resolver_utils::{ContainerType, EnumType, ScalarType};
@@ -241,8 +254,10 @@ pub use schema::{IntrospectionMode, Schema, SchemaBuilder, SchemaEnv};
 pub use static_assertions_next;
 pub use subscription::SubscriptionType;
 pub use types::*;
-pub use validation::{ValidationMode, ValidationResult, VisitorContext};
-pub use validators::CustomValidator;
+pub use validation::{
+    DeprecatedUsage, QueryAnalysis, ValidationMode, ValidationResult, VisitorContext,
+};
+pub use validators::{CustomValidator, InputFieldError, InputFieldErrors, InputObjectValidator};
 
 /// An alias of [async_graphql::Error](struct.Error.html). Present for backward
 /// compatibility reasons.
@@ -260,6 +275,8 @@ pub use async_graphql_derive::Description;
 pub use async_graphql_derive::Directive;
 #[doc = include_str!("docs/enum.md")]
 pub use async_graphql_derive::Enum;
+#[doc = include_str!("docs/include_sdl.md")]
+pub use async_graphql_derive::include_sdl;
 #[doc = include_str!("docs/input_object.md")]
 pub use async_graphql_derive::InputObject;
 #[doc = include_str!("docs/interface.md")]
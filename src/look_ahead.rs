@@ -6,9 +6,13 @@ use crate::{
 };
 
 /// A selection performed by a query.
+///
+/// Selections skipped with the `@skip` and `@include` directives are not part
+/// of the lookahead.
 pub struct Lookahead<'a> {
     fragments: &'a HashMap<Name, Positioned<FragmentDefinition>>,
     fields: Vec<&'a Field>,
+    type_name: Option<String>,
     context: &'a Context<'a>,
 }
 
@@ -21,6 +25,7 @@ impl<'a> Lookahead<'a> {
         Self {
             fragments,
             fields: vec![field],
+            type_name: None,
             context,
         }
     }
@@ -34,16 +39,71 @@ impl<'a> Lookahead<'a> {
     pub fn field(&self, name: &str) -> Self {
         let mut fields = Vec::new();
         for field in &self.fields {
-            filter(&mut fields, self.fragments, &field.selection_set.node, name)
+            filter(
+                &mut fields,
+                self.fragments,
+                &field.selection_set.node,
+                Some(name),
+                &|type_condition| self.type_applies(self.type_name.as_deref(), type_condition),
+            )
         }
 
         Self {
             fragments: self.fragments,
             fields,
+            type_name: None,
+            context: self.context,
+        }
+    }
+
+    /// Only consider the fragments that apply to the concrete type
+    /// `type_name`, for a field returning an interface or a union.
+    ///
+    /// For example, calling `.on_type("Dog").field("bark")` on
+    /// `{ ... on Dog { bark } ... on Cat { meow } }` will return a lookahead
+    /// that exists, and `.on_type("Cat").field("bark")` one that does not.
+    #[must_use]
+    pub fn on_type(&self, type_name: &str) -> Self {
+        Self {
+            fragments: self.fragments,
+            fields: self.fields.clone(),
+            type_name: Some(type_name.to_string()),
             context: self.context,
         }
     }
 
+    /// Returns the possible types of the abstract type `type_name` that the
+    /// selection set applies to, that is for which at least one field is
+    /// selected.
+    ///
+    /// If `type_name` is an object, it is returned if any field is selected.
+    pub fn possible_types(&self, type_name: &str) -> Vec<&'a str> {
+        let Some(ty) = self.context.schema_env.registry.types.get(type_name) else {
+            return Vec::new();
+        };
+        let candidates = match ty.possible_types() {
+            Some(possible_types) => possible_types.iter().map(String::as_str).collect(),
+            None => vec![ty.name()],
+        };
+
+        candidates
+            .into_iter()
+            .filter(|candidate| {
+                let mut fields = Vec::new();
+                for field in &self.fields {
+                    filter(
+                        &mut fields,
+                        self.fragments,
+                        &field.selection_set.node,
+                        None,
+                        &|type_condition| self.type_applies(Some(candidate), type_condition),
+                    );
+                }
+                !fields.is_empty()
+            })
+            .collect()
+    }
+
     /// Returns true if field exists otherwise return false.
     #[inline]
     pub fn exists(&self) -> bool {
@@ -65,6 +125,22 @@ impl<'a> Lookahead<'a> {
             })
             .collect()
     }
+
+    /// Returns true if a fragment with the type condition `type_condition`
+    /// applies to the concrete type `type_name`.
+    fn type_applies(&self, type_name: Option<&str>, type_condition: &str) -> bool {
+        let Some(type_name) = type_name else {
+            return true;
+        };
+        type_condition == type_name
+            || self
+                .context
+                .schema_env
+                .registry
+                .types
+                .get(type_condition)
+                .is_some_and(|ty| ty.is_possible_type(type_name))
+    }
 }
 
 impl<'a> From<SelectionField<'a>> for Lookahead<'a> {
@@ -72,6 +148,7 @@ impl<'a> From<SelectionField<'a>> for Lookahead<'a> {
         Lookahead {
             fragments: selection_field.fragments,
             fields: vec![selection_field.field],
+            type_name: None,
             context: selection_field.context,
         }
     }
@@ -95,31 +172,56 @@ impl<'a> TryFrom<&[SelectionField<'a>]> for Lookahead<'a> {
                     .iter()
                     .map(|selection_field| selection_field.field)
                     .collect(),
+                type_name: None,
                 context: selection_fields[0].context,
             })
         }
     }
 }
 
+/// Collects the fields named `name`, or all the fields if `name` is `None`,
+/// from the fragments whose type condition satisfies `type_applies`.
 fn filter<'a>(
     fields: &mut Vec<&'a Field>,
     fragments: &'a HashMap<Name, Positioned<FragmentDefinition>>,
     selection_set: &'a SelectionSet,
-    name: &str,
+    name: Option<&str>,
+    type_applies: &dyn Fn(&str) -> bool,
 ) {
     for item in &selection_set.items {
         match &item.node {
             Selection::Field(field) => {
-                if field.node.name.node == name {
+                if name.is_none_or(|name| field.node.name.node == name) {
                     fields.push(&field.node)
                 }
             }
             Selection::InlineFragment(fragment) => {
-                filter(fields, fragments, &fragment.node.selection_set.node, name)
+                if fragment
+                    .node
+                    .type_condition
+                    .as_ref()
+                    .is_none_or(|type_condition| type_applies(&type_condition.node.on.node))
+                {
+                    filter(
+                        fields,
+                        fragments,
+                        &fragment.node.selection_set.node,
+                        name,
+                        type_applies,
+                    )
+                }
             }
             Selection::FragmentSpread(spread) => {
-                if let Some(fragment) = fragments.get(&spread.node.fragment_name.node) {
-                    filter(fields, fragments, &fragment.node.selection_set.node, name)
+                if let Some(fragment) = fragments.get(&spread.node.fragment_name.node)
+                    && type_applies(&fragment.node.type_condition.node.on.node)
+                {
+                    filter(
+                        fields,
+                        fragments,
+                        &fragment.node.selection_set.node,
+                        name,
+                        type_applies,
+                    )
                 }
             }
         }
@@ -365,4 +467,110 @@ mod tests {
                 .is_ok()
         );
     }
+
+    #[tokio::test]
+    async fn test_look_ahead_on_type() {
+        #[derive(SimpleObject)]
+        #[graphql(internal)]
+        struct Dog {
+            name: String,
+            bark: bool,
+        }
+
+        #[derive(SimpleObject)]
+        #[graphql(internal)]
+        struct Cat {
+            name: String,
+            meow: bool,
+        }
+
+        #[derive(SimpleObject)]
+        #[graphql(internal)]
+        struct Fish {
+            name: String,
+        }
+
+        #[derive(Interface)]
+        #[graphql(internal, field(name = "name", ty = "&String"))]
+        enum Pet {
+            Dog(Dog),
+            Cat(Cat),
+            Fish(Fish),
+        }
+
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn pets(&self, ctx: &Context<'_>, expected: Vec<String>) -> Vec<Pet> {
+                let look_ahead = ctx.look_ahead();
+                let mut selected = Vec::new();
+                for ty in look_ahead.possible_types("Pet") {
+                    let fields = ["name", "bark", "meow"]
+                        .into_iter()
+                        .filter(|name| look_ahead.on_type(ty).field(name).exists())
+                        .collect::<Vec<_>>();
+                    selected.push(format!("{}: {}", ty, fields.join(",")));
+                }
+                assert_eq!(selected, expected);
+                Vec::new()
+            }
+        }
+
+        let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+            .register_output_type::<Pet>()
+            .finish();
+
+        assert!(
+            schema
+                .execute(
+                    r#"{
+            pets(expected: ["Dog: bark", "Cat: meow"]) {
+                ... on Dog { bark }
+                ... CatFields
+            }
+        }
+
+        fragment CatFields on Cat {
+            meow
+        }"#
+                )
+                .await
+                .is_ok()
+        );
+
+        assert!(
+            schema
+                .execute(
+                    r#"{
+            pets(expected: ["Dog: name,bark", "Cat: name", "Fish: name"]) {
+                name
+                ... on Dog { bark }
+            }
+        }"#
+                )
+                .await
+                .is_ok()
+        );
+
+        assert!(
+            schema
+                .execute(
+                    Request::new(
+                        r#"query($withDog: Boolean = true, $withCat: Boolean!) {
+            pets(expected: ["Dog: bark"]) {
+                ... on Dog @include(if: $withDog) { bark }
+                ... on Cat @include(if: $withCat) { meow }
+                ... on Fish @skip(if: true) { name }
+            }
+        }"#
+                    )
+                    .variables(Variables::from_json(
+                        serde_json::json!({ "withCat": false })
+                    ))
+                )
+                .await
+                .is_ok()
+        );
+    }
 }
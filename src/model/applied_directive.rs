@@ -0,0 +1,43 @@
+use crate::{Object, Value, registry};
+
+pub struct __AppliedDirective {
+    pub directive: registry::MetaDirectiveInvocation,
+}
+
+/// A directive applied to an element of the schema, with the values of its
+/// arguments.
+#[Object(internal, name = "__AppliedDirective")]
+impl __AppliedDirective {
+    #[inline]
+    async fn name(&self) -> &str {
+        &self.directive.name
+    }
+
+    async fn args(&self) -> Vec<__DirectiveArgument<'_>> {
+        self.directive
+            .args
+            .iter()
+            .map(|(name, value)| __DirectiveArgument { name, value })
+            .collect()
+    }
+}
+
+pub struct __DirectiveArgument<'a> {
+    pub name: &'a str,
+    pub value: &'a Value,
+}
+
+/// An argument of an applied directive. The value is formatted as a GraphQL
+/// literal.
+#[Object(internal, name = "__DirectiveArgument")]
+impl __DirectiveArgument<'_> {
+    #[inline]
+    async fn name(&self) -> &str {
+        self.name
+    }
+
+    #[inline]
+    async fn value(&self) -> String {
+        self.value.to_string()
+    }
+}
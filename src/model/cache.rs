@@ -0,0 +1,125 @@
+use std::{fmt::Write, future::Future, num::NonZeroUsize, sync::Mutex};
+
+use lru::LruCache;
+
+use crate::{
+    Context, Name, Positioned, ServerResult, Value,
+    parser::types::{Directive, Selection, SelectionSet},
+};
+
+type CacheKey = (Vec<bool>, String);
+
+/// Cached results of the `__schema` field.
+///
+/// A result is identified by the visibility of the types and fields of the
+/// registry, and by the selection set of the field. The least recently used
+/// results are evicted.
+pub(crate) struct IntrospectionCache {
+    results: Mutex<LruCache<CacheKey, Value>>,
+}
+
+impl IntrospectionCache {
+    /// Returns `None` if `capacity` is zero.
+    pub(crate) fn new(capacity: usize) -> Option<Self> {
+        Some(Self {
+            results: Mutex::new(LruCache::new(NonZeroUsize::new(capacity)?)),
+        })
+    }
+
+    /// Returns the cached result of the `__schema` field of `ctx`, or resolves
+    /// it with `resolve` and caches it.
+    pub(crate) async fn resolve(
+        &self,
+        ctx: &Context<'_>,
+        resolve: impl Future<Output = ServerResult<Value>>,
+    ) -> ServerResult<Value> {
+        let mut selection_set = String::new();
+        write_selection_set(ctx, &mut selection_set, &ctx.item.node.selection_set.node)?;
+        let key = (
+            ctx.schema_env.registry.visibility_fingerprint(ctx),
+            selection_set,
+        );
+        if let Some(value) = self.results.lock().unwrap().get(&key) {
+            return Ok(value.clone());
+        }
+
+        let errors = ctx.query_env.errors.lock().unwrap().len();
+        let value = resolve.await?;
+        // Results with field errors are not cached.
+        if ctx.query_env.errors.lock().unwrap().len() == errors {
+            self.results.lock().unwrap().put(key, value.clone());
+        }
+        Ok(value)
+    }
+}
+
+fn write_arguments(
+    ctx: &Context<'_>,
+    key: &mut String,
+    arguments: &[(Positioned<Name>, Positioned<async_graphql_value::Value>)],
+) -> ServerResult<()> {
+    key.push('(');
+    for (name, value) in arguments {
+        match ctx.resolve_input_value(value.clone())? {
+            Some(value) => write!(key, "{}:{},", name.node, value).unwrap(),
+            None => write!(key, "{}:,", name.node).unwrap(),
+        }
+    }
+    key.push(')');
+    Ok(())
+}
+
+fn write_directives(
+    ctx: &Context<'_>,
+    key: &mut String,
+    directives: &[Positioned<Directive>],
+) -> ServerResult<()> {
+    for directive in directives {
+        write!(key, "@{}", directive.node.name.node).unwrap();
+        write_arguments(ctx, key, &directive.node.arguments)?;
+    }
+    Ok(())
+}
+
+/// Writes the selection set with its fragments inlined and its variables
+/// replaced by their values.
+fn write_selection_set(
+    ctx: &Context<'_>,
+    key: &mut String,
+    selection_set: &SelectionSet,
+) -> ServerResult<()> {
+    key.push('{');
+    for selection in &selection_set.items {
+        match &selection.node {
+            Selection::Field(field) => {
+                let field = &field.node;
+                write!(key, "{}:{}", field.response_key().node, field.name.node).unwrap();
+                write_arguments(ctx, key, &field.arguments)?;
+                write_directives(ctx, key, &field.directives)?;
+                write_selection_set(ctx, key, &field.selection_set.node)?;
+            }
+            Selection::FragmentSpread(fragment_spread) => {
+                if let Some(fragment) = ctx
+                    .query_env
+                    .fragments
+                    .get(&fragment_spread.node.fragment_name.node)
+                {
+                    write!(key, "...on {}", fragment.node.type_condition.node.on.node).unwrap();
+                    write_directives(ctx, key, &fragment_spread.node.directives)?;
+                    write_selection_set(ctx, key, &fragment.node.selection_set.node)?;
+                }
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                key.push_str("...");
+                if let Some(type_condition) = &inline_fragment.node.type_condition {
+                    write!(key, "on {}", type_condition.node.on.node).unwrap();
+                }
+                write_directives(ctx, key, &inline_fragment.node.directives)?;
+                write_selection_set(ctx, key, &inline_fragment.node.selection_set.node)?;
+            }
+        }
+        key.push(' ');
+    }
+    key.push('}');
+    Ok(())
+}
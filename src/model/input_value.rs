@@ -1,6 +1,10 @@
 use std::collections::HashSet;
 
-use crate::{Object, model::__Type, registry};
+use crate::{
+    Object,
+    model::{__AppliedDirective, __Type},
+    registry,
+};
 
 pub struct __InputValue<'a> {
     pub registry: &'a registry::Registry,
@@ -43,4 +47,26 @@ impl<'a> __InputValue<'a> {
     async fn deprecation_reason(&self) -> Option<&str> {
         self.input_value.deprecation.reason()
     }
+
+    /// The directives applied to the input value. The `@constraint` and
+    /// `@validation` directives are included when the constraint directives
+    /// are enabled.
+    async fn applied_directives(&self) -> Vec<__AppliedDirective> {
+        let mut directives = self
+            .input_value
+            .directive_invocations
+            .iter()
+            .cloned()
+            .map(|directive| __AppliedDirective { directive })
+            .collect::<Vec<_>>();
+        if self.registry.constraint_directives {
+            directives.extend(
+                self.input_value
+                    .constraint_directives()
+                    .into_iter()
+                    .map(|directive| __AppliedDirective { directive }),
+            );
+        }
+        directives
+    }
 }
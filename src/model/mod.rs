@@ -1,3 +1,6 @@
+mod applied_directive;
+#[cfg(feature = "introspection_cache")]
+mod cache;
 mod directive;
 mod enum_value;
 mod field;
@@ -6,10 +9,14 @@ mod kind;
 mod schema;
 mod r#type;
 
+pub use applied_directive::__AppliedDirective;
+#[cfg(feature = "introspection_cache")]
+pub(crate) use cache::IntrospectionCache;
 pub use directive::{__Directive, __DirectiveLocation, location_traits};
 pub use enum_value::__EnumValue;
 pub use field::__Field;
 pub use input_value::__InputValue;
 pub use kind::__TypeKind;
 pub use schema::__Schema;
+pub(crate) use schema::resolve_schema;
 pub use r#type::__Type;
@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use crate::{
-    Object,
+    Context, Object, OutputType, ServerResult, Value,
     model::{__Directive, __Type},
     registry,
 };
@@ -20,6 +20,27 @@ impl<'a> __Schema<'a> {
     }
 }
 
+/// Resolves the `__schema` field, using the introspection cache of the schema
+/// if it is enabled.
+pub(crate) async fn resolve_schema(ctx: &Context<'_>) -> ServerResult<Value> {
+    let resolve = async {
+        let mut ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+        ctx_obj.is_for_introspection = true;
+        let visible_types = ctx.schema_env.registry.find_visible_types(ctx);
+        OutputType::resolve(
+            &__Schema::new(&ctx.schema_env.registry, &visible_types),
+            &ctx_obj,
+            ctx.item,
+        )
+        .await
+    };
+    #[cfg(feature = "introspection_cache")]
+    if let Some(cache) = &ctx.schema_env.introspection_cache {
+        return cache.resolve(ctx, resolve).await;
+    }
+    resolve.await
+}
+
 /// A GraphQL Schema defines the capabilities of a GraphQL server. It exposes
 /// all available types and directives on the server, as well as the entry
 /// points for query, mutation, and subscription operations.
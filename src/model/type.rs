@@ -4,7 +4,7 @@ use crate::{
     Context, Object,
     model::{__EnumValue, __Field, __InputValue, __TypeKind},
     registry,
-    registry::is_visible,
+    registry::{is_field_visible, is_visible},
 };
 
 enum TypeDetail<'a> {
@@ -123,7 +123,7 @@ impl<'a> __Type<'a> {
             ty.fields().map(|fields| {
                 fields
                     .values()
-                    .filter(|field| is_visible(ctx, &field.visible))
+                    .filter(|field| is_field_visible(ctx, self.registry, field))
                     .filter(|field| {
                         (include_deprecated || !field.deprecation.is_deprecated())
                             && !field.name.starts_with("__")
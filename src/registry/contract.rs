@@ -0,0 +1,270 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::registry::{MetaInputValue, MetaType, MetaTypeName, Registry, is_system_type};
+
+/// A contract filters a schema by tags, so that one codebase can serve
+/// several variants of an API.
+///
+/// - Types, fields, arguments, input fields and enum values tagged with an
+///   excluded tag are removed.
+/// - If include tags are set, only the fields of objects and interfaces tagged
+///   with one of them, or belonging to a type tagged with one of them, are
+///   kept.
+/// - Fields and arguments that refer to removed types are removed, as well as
+///   the types that are no longer reachable from the root types.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn value(&self) -> i32 {
+///         10
+///     }
+///
+///     #[graphql(tag = "internal")]
+///     async fn secret(&self) -> i32 {
+///         20
+///     }
+/// }
+///
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .contract(Contract::new().exclude_tag("internal"))
+///     .finish();
+/// assert!(!schema.sdl().contains("secret"));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Contract {
+    include_tags: HashSet<String>,
+    exclude_tags: HashSet<String>,
+}
+
+impl Contract {
+    /// Create a `Contract` that keeps the whole schema.
+    #[inline]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Only keep the fields tagged with `tag`, or belonging to a type tagged
+    /// with `tag`.
+    #[inline]
+    #[must_use]
+    pub fn include_tag(mut self, tag: impl Into<String>) -> Self {
+        self.include_tags.insert(tag.into());
+        self
+    }
+
+    /// Remove the elements tagged with `tag`.
+    #[inline]
+    #[must_use]
+    pub fn exclude_tag(mut self, tag: impl Into<String>) -> Self {
+        self.exclude_tags.insert(tag.into());
+        self
+    }
+
+    fn is_excluded(&self, tags: &[String]) -> bool {
+        tags.iter().any(|tag| self.exclude_tags.contains(tag))
+    }
+
+    fn is_included(&self, tags: &[String]) -> bool {
+        self.include_tags.is_empty() || tags.iter().any(|tag| self.include_tags.contains(tag))
+    }
+}
+
+fn type_tags(ty: &MetaType) -> &[String] {
+    match ty {
+        MetaType::Scalar { tags, .. }
+        | MetaType::Object { tags, .. }
+        | MetaType::Interface { tags, .. }
+        | MetaType::Union { tags, .. }
+        | MetaType::Enum { tags, .. }
+        | MetaType::InputObject { tags, .. } => tags,
+    }
+}
+
+fn is_required(input_value: &MetaInputValue) -> bool {
+    MetaTypeName::create(&input_value.ty).is_non_null() && input_value.default_value.is_none()
+}
+
+fn is_system_field(name: &str) -> bool {
+    name.starts_with("__") || name == "_entities" || name == "_service"
+}
+
+impl Registry {
+    /// Apply a contract to this registry.
+    ///
+    /// The removed fields are also rejected at execution, see [`Contract`].
+    pub fn apply_contract(&mut self, contract: Contract) {
+        let root_types = Some(&self.query_type)
+            .into_iter()
+            .chain(self.mutation_type.iter())
+            .chain(self.subscription_type.iter())
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        self.types.retain(|name, ty| {
+            is_system_type(name)
+                || root_types.contains(name)
+                || !contract.is_excluded(type_tags(ty))
+        });
+
+        let mut removed_types = Vec::new();
+        for (name, ty) in &mut self.types {
+            if is_system_type(name) {
+                continue;
+            }
+
+            let type_included = contract.is_included(type_tags(ty));
+            match ty {
+                MetaType::Object { fields, .. } | MetaType::Interface { fields, .. } => {
+                    fields.retain(|name, field| {
+                        is_system_field(name)
+                            || (!contract.is_excluded(&field.tags)
+                                && (type_included || contract.is_included(&field.tags))
+                                && !field
+                                    .args
+                                    .values()
+                                    .any(|arg| is_required(arg) && contract.is_excluded(&arg.tags)))
+                    });
+                    for field in fields.values_mut() {
+                        field.args.retain(|_, arg| !contract.is_excluded(&arg.tags));
+                    }
+                }
+                MetaType::InputObject { input_fields, .. } => {
+                    if input_fields
+                        .values()
+                        .any(|field| is_required(field) && contract.is_excluded(&field.tags))
+                    {
+                        removed_types.push(name.clone());
+                    } else {
+                        input_fields.retain(|_, field| !contract.is_excluded(&field.tags));
+                    }
+                }
+                MetaType::Enum { enum_values, .. } => {
+                    enum_values.retain(|_, value| !contract.is_excluded(&value.tags));
+                }
+                MetaType::Scalar { .. } | MetaType::Union { .. } => {}
+            }
+        }
+        for name in removed_types {
+            self.types.remove(&name);
+        }
+
+        self.remove_dangling_references(&root_types);
+        self.remove_unused_types();
+        self.contract = Some(contract);
+    }
+
+    /// Removes the fields and arguments referring to removed types, and the
+    /// types left empty, until the registry is consistent.
+    fn remove_dangling_references(&mut self, root_types: &HashSet<String>) {
+        loop {
+            let type_names = self.types.keys().cloned().collect::<HashSet<_>>();
+            let exists = |ty: &str| type_names.contains(MetaTypeName::concrete_typename(ty));
+            let mut changed = false;
+            let mut removed_types = Vec::new();
+
+            for (name, ty) in &mut self.types {
+                let is_empty = match ty {
+                    MetaType::Object { fields, .. } | MetaType::Interface { fields, .. } => {
+                        let len = fields.len();
+                        fields.retain(|_, field| {
+                            exists(&field.ty)
+                                && field
+                                    .args
+                                    .values()
+                                    .all(|arg| exists(&arg.ty) || !is_required(arg))
+                        });
+                        for field in fields.values_mut() {
+                            field.args.retain(|_, arg| exists(&arg.ty));
+                        }
+                        changed |= fields.len() != len;
+                        fields.keys().all(|name| is_system_field(name))
+                    }
+                    MetaType::InputObject { input_fields, .. } => {
+                        if input_fields
+                            .values()
+                            .any(|field| !exists(&field.ty) && is_required(field))
+                        {
+                            true
+                        } else {
+                            input_fields.retain(|_, field| exists(&field.ty));
+                            input_fields.is_empty()
+                        }
+                    }
+                    MetaType::Enum { enum_values, .. } => enum_values.is_empty(),
+                    MetaType::Union { possible_types, .. } => {
+                        possible_types.retain(|ty| type_names.contains(ty));
+                        possible_types.is_empty()
+                    }
+                    MetaType::Scalar { .. } => false,
+                };
+                if let MetaType::Interface { possible_types, .. } = ty {
+                    possible_types.retain(|ty| type_names.contains(ty));
+                }
+                if is_empty && !is_system_type(name) && *name != self.query_type {
+                    removed_types.push(name.clone());
+                }
+            }
+
+            // An interface field must be implemented by all the possible types.
+            let mut missing_fields = HashMap::new();
+            for ty in self.types.values() {
+                if let MetaType::Interface {
+                    name,
+                    fields,
+                    possible_types,
+                    ..
+                } = ty
+                {
+                    for field_name in fields.keys() {
+                        if possible_types.iter().any(|possible_type| {
+                            self.types
+                                .get(possible_type)
+                                .and_then(|ty| ty.field_by_name(field_name))
+                                .is_none()
+                        }) {
+                            missing_fields
+                                .entry(name.clone())
+                                .or_insert_with(Vec::new)
+                                .push(field_name.clone());
+                        }
+                    }
+                }
+            }
+            for (name, field_names) in missing_fields {
+                if let Some(MetaType::Interface { fields, .. }) = self.types.get_mut(&name) {
+                    for field_name in field_names {
+                        fields.shift_remove(&field_name);
+                        changed = true;
+                    }
+                }
+            }
+
+            if removed_types.is_empty() && !changed {
+                break;
+            }
+            for name in removed_types {
+                self.types.remove(&name);
+                if root_types.contains(&name) {
+                    if self.mutation_type.as_deref() == Some(&name) {
+                        self.mutation_type = None;
+                    }
+                    if self.subscription_type.as_deref() == Some(&name) {
+                        self.subscription_type = None;
+                    }
+                }
+            }
+        }
+
+        self.implements.retain(|name, interfaces| {
+            interfaces.retain(|interface| self.types.contains_key(interface));
+            self.types.contains_key(name) && !interfaces.is_empty()
+        });
+    }
+}
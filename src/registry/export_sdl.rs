@@ -17,6 +17,7 @@ pub struct SDLExportOptions {
     compose_directive: bool,
     use_space_ident: bool,
     indent_width: u8,
+    constraint_directives: bool,
 }
 
 impl Default for SDLExportOptions {
@@ -31,6 +32,7 @@ impl Default for SDLExportOptions {
             compose_directive: false,
             use_space_ident: false,
             indent_width: 2,
+            constraint_directives: false,
         }
     }
 }
@@ -124,11 +126,25 @@ impl SDLExportOptions {
             ..self
         }
     }
+
+    /// Export the constraints of the built-in validators as `@constraint` and
+    /// `@validation` directives on the arguments and input fields
+    pub fn constraint_directives(self) -> Self {
+        Self {
+            constraint_directives: true,
+            ..self
+        }
+    }
 }
 
 impl Registry {
-    pub(crate) fn export_sdl(&self, options: SDLExportOptions) -> String {
+    /// Export the registry as SDL(Schema Definition Language)
+    pub fn export_sdl(&self, options: SDLExportOptions) -> String {
         let mut sdl = String::new();
+        let options = SDLExportOptions {
+            constraint_directives: options.constraint_directives || self.constraint_directives,
+            ..options
+        };
 
         for ty in self.types.values() {
             if ty.name().starts_with("__") {
@@ -186,9 +202,27 @@ impl Registry {
                 return;
             }
 
+            // Filter out constraint directives from SDL if they are not used
+            if (directive.name == "constraint" || directive.name == "validation")
+                && self.constraint_directives
+                && !self.has_constraints(&directive.name)
+            {
+                return;
+            }
+
             writeln!(sdl, "{}", directive.sdl(&options)).ok();
         });
 
+        if options.constraint_directives {
+            for directive in [Self::constraint_directive(), Self::validation_directive()] {
+                if !self.directives.contains_key(&directive.name)
+                    && self.has_constraints(&directive.name)
+                {
+                    writeln!(sdl, "{}", directive.sdl(&options)).ok();
+                }
+            }
+        }
+
         if options.federation {
             writeln!(sdl, "extend schema @link(").ok();
             writeln!(
@@ -197,7 +231,7 @@ impl Registry {
                 tab(&options)
             )
             .ok();
-            writeln!(sdl, "{}import: [\"@key\", \"@tag\", \"@shareable\", \"@inaccessible\", \"@override\", \"@external\", \"@provides\", \"@requires\", \"@composeDirective\", \"@interfaceObject\", \"@requiresScopes\"]", tab(&options)).ok();
+            writeln!(sdl, "{}import: [\"@key\", \"@tag\", \"@shareable\", \"@inaccessible\", \"@override\", \"@external\", \"@provides\", \"@requires\", \"@composeDirective\", \"@interfaceObject\", \"@requiresScopes\", \"@authenticated\"]", tab(&options)).ok();
             writeln!(sdl, ")").ok();
 
             if options.compose_directive {
@@ -302,6 +336,8 @@ impl Registry {
                     for directive in &arg.directive_invocations {
                         write!(sdl, " {}", directive.sdl()).ok();
                     }
+
+                    write_constraints(sdl, options, arg);
                 }
 
                 if need_multiline {
@@ -341,6 +377,9 @@ impl Registry {
                     write!(sdl, " @override(from: \"{}\")", from).ok();
                 }
 
+                if field.authenticated {
+                    write!(sdl, " @authenticated").ok();
+                }
                 if !&field.requires_scopes.is_empty() {
                     write_requires_scopes(sdl, &field.requires_scopes);
                 }
@@ -360,6 +399,7 @@ impl Registry {
                 specified_by_url,
                 directive_invocations,
                 requires_scopes,
+                authenticated,
                 ..
             } => {
                 let mut export_scalar = !SYSTEM_SCALARS.contains(&name.as_str());
@@ -390,6 +430,9 @@ impl Registry {
                         for tag in tags {
                             write!(sdl, " @tag(name: \"{}\")", tag.replace('"', "\\\"")).ok();
                         }
+                        if *authenticated {
+                            write!(sdl, " @authenticated").ok();
+                        }
                         if !requires_scopes.is_empty() {
                             write_requires_scopes(sdl, requires_scopes);
                         }
@@ -415,6 +458,7 @@ impl Registry {
                 tags,
                 directive_invocations: raw_directives,
                 requires_scopes,
+                authenticated,
                 ..
             } => {
                 if Some(name.as_str()) == self.subscription_type.as_deref()
@@ -482,6 +526,10 @@ impl Registry {
                         write!(sdl, " @tag(name: \"{}\")", tag.replace('"', "\\\"")).ok();
                     }
 
+                    if *authenticated {
+                        write!(sdl, " @authenticated").ok();
+                    }
+
                     if !requires_scopes.is_empty() {
                         write_requires_scopes(sdl, requires_scopes);
                     }
@@ -501,6 +549,7 @@ impl Registry {
                 tags,
                 directive_invocations,
                 requires_scopes,
+                authenticated,
                 ..
             } => {
                 if let Some(description) = description {
@@ -526,6 +575,10 @@ impl Registry {
                         write!(sdl, " @tag(name: \"{}\")", tag.replace('"', "\\\"")).ok();
                     }
 
+                    if *authenticated {
+                        write!(sdl, " @authenticated").ok();
+                    }
+
                     if !requires_scopes.is_empty() {
                         write_requires_scopes(sdl, requires_scopes);
                     }
@@ -549,6 +602,7 @@ impl Registry {
                 tags,
                 directive_invocations,
                 requires_scopes,
+                authenticated,
                 ..
             } => {
                 if let Some(description) = description {
@@ -564,6 +618,10 @@ impl Registry {
                         write!(sdl, " @tag(name: \"{}\")", tag.replace('"', "\\\"")).ok();
                     }
 
+                    if *authenticated {
+                        write!(sdl, " @authenticated").ok();
+                    }
+
                     if !requires_scopes.is_empty() {
                         write_requires_scopes(sdl, requires_scopes);
                     }
@@ -662,6 +720,7 @@ impl Registry {
                     for directive in &field.directive_invocations {
                         write!(sdl, " {}", directive.sdl()).ok();
                     }
+                    write_constraints(sdl, options, field);
                     writeln!(sdl).ok();
                 }
 
@@ -757,6 +816,14 @@ fn write_input_value(sdl: &mut String, input_value: &MetaInputValue) {
     write_deprecated(sdl, &input_value.deprecation);
 }
 
+fn write_constraints(sdl: &mut String, options: &SDLExportOptions, input_value: &MetaInputValue) {
+    if options.constraint_directives {
+        for directive in input_value.constraint_directives() {
+            write!(sdl, " {}", directive.sdl()).ok();
+        }
+    }
+}
+
 fn write_deprecated(sdl: &mut String, deprecation: &Deprecation) {
     if let Deprecation::Deprecated { reason } = deprecation {
         let _ = match reason {
@@ -838,7 +905,7 @@ mod tests {
         let expected = r#"directive @custom_type_directive on FIELD_DEFINITION
 extend schema @link(
 	url: "https://specs.apollo.dev/federation/v2.5",
-	import: ["@key", "@tag", "@shareable", "@inaccessible", "@override", "@external", "@provides", "@requires", "@composeDirective", "@interfaceObject", "@requiresScopes"]
+	import: ["@key", "@tag", "@shareable", "@inaccessible", "@override", "@external", "@provides", "@requires", "@composeDirective", "@interfaceObject", "@requiresScopes", "@authenticated"]
 )
 
 extend schema @link(
@@ -891,6 +958,7 @@ schema {
                         tags: vec![],
                         is_secret: false,
                         directive_invocations: vec![],
+                        constraints: Default::default(),
                     },
                 ),
                 (
@@ -906,6 +974,7 @@ schema {
                         tags: vec![],
                         is_secret: false,
                         directive_invocations: vec![],
+                        constraints: Default::default(),
                     },
                 ),
             ]
@@ -0,0 +1,331 @@
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use crate::{
+    Error, InputType, Name, Result, Value,
+    registry::{
+        __DirectiveLocation, Deprecation, EnumBuilder, InputObjectBuilder, MetaDirective,
+        MetaEnumValue, MetaField, MetaInputValue, MetaType, ObjectBuilder, Registry,
+    },
+};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionSchema {
+    query_type: NamedTypeRef,
+    mutation_type: Option<NamedTypeRef>,
+    subscription_type: Option<NamedTypeRef>,
+    types: Vec<IntrospectionType>,
+    #[serde(default)]
+    directives: Vec<IntrospectionDirective>,
+}
+
+#[derive(Deserialize)]
+struct NamedTypeRef {
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum TypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+    List,
+    NonNull,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionType {
+    kind: TypeKind,
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    fields: Option<Vec<IntrospectionField>>,
+    #[serde(default)]
+    interfaces: Option<Vec<NamedTypeRef>>,
+    #[serde(default)]
+    possible_types: Option<Vec<NamedTypeRef>>,
+    #[serde(default)]
+    enum_values: Option<Vec<IntrospectionEnumValue>>,
+    #[serde(default)]
+    input_fields: Option<Vec<IntrospectionInputValue>>,
+    #[serde(default, rename = "specifiedByURL", alias = "specifiedByUrl")]
+    specified_by_url: Option<String>,
+    #[serde(default)]
+    is_one_of: Option<bool>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionTypeRef {
+    kind: TypeKind,
+    name: Option<String>,
+    of_type: Option<Box<IntrospectionTypeRef>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionField {
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    args: Vec<IntrospectionInputValue>,
+    #[serde(rename = "type")]
+    ty: IntrospectionTypeRef,
+    #[serde(default)]
+    is_deprecated: bool,
+    deprecation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionInputValue {
+    name: String,
+    description: Option<String>,
+    #[serde(rename = "type")]
+    ty: IntrospectionTypeRef,
+    default_value: Option<String>,
+    #[serde(default)]
+    is_deprecated: bool,
+    deprecation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionEnumValue {
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    is_deprecated: bool,
+    deprecation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionDirective {
+    name: String,
+    description: Option<String>,
+    locations: Vec<String>,
+    #[serde(default)]
+    args: Vec<IntrospectionInputValue>,
+    #[serde(default)]
+    is_repeatable: bool,
+}
+
+impl IntrospectionTypeRef {
+    fn type_name(&self) -> Result<String> {
+        match (&self.kind, &self.of_type) {
+            (TypeKind::NonNull, Some(of_type)) => Ok(format!("{}!", of_type.type_name()?)),
+            (TypeKind::List, Some(of_type)) => Ok(format!("[{}]", of_type.type_name()?)),
+            (TypeKind::NonNull | TypeKind::List, None) => Err(Error::new(
+                "Invalid introspection: wrapping type without `ofType`.",
+            )),
+            (_, _) => self
+                .name
+                .clone()
+                .ok_or_else(|| Error::new("Invalid introspection: named type without `name`.")),
+        }
+    }
+}
+
+fn deprecation(is_deprecated: bool, reason: Option<String>) -> Deprecation {
+    if is_deprecated {
+        Deprecation::Deprecated { reason }
+    } else {
+        Deprecation::NoDeprecated
+    }
+}
+
+fn convert_input_values(
+    values: Vec<IntrospectionInputValue>,
+) -> Result<IndexMap<String, MetaInputValue>> {
+    values
+        .into_iter()
+        .map(|value| {
+            let mut input_value = MetaInputValue::new(value.name.clone(), value.ty.type_name()?);
+            input_value.description = value.description;
+            input_value.default_value = value.default_value;
+            input_value.deprecation = deprecation(value.is_deprecated, value.deprecation_reason);
+            Ok((value.name, input_value))
+        })
+        .collect()
+}
+
+fn convert_fields(fields: Vec<IntrospectionField>) -> Result<IndexMap<String, MetaField>> {
+    fields
+        .into_iter()
+        .map(|field| {
+            let mut meta_field = MetaField::new(field.name.clone(), field.ty.type_name()?);
+            meta_field.description = field.description;
+            meta_field.args = convert_input_values(field.args)?;
+            meta_field.deprecation = deprecation(field.is_deprecated, field.deprecation_reason);
+            Ok((field.name, meta_field))
+        })
+        .collect()
+}
+
+fn convert_type(ty: IntrospectionType, is_subscription: bool) -> Result<MetaType> {
+    Ok(match ty.kind {
+        TypeKind::Scalar => MetaType::Scalar {
+            name: ty.name,
+            description: ty.description,
+            is_valid: None,
+            visible: None,
+            inaccessible: false,
+            tags: Default::default(),
+            specified_by_url: ty.specified_by_url,
+            directive_invocations: Default::default(),
+            requires_scopes: Default::default(),
+            authenticated: false,
+        },
+        TypeKind::Object => {
+            ObjectBuilder::new(ty.name, convert_fields(ty.fields.unwrap_or_default())?)
+                .description(ty.description)
+                .is_subscription(is_subscription)
+                .build()
+        }
+        TypeKind::Interface => MetaType::Interface {
+            name: ty.name,
+            description: ty.description,
+            fields: convert_fields(ty.fields.unwrap_or_default())?,
+            possible_types: ty
+                .possible_types
+                .unwrap_or_default()
+                .into_iter()
+                .map(|ty| ty.name)
+                .collect(),
+            extends: false,
+            keys: None,
+            visible: None,
+            inaccessible: false,
+            tags: Default::default(),
+            rust_typename: None,
+            directive_invocations: Default::default(),
+            requires_scopes: Default::default(),
+            authenticated: false,
+        },
+        TypeKind::Union => MetaType::Union {
+            name: ty.name,
+            description: ty.description,
+            possible_types: ty
+                .possible_types
+                .unwrap_or_default()
+                .into_iter()
+                .map(|ty| ty.name)
+                .collect(),
+            visible: None,
+            inaccessible: false,
+            tags: Default::default(),
+            rust_typename: None,
+            directive_invocations: Default::default(),
+        },
+        TypeKind::Enum => EnumBuilder::new(
+            ty.name,
+            ty.enum_values
+                .unwrap_or_default()
+                .into_iter()
+                .map(|value| {
+                    let mut enum_value = MetaEnumValue::new(value.name.clone());
+                    enum_value.description = value.description;
+                    enum_value.deprecation =
+                        deprecation(value.is_deprecated, value.deprecation_reason);
+                    (value.name, enum_value)
+                })
+                .collect(),
+        )
+        .description(ty.description)
+        .build(),
+        TypeKind::InputObject => InputObjectBuilder::new(
+            ty.name,
+            convert_input_values(ty.input_fields.unwrap_or_default())?,
+        )
+        .description(ty.description)
+        .oneof(ty.is_one_of.unwrap_or_default())
+        .build(),
+        TypeKind::List | TypeKind::NonNull => {
+            return Err(Error::new(format!(
+                "Invalid introspection: `{}` must be a named type.",
+                ty.name
+            )));
+        }
+    })
+}
+
+fn convert_directive(directive: IntrospectionDirective) -> Result<MetaDirective> {
+    Ok(MetaDirective {
+        name: directive.name,
+        description: directive.description,
+        locations: directive
+            .locations
+            .into_iter()
+            .map(|location| {
+                __DirectiveLocation::parse(Some(Value::Enum(Name::new(&location)))).map_err(|_| {
+                    Error::new(format!(
+                        "Invalid introspection: unknown directive location `{}`.",
+                        location
+                    ))
+                })
+            })
+            .collect::<Result<_>>()?,
+        args: convert_input_values(directive.args)?,
+        is_repeatable: directive.is_repeatable,
+        visible: None,
+        composable: None,
+    })
+}
+
+impl Registry {
+    /// Create a registry from the result of an introspection query.
+    ///
+    /// The input can be a whole GraphQL response (`{"data": {"__schema":
+    /// ...}}`), its `data` (`{"__schema": ...}`) or the `__schema` object
+    /// itself. The introspection types (`__Schema`, `__Type`, ...) are not
+    /// imported, so the resulting registry can be printed with
+    /// [`Registry::export_sdl`] like the registry of any other schema.
+    pub fn from_introspection(introspection: serde_json::Value) -> Result<Registry> {
+        let mut introspection = introspection;
+        if let Some(data) = introspection.get_mut("data") {
+            introspection = data.take();
+        }
+        if let Some(schema) = introspection.get_mut("__schema") {
+            introspection = schema.take();
+        }
+        let schema = IntrospectionSchema::deserialize(introspection)
+            .map_err(|err| Error::new(format!("Invalid introspection: {}", err)))?;
+
+        let mut registry = Registry {
+            query_type: schema.query_type.name,
+            mutation_type: schema.mutation_type.map(|ty| ty.name),
+            subscription_type: schema.subscription_type.map(|ty| ty.name),
+            ..Default::default()
+        };
+        registry.add_system_types();
+
+        for ty in schema.types {
+            if ty.name.starts_with("__") {
+                continue;
+            }
+
+            for interface in ty.interfaces.iter().flatten() {
+                registry.add_implements(&ty.name, &interface.name);
+            }
+            let is_subscription = registry.subscription_type.as_deref() == Some(&ty.name);
+            let ty = convert_type(ty, is_subscription)?;
+            registry.types.insert(ty.name().to_string(), ty);
+        }
+
+        for directive in schema.directives {
+            let directive = convert_directive(directive)?;
+            registry
+                .directives
+                .insert(directive.name.clone(), directive);
+        }
+
+        Ok(registry)
+    }
+}
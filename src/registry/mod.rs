@@ -1,14 +1,19 @@
 mod cache_control;
+mod contract;
 mod export_sdl;
+mod from_introspection;
 mod stringify_exec_doc;
 
 use std::{
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     fmt::{self, Display, Formatter, Write},
+    num::NonZeroUsize,
     sync::Arc,
+    time::Duration,
 };
 
 pub use cache_control::CacheControl;
+pub use contract::Contract;
 pub use export_sdl::SDLExportOptions;
 use indexmap::{map::IndexMap, set::IndexSet};
 
@@ -159,8 +164,22 @@ pub struct MetaInputValue {
     pub is_secret: bool,
     /// Custom directive invocations
     pub directive_invocations: Vec<MetaDirectiveInvocation>,
+    /// The constraints of the built-in validators, which are the arguments of
+    /// the `@constraint` and `@validation` directives
+    pub constraints: IndexMap<String, Value>,
 }
 
+/// The arguments of the `@validation` directive, which describe the built-in
+/// validators that graphql-constraint-directive cannot express.
+const VALIDATION_ARGS: &[&str] = &[
+    "formats",
+    "uniqueItems",
+    "notBlank",
+    "oneOf",
+    "oneOfInt",
+    "oneOfFloat",
+];
+
 impl MetaInputValue {
     pub fn new(name: impl Into<String>, ty: impl Into<String>) -> Self {
         Self {
@@ -174,8 +193,27 @@ impl MetaInputValue {
             tags: Vec::new(),
             is_secret: false,
             directive_invocations: Vec::new(),
+            constraints: IndexMap::new(),
         }
     }
+
+    /// Returns the `@constraint` and `@validation` directives describing the
+    /// built-in validators of the input value, if it has any.
+    pub fn constraint_directives(&self) -> Vec<MetaDirectiveInvocation> {
+        let (validation, constraint): (IndexMap<_, _>, IndexMap<_, _>) = self
+            .constraints
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .partition(|(name, _)| VALIDATION_ARGS.contains(&name.as_str()));
+        [("constraint", constraint), ("validation", validation)]
+            .into_iter()
+            .filter(|(_, args)| !args.is_empty())
+            .map(|(name, args)| MetaDirectiveInvocation {
+                name: name.to_string(),
+                args,
+            })
+            .collect()
+    }
 }
 
 type ComputeComplexityFn = fn(
@@ -259,6 +297,14 @@ pub struct MetaField {
     /// the authenticated supergraph users with the appropriate JWT scopes
     /// when using Apollo Federation.
     pub requires_scopes: Vec<String>,
+    /// Indicates to composition that the target element is accessible only to
+    /// the authenticated supergraph users when using Apollo Federation.
+    pub authenticated: bool,
+    /// The maximum time the field is allowed to take to resolve.
+    pub timeout: Option<Duration>,
+    /// The maximum number of list items and fields of the value of the field
+    /// resolved concurrently.
+    pub max_concurrency: Option<NonZeroUsize>,
 }
 
 impl MetaField {
@@ -281,6 +327,9 @@ impl MetaField {
             compute_complexity: None,
             directive_invocations: Vec::new(),
             requires_scopes: Vec::new(),
+            authenticated: false,
+            timeout: None,
+            max_concurrency: None,
         }
     }
 }
@@ -335,6 +384,7 @@ impl MetaTypeId {
                 specified_by_url: None,
                 directive_invocations: vec![],
                 requires_scopes: vec![],
+                authenticated: false,
             },
             MetaTypeId::Object => MetaType::Object {
                 name: "".to_string(),
@@ -353,6 +403,7 @@ impl MetaTypeId {
                 rust_typename: Some(rust_typename),
                 directive_invocations: vec![],
                 requires_scopes: vec![],
+                authenticated: false,
             },
             MetaTypeId::Interface => MetaType::Interface {
                 name: "".to_string(),
@@ -367,6 +418,7 @@ impl MetaTypeId {
                 rust_typename: Some(rust_typename),
                 directive_invocations: vec![],
                 requires_scopes: vec![],
+                authenticated: false,
             },
             MetaTypeId::Union => MetaType::Union {
                 name: "".to_string(),
@@ -388,6 +440,7 @@ impl MetaTypeId {
                 rust_typename: Some(rust_typename),
                 directive_invocations: vec![],
                 requires_scopes: vec![],
+                authenticated: false,
             },
             MetaTypeId::InputObject => MetaType::InputObject {
                 name: "".to_string(),
@@ -456,6 +509,9 @@ pub enum MetaType {
         /// to the authenticated supergraph users with the appropriate
         /// JWT scopes when using Apollo Federation.
         requires_scopes: Vec<String>,
+        /// Indicates to composition that the target element is accessible only
+        /// to the authenticated supergraph users when using Apollo Federation.
+        authenticated: bool,
     },
     /// Object
     ///
@@ -525,6 +581,9 @@ pub enum MetaType {
         /// to the authenticated supergraph users with the appropriate
         /// JWT scopes when using Apollo Federation.
         requires_scopes: Vec<String>,
+        /// Indicates to composition that the target element is accessible only
+        /// to the authenticated supergraph users when using Apollo Federation.
+        authenticated: bool,
     },
     /// Interface
     ///
@@ -573,6 +632,9 @@ pub enum MetaType {
         /// to the authenticated supergraph users with the appropriate
         /// JWT scopes when using Apollo Federation.
         requires_scopes: Vec<String>,
+        /// Indicates to composition that the target element is accessible only
+        /// to the authenticated supergraph users when using Apollo Federation.
+        authenticated: bool,
     },
     /// Union
     ///
@@ -633,6 +695,9 @@ pub enum MetaType {
         /// to the authenticated supergraph users with the appropriate
         /// JWT scopes when using Apollo Federation.
         requires_scopes: Vec<String>,
+        /// Indicates to composition that the target element is accessible only
+        /// to the authenticated supergraph users when using Apollo Federation.
+        authenticated: bool,
     },
     /// Input object
     ///
@@ -686,6 +751,7 @@ pub struct ObjectBuilder {
     rust_typename: Option<&'static str>,
     directive_invocations: Vec<MetaDirectiveInvocation>,
     requires_scopes: Vec<String>,
+    authenticated: bool,
 }
 
 impl ObjectBuilder {
@@ -707,6 +773,7 @@ impl ObjectBuilder {
             rust_typename: None,
             directive_invocations: Vec::new(),
             requires_scopes: Vec::new(),
+            authenticated: false,
         }
     }
 
@@ -797,6 +864,12 @@ impl ObjectBuilder {
         self
     }
 
+    #[must_use]
+    pub fn authenticated(mut self, authenticated: bool) -> Self {
+        self.authenticated = authenticated;
+        self
+    }
+
     pub fn build(self) -> MetaType {
         MetaType::Object {
             name: self.name,
@@ -815,6 +888,7 @@ impl ObjectBuilder {
             rust_typename: self.rust_typename,
             directive_invocations: self.directive_invocations,
             requires_scopes: self.requires_scopes,
+            authenticated: self.authenticated,
         }
     }
 }
@@ -830,6 +904,7 @@ pub struct EnumBuilder {
     rust_typename: Option<&'static str>,
     directive_invocations: Vec<MetaDirectiveInvocation>,
     requires_scopes: Vec<String>,
+    authenticated: bool,
 }
 
 impl EnumBuilder {
@@ -844,6 +919,7 @@ impl EnumBuilder {
             rust_typename: None,
             directive_invocations: Vec::new(),
             requires_scopes: Vec::new(),
+            authenticated: false,
         }
     }
 
@@ -892,6 +968,12 @@ impl EnumBuilder {
         self
     }
 
+    #[must_use]
+    pub fn authenticated(mut self, authenticated: bool) -> Self {
+        self.authenticated = authenticated;
+        self
+    }
+
     pub fn build(self) -> MetaType {
         MetaType::Enum {
             name: self.name,
@@ -903,6 +985,7 @@ impl EnumBuilder {
             rust_typename: self.rust_typename,
             directive_invocations: self.directive_invocations,
             requires_scopes: self.requires_scopes,
+            authenticated: self.authenticated,
         }
     }
 }
@@ -1033,6 +1116,40 @@ impl MetaType {
             MetaType::InputObject { visible, .. } => visible,
         };
         is_visible(ctx, visible)
+            && ctx
+                .schema_env
+                .authorization
+                .as_ref()
+                .filter(|authorization| authorization.hides_unauthorized())
+                .is_none_or(|authorization| authorization.is_type_authorized(ctx, self))
+    }
+
+    /// Returns the `@authenticated` and `@requiresScopes` requirements of the
+    /// type.
+    pub fn access_requirements(&self) -> (bool, &[String]) {
+        match self {
+            MetaType::Scalar {
+                authenticated,
+                requires_scopes,
+                ..
+            }
+            | MetaType::Object {
+                authenticated,
+                requires_scopes,
+                ..
+            }
+            | MetaType::Interface {
+                authenticated,
+                requires_scopes,
+                ..
+            }
+            | MetaType::Enum {
+                authenticated,
+                requires_scopes,
+                ..
+            } => (*authenticated, requires_scopes),
+            MetaType::Union { .. } | MetaType::InputObject { .. } => (false, &[]),
+        }
     }
 
     #[inline]
@@ -1186,10 +1303,14 @@ pub struct Registry {
     pub mutation_type: Option<String>,
     pub subscription_type: Option<String>,
     pub introspection_mode: IntrospectionMode,
+    pub introspection_depth: Option<usize>,
+    pub introspection_fields: Option<usize>,
     pub enable_federation: bool,
     pub federation_subscription: bool,
     pub ignore_name_conflicts: HashSet<String>,
     pub enable_suggestions: bool,
+    pub contract: Option<Contract>,
+    pub constraint_directives: bool,
 }
 
 impl Registry {
@@ -1214,7 +1335,8 @@ impl Registry {
                     inaccessible: false,
                     tags: Default::default(),
                     is_secret: false,
-                    directive_invocations: vec![]
+                    directive_invocations: vec![],
+                    constraints: IndexMap::new()
                 });
                 args
             },
@@ -1243,7 +1365,8 @@ impl Registry {
                     inaccessible: false,
                     tags: Default::default(),
                     is_secret: false,
-                    directive_invocations: vec![]
+                    directive_invocations: vec![],
+                    constraints: IndexMap::new()
                 });
                 args
             },
@@ -1281,6 +1404,7 @@ impl Registry {
                         tags: Default::default(),
                         is_secret: false,
                         directive_invocations: vec![],
+                        constraints: IndexMap::new(),
                     },
                 );
                 args
@@ -1309,6 +1433,7 @@ impl Registry {
                         tags: Default::default(),
                         is_secret: false,
                         directive_invocations: vec![],
+                        constraints: IndexMap::new(),
                     },
                 );
                 args
@@ -1444,6 +1569,91 @@ impl Registry {
             .insert(directive.name.to_string(), directive);
     }
 
+    /// Returns the definition of the `@constraint` directive, compatible with
+    /// [graphql-constraint-directive](https://github.com/confuser/graphql-constraint-directive).
+    pub(crate) fn constraint_directive() -> MetaDirective {
+        MetaDirective {
+            name: "constraint".into(),
+            description: Some(
+                "Describes the constraints checked by the validators of an argument or an input field."
+                    .into(),
+            ),
+            locations: vec![
+                __DirectiveLocation::ARGUMENT_DEFINITION,
+                __DirectiveLocation::INPUT_FIELD_DEFINITION,
+            ],
+            args: [
+                ("minLength", "Int"),
+                ("maxLength", "Int"),
+                ("startsWith", "String"),
+                ("endsWith", "String"),
+                ("pattern", "String"),
+                ("format", "String"),
+                ("min", "Float"),
+                ("max", "Float"),
+                ("multipleOf", "Float"),
+                ("minItems", "Int"),
+                ("maxItems", "Int"),
+            ]
+            .into_iter()
+            .map(|(name, ty)| (name.to_string(), MetaInputValue::new(name, ty)))
+            .collect(),
+            is_repeatable: false,
+            visible: None,
+            composable: None,
+        }
+    }
+
+    /// Returns the definition of the `@validation` directive, which describes
+    /// the validators that `@constraint` cannot express.
+    pub(crate) fn validation_directive() -> MetaDirective {
+        MetaDirective {
+            name: "validation".into(),
+            description: Some(
+                "Describes the validators of an argument or an input field that are not supported by the `@constraint` directive."
+                    .into(),
+            ),
+            locations: vec![
+                __DirectiveLocation::ARGUMENT_DEFINITION,
+                __DirectiveLocation::INPUT_FIELD_DEFINITION,
+            ],
+            args: [
+                ("formats", "[String!]"),
+                ("uniqueItems", "Boolean"),
+                ("notBlank", "Boolean"),
+                ("oneOf", "[String!]"),
+                ("oneOfInt", "[Int!]"),
+                ("oneOfFloat", "[Float!]"),
+            ]
+            .into_iter()
+            .map(|(name, ty)| (name.to_string(), MetaInputValue::new(name, ty)))
+            .collect(),
+            is_repeatable: false,
+            visible: None,
+            composable: None,
+        }
+    }
+
+    /// Returns `true` if any argument or input field has constraints exported
+    /// by the `directive` directive.
+    pub(crate) fn has_constraints(&self, directive: &str) -> bool {
+        let has_constraints = |input_value: &MetaInputValue| {
+            input_value
+                .constraint_directives()
+                .iter()
+                .any(|invocation| invocation.name == directive)
+        };
+        self.types.values().any(|ty| match ty {
+            MetaType::Object { fields, .. } | MetaType::Interface { fields, .. } => fields
+                .values()
+                .any(|field| field.args.values().any(has_constraints)),
+            MetaType::InputObject { input_fields, .. } => {
+                input_fields.values().any(has_constraints)
+            }
+            _ => false,
+        })
+    }
+
     pub fn add_implements(&mut self, ty: &str, interface: &str) {
         self.implements
             .entry(ty.to_string())
@@ -1545,6 +1755,9 @@ impl Registry {
                     compute_complexity: None,
                     directive_invocations: vec![],
                     requires_scopes: vec![],
+                    authenticated: false,
+                    timeout: None,
+                    max_concurrency: None,
                 },
             );
         }
@@ -1585,6 +1798,7 @@ impl Registry {
                                     tags: Default::default(),
                                     is_secret: false,
                                     directive_invocations: vec![],
+                                    constraints: IndexMap::new(),
                                 },
                             );
                             args
@@ -1603,6 +1817,9 @@ impl Registry {
                         compute_complexity: None,
                         directive_invocations: vec![],
                         requires_scopes: vec![],
+                        authenticated: false,
+                        timeout: None,
+                        max_concurrency: None,
                     },
                 );
             }
@@ -1633,6 +1850,9 @@ impl Registry {
                     override_from: None,
                     directive_invocations: vec![],
                     requires_scopes: vec![],
+                    authenticated: false,
+                    timeout: None,
+                    max_concurrency: None,
                 },
             );
 
@@ -1656,6 +1876,7 @@ impl Registry {
                                 tags: Default::default(),
                                 is_secret: false,
                                 directive_invocations: vec![],
+                                constraints: IndexMap::new(),
                             },
                         );
                         args
@@ -1674,6 +1895,9 @@ impl Registry {
                     compute_complexity: None,
                     directive_invocations: vec![],
                     requires_scopes: vec![],
+                    authenticated: false,
+                    timeout: None,
+                    max_concurrency: None,
                 },
             );
         }
@@ -1709,6 +1933,9 @@ impl Registry {
                             compute_complexity: None,
                             directive_invocations: vec![],
                             requires_scopes: vec![],
+                            authenticated: false,
+                            timeout: None,
+                            max_concurrency: None,
                         },
                     );
                     fields
@@ -1726,6 +1953,7 @@ impl Registry {
                 rust_typename: Some("async_graphql::federation::Service"),
                 directive_invocations: vec![],
                 requires_scopes: vec![],
+                authenticated: false,
             },
         );
 
@@ -1902,6 +2130,44 @@ impl Registry {
         }
     }
 
+    /// Returns the results of all visibility checks of the registry, two
+    /// contexts with the same fingerprint get the same introspection result.
+    #[cfg(feature = "introspection_cache")]
+    pub(crate) fn visibility_fingerprint(&self, ctx: &Context<'_>) -> Vec<bool> {
+        let mut fingerprint = Vec::new();
+        let check_input_values =
+            |fingerprint: &mut Vec<bool>, values: &IndexMap<String, MetaInputValue>| {
+                fingerprint.extend(values.values().map(|value| is_visible(ctx, &value.visible)));
+            };
+
+        for ty in self.types.values() {
+            fingerprint.push(ty.is_visible(ctx));
+            match ty {
+                MetaType::Object { fields, .. } | MetaType::Interface { fields, .. } => {
+                    for field in fields.values() {
+                        fingerprint.push(is_field_visible(ctx, self, field));
+                        check_input_values(&mut fingerprint, &field.args);
+                    }
+                }
+                MetaType::Enum { enum_values, .. } => {
+                    fingerprint.extend(
+                        enum_values
+                            .values()
+                            .map(|value| is_visible(ctx, &value.visible)),
+                    );
+                }
+                MetaType::InputObject { input_fields, .. } => {
+                    check_input_values(&mut fingerprint, input_fields);
+                }
+                MetaType::Scalar { .. } | MetaType::Union { .. } => {}
+            }
+        }
+        for directive in self.directives.values() {
+            check_input_values(&mut fingerprint, &directive.args);
+        }
+        fingerprint
+    }
+
     pub fn find_visible_types(&self, ctx: &Context<'_>) -> HashSet<&str> {
         let mut visible_types = HashSet::new();
 
@@ -2056,6 +2322,18 @@ pub(crate) fn is_visible(ctx: &Context<'_>, visible: &Option<MetaVisibleFn>) ->
     }
 }
 
+/// Checks the visibility of a field, and its authorization when the
+/// unauthorized fields are hidden.
+pub(crate) fn is_field_visible(ctx: &Context<'_>, registry: &Registry, field: &MetaField) -> bool {
+    is_visible(ctx, &field.visible)
+        && ctx
+            .schema_env
+            .authorization
+            .as_ref()
+            .filter(|authorization| authorization.hides_unauthorized())
+            .is_none_or(|authorization| authorization.is_field_authorized(ctx, registry, field))
+}
+
 fn is_system_type(name: &str) -> bool {
     if name.starts_with("__") {
         return true;
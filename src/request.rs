@@ -1,12 +1,16 @@
 use std::{
     any::Any,
     fmt::{self, Debug, Formatter},
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
 use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::{
-    Data, Extensions, ParseRequestError, ServerError, UploadValue, Value, Variables,
+    CancellationToken, Data, Extensions, ParseRequestError, ServerError, UploadValue, Value,
+    Variables,
+    http::MultipartUploads,
     parser::{parse_query, types::ExecutableDocument},
     schema::IntrospectionMode,
 };
@@ -36,6 +40,9 @@ pub struct Request {
     #[serde(skip)]
     pub uploads: Vec<UploadValue>,
 
+    #[serde(skip)]
+    pub(crate) upload_stream: Option<Arc<MultipartUploads>>,
+
     /// The data of the request that can be accessed through `Context::data`.
     ///
     /// **This data is only valid for this request**
@@ -53,6 +60,16 @@ pub struct Request {
     /// [IntrospectionMode::Enabled]).
     #[serde(skip)]
     pub introspection_mode: IntrospectionMode,
+
+    /// The instant after which the fields which are not resolved yet fail
+    /// with a `TIMEOUT` error.
+    #[serde(skip)]
+    pub deadline: Option<Instant>,
+
+    /// The token cancelled when the client does not wait for the response
+    /// anymore, see [`CancellationToken`].
+    #[serde(skip)]
+    pub cancellation_token: CancellationToken,
 }
 
 impl Request {
@@ -63,10 +80,13 @@ impl Request {
             operation_name: None,
             variables: Variables::default(),
             uploads: Vec::default(),
+            upload_stream: None,
             data: Data::default(),
             extensions: Default::default(),
             parsed_query: None,
             introspection_mode: IntrospectionMode::Enabled,
+            deadline: None,
+            cancellation_token: Default::default(),
         }
     }
 
@@ -106,6 +126,33 @@ impl Request {
         self
     }
 
+    /// Set the deadline of the request.
+    ///
+    /// It is only enforced if the schema has a timer, see
+    /// [`SchemaBuilder::timer`](crate::SchemaBuilder::timer).
+    #[must_use]
+    pub fn deadline(self, deadline: Instant) -> Self {
+        Self {
+            deadline: Some(deadline),
+            ..self
+        }
+    }
+
+    /// Set the deadline of the request to `timeout` from now.
+    #[must_use]
+    pub fn timeout(self, timeout: Duration) -> Self {
+        self.deadline(Instant::now() + timeout)
+    }
+
+    /// Set the token to cancel the request with.
+    #[must_use]
+    pub fn cancellation_token(self, token: CancellationToken) -> Self {
+        Self {
+            cancellation_token: token,
+            ..self
+        }
+    }
+
     #[inline]
     /// Performs parsing of query ahead of execution.
     ///
@@ -140,29 +187,42 @@ impl Request {
     /// to the Rust code `request.variables["files"][2]["content"]`. If no
     /// variable exists at the path this function won't do anything.
     pub fn set_upload(&mut self, var_path: &str, upload: UploadValue) {
-        fn variable_path<'a>(variables: &'a mut Variables, path: &str) -> Option<&'a mut Value> {
-            let mut parts = path.strip_prefix("variables.")?.split('.');
-
-            let initial = variables.get_mut(parts.next().unwrap())?;
-
-            parts.try_fold(initial, |current, part| match current {
-                Value::List(list) => part
-                    .parse::<u32>()
-                    .ok()
-                    .and_then(|idx| usize::try_from(idx).ok())
-                    .and_then(move |idx| list.get_mut(idx)),
-                Value::Object(obj) => obj.get_mut(part),
-                _ => None,
-            })
-        }
-
-        let variable = match variable_path(&mut self.variables, var_path) {
-            Some(variable) => variable,
-            None => return,
+        let Some(variable) = variable_path(&mut self.variables, var_path) else {
+            return;
         };
         self.uploads.push(upload);
         *variable = Value::String(format!("#__graphql_file__:{}", self.uploads.len() - 1));
     }
+
+    /// Set a variable to the upload at `index` of the streamed uploads.
+    pub(crate) fn set_upload_stream(
+        &mut self,
+        var_path: &str,
+        index: usize,
+        uploads: &Arc<MultipartUploads>,
+    ) {
+        let Some(variable) = variable_path(&mut self.variables, var_path) else {
+            return;
+        };
+        self.upload_stream = Some(uploads.clone());
+        *variable = Value::String(format!("#__graphql_file__:{}", index));
+    }
+}
+
+fn variable_path<'a>(variables: &'a mut Variables, path: &str) -> Option<&'a mut Value> {
+    let mut parts = path.strip_prefix("variables.")?.split('.');
+
+    let initial = variables.get_mut(parts.next().unwrap())?;
+
+    parts.try_fold(initial, |current, part| match current {
+        Value::List(list) => part
+            .parse::<u32>()
+            .ok()
+            .and_then(|idx| usize::try_from(idx).ok())
+            .and_then(move |idx| list.get_mut(idx)),
+        Value::Object(obj) => obj.get_mut(part),
+        _ => None,
+    })
 }
 
 impl<T: Into<String>> From<T> for Request {
@@ -267,6 +327,24 @@ impl BatchRequest {
         }
         self
     }
+
+    /// Set the deadline of each request.
+    #[must_use]
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        for request in self.iter_mut() {
+            request.deadline = Some(deadline);
+        }
+        self
+    }
+
+    /// Set the token to cancel each request with.
+    #[must_use]
+    pub fn cancellation_token(mut self, token: CancellationToken) -> Self {
+        for request in self.iter_mut() {
+            request.cancellation_token = token.clone();
+        }
+        self
+    }
 }
 
 fn deserialize_non_empty_vec<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
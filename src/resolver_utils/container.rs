@@ -4,8 +4,13 @@ use futures_util::FutureExt;
 use indexmap::IndexMap;
 
 use crate::{
-    Context, ContextBase, ContextSelectionSet, Error, Name, OutputType, ServerError, ServerResult,
-    Value, extensions::ResolveInfo, parser::types::Selection,
+    Context, ContextBase, ContextSelectionSet, Error, ErrorCode, GuardAction, Name, OutputType,
+    Result, ServerError, ServerResult, Value,
+    authorization::check_field_access,
+    concurrency::{field_concurrency_limit, try_join_limited},
+    extensions::ResolveInfo,
+    parser::types::Selection,
+    timeout::{field_deadline, resolve_until_deadline},
 };
 
 /// Represents a GraphQL container object.
@@ -53,6 +58,31 @@ pub trait ContainerType: OutputType {
         fields.add_set(ctx, self)
     }
 
+    /// Check the guards attached to the type before the fields of this value
+    /// are resolved, returns [`GuardAction::Redact`] if they must all be
+    /// redacted.
+    ///
+    /// Objects with a type guard or a post-resolution guard override this,
+    /// and interfaces and unions must also call it on their internal type.
+    #[cfg(feature = "boxed-trait")]
+    async fn check_type_guard(&self, _: &Context<'_>) -> Result<GuardAction> {
+        Ok(GuardAction::Allow)
+    }
+
+    /// Check the guards attached to the type before the fields of this value
+    /// are resolved, returns [`GuardAction::Redact`] if they must all be
+    /// redacted.
+    ///
+    /// Objects with a type guard or a post-resolution guard override this,
+    /// and interfaces and unions must also call it on their internal type.
+    #[cfg(not(feature = "boxed-trait"))]
+    fn check_type_guard(
+        &self,
+        _: &Context<'_>,
+    ) -> impl Future<Output = Result<GuardAction>> + Send {
+        async { Ok(GuardAction::Allow) }
+    }
+
     /// Find the GraphQL entity with the given name from the parameter.
     ///
     /// Objects should override this in case they are the query root.
@@ -72,6 +102,41 @@ pub trait ContainerType: OutputType {
     ) -> impl Future<Output = ServerResult<Option<Value>>> + Send {
         async { Ok(None) }
     }
+
+    /// Find the GraphQL entities for a batch of representations at once.
+    ///
+    /// `entities` has one slot per representation, in the same order. Slots
+    /// that are already filled must be left untouched, and slots that are
+    /// left empty are resolved one by one with `find_entity`.
+    ///
+    /// Objects should override this in case they are the query root and have
+    /// batch entity resolvers.
+    #[cfg(feature = "boxed-trait")]
+    async fn find_entities(
+        &self,
+        _: &Context<'_>,
+        _: &[Value],
+        _: &mut [Option<ServerResult<Value>>],
+    ) {
+    }
+
+    /// Find the GraphQL entities for a batch of representations at once.
+    ///
+    /// `entities` has one slot per representation, in the same order. Slots
+    /// that are already filled must be left untouched, and slots that are
+    /// left empty are resolved one by one with `find_entity`.
+    ///
+    /// Objects should override this in case they are the query root and have
+    /// batch entity resolvers.
+    #[cfg(not(feature = "boxed-trait"))]
+    fn find_entities(
+        &self,
+        _: &Context<'_>,
+        _representations: &[Value],
+        _entities: &mut [Option<ServerResult<Value>>],
+    ) -> impl Future<Output = ()> + Send {
+        async {}
+    }
 }
 
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
@@ -80,9 +145,22 @@ impl<T: ContainerType + ?Sized> ContainerType for &T {
         T::resolve_field(*self, ctx).await
     }
 
+    async fn check_type_guard(&self, ctx: &Context<'_>) -> Result<GuardAction> {
+        T::check_type_guard(*self, ctx).await
+    }
+
     async fn find_entity(&self, ctx: &Context<'_>, params: &Value) -> ServerResult<Option<Value>> {
         T::find_entity(*self, ctx, params).await
     }
+
+    async fn find_entities(
+        &self,
+        ctx: &Context<'_>,
+        representations: &[Value],
+        entities: &mut [Option<ServerResult<Value>>],
+    ) {
+        T::find_entities(*self, ctx, representations, entities).await
+    }
 }
 
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
@@ -91,9 +169,22 @@ impl<T: ContainerType + ?Sized> ContainerType for Arc<T> {
         T::resolve_field(self, ctx).await
     }
 
+    async fn check_type_guard(&self, ctx: &Context<'_>) -> Result<GuardAction> {
+        T::check_type_guard(self, ctx).await
+    }
+
     async fn find_entity(&self, ctx: &Context<'_>, params: &Value) -> ServerResult<Option<Value>> {
         T::find_entity(self, ctx, params).await
     }
+
+    async fn find_entities(
+        &self,
+        ctx: &Context<'_>,
+        representations: &[Value],
+        entities: &mut [Option<ServerResult<Value>>],
+    ) {
+        T::find_entities(self, ctx, representations, entities).await
+    }
 }
 
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
@@ -102,9 +193,22 @@ impl<T: ContainerType + ?Sized> ContainerType for Box<T> {
         T::resolve_field(self, ctx).await
     }
 
+    async fn check_type_guard(&self, ctx: &Context<'_>) -> Result<GuardAction> {
+        T::check_type_guard(self, ctx).await
+    }
+
     async fn find_entity(&self, ctx: &Context<'_>, params: &Value) -> ServerResult<Option<Value>> {
         T::find_entity(self, ctx, params).await
     }
+
+    async fn find_entities(
+        &self,
+        ctx: &Context<'_>,
+        representations: &[Value],
+        entities: &mut [Option<ServerResult<Value>>],
+    ) {
+        T::find_entities(self, ctx, representations, entities).await
+    }
 }
 
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
@@ -116,12 +220,42 @@ impl<T: ContainerType, E: Into<Error> + Send + Sync + Clone> ContainerType for R
         }
     }
 
+    async fn check_type_guard(&self, ctx: &Context<'_>) -> Result<GuardAction> {
+        match self {
+            Ok(value) => T::check_type_guard(value, ctx).await,
+            Err(_) => Ok(GuardAction::Allow),
+        }
+    }
+
     async fn find_entity(&self, ctx: &Context<'_>, params: &Value) -> ServerResult<Option<Value>> {
         match self {
             Ok(value) => T::find_entity(value, ctx, params).await,
             Err(err) => Err(ctx.set_error_path(err.clone().into().into_server_error(ctx.item.pos))),
         }
     }
+
+    async fn find_entities(
+        &self,
+        ctx: &Context<'_>,
+        representations: &[Value],
+        entities: &mut [Option<ServerResult<Value>>],
+    ) {
+        if let Ok(value) = self {
+            T::find_entities(value, ctx, representations, entities).await
+        }
+    }
+}
+
+/// Helper used by proc-macro-generated interfaces and unions to check the type
+/// guards of their internal type.
+///
+/// The future is boxed so that it is `Send` for trait objects too.
+#[doc(hidden)]
+pub fn boxed_check_type_guard<'a, T: ContainerType + ?Sized>(
+    obj: &'a T,
+    ctx: &'a Context<'_>,
+) -> Pin<Box<dyn Future<Output = Result<GuardAction>> + Send + 'a>> {
+    Box::pin(obj.check_type_guard(ctx))
 }
 
 /// Resolve an container by executing each of the fields concurrently.
@@ -183,7 +317,7 @@ async fn resolve_container_inner<'a, T: ContainerType + ?Sized>(
     fields.add_set(ctx, root)?;
 
     let res = if parallel {
-        futures_util::future::try_join_all(fields.0).await?
+        try_join_limited(ctx, fields.0).await?
     } else {
         let mut results = Vec::with_capacity(fields.0.len());
         for field in fields.0 {
@@ -223,104 +357,140 @@ impl<'a> Fields<'a> {
                         continue;
                     }
 
+                    // Fields removed by a contract must not be executed, even if the
+                    // validation is skipped.
+                    if ctx.schema_env.registry.contract.is_some() {
+                        let type_name = T::type_name();
+                        if ctx
+                            .schema_env
+                            .registry
+                            .types
+                            .get(type_name.as_ref())
+                            .and_then(|ty| ty.field_by_name(field.node.name.node.as_str()))
+                            .is_none()
+                        {
+                            return Err(ServerError::new(
+                                format!(
+                                    r#"Cannot query field "{}" on type "{}"."#,
+                                    field.node.name.node, type_name
+                                ),
+                                Some(field.pos),
+                            )
+                            .with_code(ErrorCode::GraphqlValidationFailed));
+                        }
+                    }
+
                     let resolve_fut = Box::pin({
                         let ctx = ctx.clone();
                         async move {
-                            let ctx_field = ctx.with_field(field);
+                            let mut ctx_field = ctx.with_field(field);
                             let field_name = ctx_field.item.node.response_key().node.clone();
                             let extensions = &ctx.query_env.extensions;
+                            let parent_type = root.introspection_type_name();
 
-                            if extensions.is_empty() && field.node.directives.is_empty() {
-                                Ok((
-                                    field_name,
-                                    root.resolve_field(&ctx_field).await?.unwrap_or_default(),
-                                ))
-                            } else {
-                                let type_name = T::type_name();
-                                let resolve_info = ResolveInfo {
-                                    path_node: ctx_field.path_node.as_ref().unwrap(),
-                                    parent_type: &type_name,
-                                    return_type: match ctx_field
-                                        .schema_env
-                                        .registry
-                                        .types
-                                        .get(type_name.as_ref())
-                                        .and_then(|ty| {
-                                            ty.field_by_name(field.node.name.node.as_str())
-                                        })
-                                        .map(|field| &field.ty)
-                                    {
-                                        Some(ty) => &ty,
-                                        None => {
-                                            return Err(ServerError::new(
-                                                format!(
-                                                    r#"Cannot query field "{}" on type "{}"."#,
-                                                    field_name, type_name
-                                                ),
-                                                Some(ctx_field.item.pos),
-                                            ));
-                                        }
-                                    },
-                                    name: field.node.name.node.as_str(),
-                                    alias: field
-                                        .node
-                                        .alias
-                                        .as_ref()
-                                        .map(|alias| alias.node.as_str()),
-                                    is_for_introspection: ctx_field.is_for_introspection,
-                                    field: &field.node,
-                                };
-
-                                let resolve_fut = root.resolve_field(&ctx_field);
-
-                                if field.node.directives.is_empty() {
-                                    futures_util::pin_mut!(resolve_fut);
-                                    Ok((
-                                        field_name,
-                                        extensions
-                                            .resolve(resolve_info, &mut resolve_fut)
-                                            .await?
-                                            .unwrap_or_default(),
-                                    ))
-                                } else {
-                                    let mut resolve_fut = resolve_fut.boxed();
+                            if let Some(res) = check_field_access(&ctx_field, &parent_type) {
+                                return res.map(|value| (field_name, value));
+                            }
 
-                                    for directive in &field.node.directives {
-                                        if let Some(directive_factory) = ctx
+                            let meta_field = field_deadline(&mut ctx_field, &parent_type);
+                            field_concurrency_limit(&mut ctx_field, &parent_type);
+                            let resolve_value = async {
+                                if extensions.is_empty() && field.node.directives.is_empty() {
+                                    Ok(root.resolve_field(&ctx_field).await?.unwrap_or_default())
+                                } else {
+                                    let resolve_info = ResolveInfo {
+                                        path_node: ctx_field.path_node.as_ref().unwrap(),
+                                        parent_type: &parent_type,
+                                        return_type: match ctx_field
                                             .schema_env
-                                            .custom_directives
-                                            .get(directive.node.name.node.as_str())
+                                            .registry
+                                            .types
+                                            .get(parent_type.as_ref())
+                                            .and_then(|ty| {
+                                                ty.field_by_name(field.node.name.node.as_str())
+                                            })
+                                            .map(|field| &field.ty)
                                         {
-                                            let ctx_directive = ContextBase {
-                                                path_node: ctx_field.path_node,
-                                                is_for_introspection: false,
-                                                item: directive,
-                                                schema_env: ctx_field.schema_env,
-                                                query_env: ctx_field.query_env,
-                                                execute_data: ctx_field.execute_data,
-                                            };
-                                            let directive_instance = directive_factory
-                                                .create(&ctx_directive, &directive.node)?;
-                                            resolve_fut = Box::pin({
-                                                let ctx_field = ctx_field.clone();
-                                                async move {
-                                                    directive_instance
-                                                        .resolve_field(&ctx_field, &mut resolve_fut)
-                                                        .await
-                                                }
-                                            });
+                                            Some(ty) => &ty,
+                                            None => {
+                                                return Err(ServerError::new(
+                                                    format!(
+                                                        r#"Cannot query field "{}" on type "{}"."#,
+                                                        field_name, parent_type
+                                                    ),
+                                                    Some(ctx_field.item.pos),
+                                                ));
+                                            }
+                                        },
+                                        name: field.node.name.node.as_str(),
+                                        alias: field
+                                            .node
+                                            .alias
+                                            .as_ref()
+                                            .map(|alias| alias.node.as_str()),
+                                        is_for_introspection: ctx_field.is_for_introspection,
+                                        field: &field.node,
+                                    };
+
+                                    let resolve_fut = root.resolve_field(&ctx_field);
+
+                                    if field.node.directives.is_empty() {
+                                        futures_util::pin_mut!(resolve_fut);
+                                        Ok(extensions
+                                            .resolve(resolve_info, &mut resolve_fut)
+                                            .await?
+                                            .unwrap_or_default())
+                                    } else {
+                                        let mut resolve_fut = resolve_fut.boxed();
+
+                                        for directive in &field.node.directives {
+                                            if let Some(directive_factory) = ctx
+                                                .schema_env
+                                                .custom_directives
+                                                .get(directive.node.name.node.as_str())
+                                            {
+                                                let ctx_directive = ContextBase {
+                                                    path_node: ctx_field.path_node,
+                                                    is_for_introspection: false,
+                                                    item: directive,
+                                                    schema_env: ctx_field.schema_env,
+                                                    query_env: ctx_field.query_env,
+                                                    execute_data: ctx_field.execute_data,
+                                                    deadline: ctx_field.deadline,
+                                                    concurrency_limit: ctx_field.concurrency_limit,
+                                                };
+                                                let directive_instance = directive_factory
+                                                    .create(&ctx_directive, &directive.node)?;
+                                                resolve_fut = Box::pin({
+                                                    let ctx_field = ctx_field.clone();
+                                                    async move {
+                                                        directive_instance
+                                                            .resolve_field(
+                                                                &ctx_field,
+                                                                &mut resolve_fut,
+                                                            )
+                                                            .await
+                                                    }
+                                                });
+                                            }
                                         }
-                                    }
 
-                                    Ok((
-                                        field_name,
-                                        extensions
+                                        Ok(extensions
                                             .resolve(resolve_info, &mut resolve_fut)
                                             .await?
-                                            .unwrap_or_default(),
-                                    ))
+                                            .unwrap_or_default())
+                                    }
                                 }
-                            }
+                            };
+
+                            let value = match meta_field {
+                                Some(meta_field) => {
+                                    resolve_until_deadline(&ctx_field, meta_field, resolve_value)
+                                        .await?
+                                }
+                                None => resolve_value.await?,
+                            };
+                            Ok((field_name, value))
                         }
                     });
 
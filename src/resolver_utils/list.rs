@@ -1,9 +1,10 @@
 use crate::{
-    ContextSelectionSet, OutputType, Positioned, ServerResult, Value, extensions::ResolveInfo,
-    parser::types::Field,
+    ContextSelectionSet, OutputType, Positioned, ServerResult, Value,
+    concurrency::try_join_limited, extensions::ResolveInfo, parser::types::Field,
 };
 
-/// Resolve an list by executing each of the items concurrently.
+/// Resolve an list by executing each of the items concurrently, up to the
+/// concurrency limit of the field.
 pub async fn resolve_list<'a, T: OutputType + 'a>(
     ctx: &ContextSelectionSet<'a>,
     field: &Positioned<Field>,
@@ -43,9 +44,7 @@ pub async fn resolve_list<'a, T: OutputType + 'a>(
                 }
             });
         }
-        Ok(Value::List(
-            futures_util::future::try_join_all(futures).await?,
-        ))
+        Ok(Value::List(try_join_limited(ctx, futures).await?))
     } else {
         let mut futures = len.map(Vec::with_capacity).unwrap_or_default();
         for (idx, item) in iter.into_iter().enumerate() {
@@ -56,8 +55,6 @@ pub async fn resolve_list<'a, T: OutputType + 'a>(
                     .map_err(|err| ctx_idx.set_error_path(err))
             });
         }
-        Ok(Value::List(
-            futures_util::future::try_join_all(futures).await?,
-        ))
+        Ok(Value::List(try_join_limited(ctx, futures).await?))
     }
 }
@@ -6,9 +6,11 @@ mod r#enum;
 mod list;
 mod object;
 mod scalar;
+mod sdl;
 
 pub use container::*;
 pub use r#enum::*;
 pub use list::*;
 pub use object::*;
 pub use scalar::*;
+pub use sdl::*;
@@ -1,8 +1,47 @@
-use std::future::Future;
+use std::{borrow::Cow, future::Future};
 
 use indexmap::IndexMap;
 
-use crate::{Context, Error, Name, OutputType, ServerError, ServerResult, Value};
+use crate::{
+    Context, ContextBase, ContextSelectionSet, Error, ErrorCode, GuardAction, Name, OutputType,
+    Positioned, ResultGuard, ServerError, ServerResult, Value,
+    parser::types::Field,
+    registry::{MetaTypeName, Registry},
+    resolver_utils::{ContainerType, resolve_container},
+};
+
+/// Helper used by proc-macro-generated resolvers to set the `FORBIDDEN` code
+/// on the errors of guards, unless they have a code.
+#[doc(hidden)]
+pub fn guard_error(err: Error) -> Error {
+    err.with_code(ErrorCode::Forbidden)
+}
+
+/// Helper used by proc-macro-generated resolvers to check the type guards of
+/// `root` before resolving the selection set of `field`.
+///
+/// Returns [`GuardAction::Redact`] if the fields of `root` must be resolved
+/// with [`resolve_redacted_container`].
+#[doc(hidden)]
+pub async fn check_type_guard<T: ContainerType + ?Sized>(
+    ctx: &ContextSelectionSet<'_>,
+    field: &Positioned<Field>,
+    root: &T,
+) -> ServerResult<GuardAction> {
+    let ctx_field = ContextBase {
+        path_node: ctx.path_node,
+        is_for_introspection: ctx.is_for_introspection,
+        item: field,
+        schema_env: ctx.schema_env,
+        query_env: ctx.query_env,
+        execute_data: ctx.execute_data,
+        deadline: ctx.deadline,
+        concurrency_limit: ctx.concurrency_limit,
+    };
+    root.check_type_guard(&ctx_field)
+        .await
+        .map_err(|err| ctx.set_error_path(guard_error(err).into_server_error(field.pos)))
+}
 
 /// Helper used by proc-macro-generated object resolvers to reduce emitted code.
 #[doc(hidden)]
@@ -32,6 +71,91 @@ where
     }
 }
 
+/// Helper used by proc-macro-generated resolvers to check a post-resolution
+/// guard, returns `false` if the value of the field of `parent_type` is
+/// redacted.
+#[doc(hidden)]
+pub async fn check_result_guard<T, G>(
+    ctx: &Context<'_>,
+    parent_type: &str,
+    guard: &G,
+    value: &T,
+) -> ServerResult<bool>
+where
+    T: Send + Sync,
+    G: ResultGuard<T>,
+{
+    match guard
+        .check(ctx, value)
+        .await
+        .map_err(|err| ctx.set_error_path(guard_error(err).into_server_error(ctx.item.pos)))?
+    {
+        GuardAction::Allow => Ok(true),
+        GuardAction::Redact => redact_field(ctx, parent_type).map(|_| false),
+    }
+}
+
+/// Returns `null` for the field of `ctx` on `parent_type`, or a `FORBIDDEN`
+/// error if the field is non-null.
+fn redact_field(ctx: &Context<'_>, parent_type: &str) -> ServerResult<Option<Value>> {
+    let is_non_null = ctx
+        .schema_env
+        .registry
+        .types
+        .get(parent_type)
+        .and_then(|ty| ty.field_by_name(ctx.item.node.name.node.as_str()))
+        .is_some_and(|field| MetaTypeName::create(&field.ty).is_non_null());
+    if is_non_null {
+        let err = guard_error(Error::new("Forbidden")).into_server_error(ctx.item.pos);
+        return Err(ctx.set_error_path(err));
+    }
+    Ok(Some(Value::Null))
+}
+
+/// Helper used by proc-macro-generated resolvers to resolve the selection set
+/// of `root` when its type guards redact it, every field is `null`, or a
+/// `FORBIDDEN` error if it is non-null.
+#[doc(hidden)]
+pub async fn resolve_redacted_container<T: ContainerType + ?Sized>(
+    ctx: &ContextSelectionSet<'_>,
+    root: &T,
+) -> ServerResult<Value> {
+    resolve_container(ctx, &Redacted(root)).await
+}
+
+/// A container whose fields are all redacted.
+struct Redacted<'a, T: ?Sized>(&'a T);
+
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl<T: ContainerType + ?Sized> OutputType for Redacted<'_, T> {
+    fn type_name() -> Cow<'static, str> {
+        T::type_name()
+    }
+
+    fn introspection_type_name(&self) -> Cow<'static, str> {
+        self.0.introspection_type_name()
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        T::create_type_info(registry)
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl<T: ContainerType + ?Sized> ContainerType for Redacted<'_, T> {
+    async fn resolve_field(&self, ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        redact_field(ctx, &self.0.introspection_type_name())
+    }
+}
+
 /// Helper used by proc-macro-generated object resolvers to parse entity params.
 #[doc(hidden)]
 pub fn find_entity_params<'a>(
@@ -53,6 +177,63 @@ pub fn find_entity_params<'a>(
     Ok(Some((params, typename)))
 }
 
+/// Helper used by proc-macro-generated object resolvers to resolve the result
+/// of a batch entity resolver.
+///
+/// `indexes` are the positions of the representations that were passed to the
+/// resolver, the resolved entities are written back to the same positions.
+#[doc(hidden)]
+pub async fn resolve_entity_batch<T, E, F>(
+    ctx: &Context<'_>,
+    entities: &mut [Option<ServerResult<Value>>],
+    indexes: Vec<usize>,
+    fut: F,
+) where
+    T: OutputType,
+    E: Into<Error>,
+    F: Future<Output = Result<Vec<T>, E>>,
+{
+    if indexes.is_empty() {
+        return;
+    }
+
+    let values = match fut.await {
+        Ok(values) if values.len() == indexes.len() => values,
+        res => {
+            let err = match res {
+                Ok(values) => ServerError::new(
+                    format!(
+                        "Batch entity resolver returned {} entities for {} representations.",
+                        values.len(),
+                        indexes.len()
+                    ),
+                    Some(ctx.item.pos),
+                ),
+                Err(err) => err.into().into_server_error(ctx.item.pos),
+            };
+            for idx in indexes {
+                entities[idx] = Some(Err(ctx.with_index(idx).set_error_path(err.clone())));
+            }
+            return;
+        }
+    };
+
+    let ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
+    let resolved =
+        futures_util::future::join_all(indexes.iter().zip(&values).map(|(idx, value)| {
+            let ctx_idx = ctx_obj.with_index(*idx);
+            async move {
+                OutputType::resolve(value, &ctx_idx, ctx.item)
+                    .await
+                    .map_err(|err| ctx_idx.set_error_path(err))
+            }
+        }))
+        .await;
+    for (idx, res) in indexes.into_iter().zip(resolved) {
+        entities[idx] = Some(res);
+    }
+}
+
 /// Resolve a SimpleObject field value using the current selection set.
 ///
 /// This is a small helper used by derive codegen to keep emitted resolver code
@@ -1,4 +1,4 @@
-use crate::{InputValueResult, Value};
+use crate::{ContextBase, ErrorCode, InputValueResult, Pos, ServerError, ServerResult, Value};
 
 /// A GraphQL scalar.
 ///
@@ -43,6 +43,27 @@ pub trait ScalarType: Sized + Send {
     fn to_value(&self) -> Value;
 }
 
+/// Checks that a value of the `Int` scalar is a 32-bit signed integer when
+/// [`SchemaBuilder::enable_strict_int`](crate::SchemaBuilder::enable_strict_int)
+/// is set.
+#[doc(hidden)]
+pub fn check_int_value<T>(ctx: &ContextBase<'_, T>, pos: Pos, value: Value) -> ServerResult<Value> {
+    if !ctx.schema_env.strict_int {
+        return Ok(value);
+    }
+    match &value {
+        Value::Number(n) if n.as_i64().is_none_or(|n| i32::try_from(n).is_err()) => Err(ctx
+            .set_error_path(
+                ServerError::new(
+                    format!("Int cannot represent non 32-bit signed integer value: {n}"),
+                    Some(pos),
+                )
+                .with_code(ErrorCode::InternalServerError),
+            )),
+        _ => Ok(value),
+    }
+}
+
 /// Define a scalar
 ///
 /// If your type implemented `serde::Serialize` and `serde::Deserialize`, then
@@ -171,6 +192,7 @@ macro_rules! scalar_internal {
                         specified_by_url: $specified_by_url,
                         directive_invocations: ::std::vec::Vec::new(),
                         requires_scopes: ::std::vec::Vec::new(),
+                        authenticated: false,
                     }
                 })
             }
@@ -221,6 +243,7 @@ macro_rules! scalar_internal_output {
                         specified_by_url: $specified_by_url,
                         directive_invocations: ::std::vec::Vec::new(),
                         requires_scopes: ::std::vec::Vec::new(),
+                        authenticated: false,
                     }
                 })
             }
@@ -262,6 +285,7 @@ macro_rules! scalar_internal_output {
                         specified_by_url: $specified_by_url,
                         directive_invocations: ::std::vec::Vec::new(),
                         requires_scopes: ::std::vec::Vec::new(),
+                        authenticated: false,
                     }
                 })
             }
@@ -0,0 +1,258 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use async_graphql_parser::{
+    Positioned, parse_schema,
+    types::{
+        ConstDirective, FieldDefinition, InputValueDefinition, ServiceDocument, TypeKind,
+        TypeSystemDefinition,
+    },
+};
+
+use crate::{Name, ObjectType, Schema, SubscriptionType, Value};
+
+const BUILTIN_SCALARS: &[&str] = &["Int", "Float", "String", "Boolean", "ID"];
+
+/// Compares the schema built from the given root types with the SDL it was
+/// generated from, returning the differences as an error.
+///
+/// This is used by the test generated by `include_sdl!`.
+#[doc(hidden)]
+pub fn check_sdl<Query, Mutation, Subscription>(sdl: &str) -> Result<(), String>
+where
+    Query: ObjectType + 'static,
+    Mutation: ObjectType + 'static,
+    Subscription: SubscriptionType + 'static,
+{
+    let expected = parse_schema(sdl).map_err(|err| format!("Invalid SDL: {}", err))?;
+    let actual_sdl = Schema::<Query, Mutation, Subscription>::create_registry(Default::default())
+        .export_sdl(Default::default());
+    let actual = parse_schema(&actual_sdl).map_err(|err| format!("Invalid SDL: {}", err))?;
+
+    let expected = canonicalize(&expected);
+    let actual = canonicalize(&actual);
+    let mut errors = String::new();
+    for (name, expected_def) in &expected {
+        match actual.get(name) {
+            Some(actual_def) if actual_def == expected_def => {}
+            Some(actual_def) => {
+                let _ = writeln!(
+                    errors,
+                    "`{}` differs:\n  expected: {}\n  actual:   {}",
+                    name, expected_def, actual_def
+                );
+            }
+            None => {
+                let _ = writeln!(errors, "`{}` is missing from the schema.", name);
+            }
+        }
+    }
+    for name in actual.keys() {
+        if !expected.contains_key(name) {
+            let _ = writeln!(errors, "`{}` is not defined in the SDL.", name);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "The schema does not match its SDL:\n{}\nGenerated SDL:\n{}",
+            errors, actual_sdl
+        ))
+    }
+}
+
+/// Returns an order-independent representation of each definition.
+fn canonicalize(doc: &ServiceDocument) -> BTreeMap<String, String> {
+    let mut defs = BTreeMap::new();
+    let mut has_schema = false;
+
+    for definition in &doc.definitions {
+        match definition {
+            TypeSystemDefinition::Schema(schema) => {
+                has_schema = true;
+                let schema = &schema.node;
+                defs.insert(
+                    "schema".to_string(),
+                    format!(
+                        "query: {}, mutation: {:?}, subscription: {:?}",
+                        schema
+                            .query
+                            .as_ref()
+                            .map(|name| name.node.as_str())
+                            .unwrap_or("Query"),
+                        schema.mutation.as_ref().map(|name| name.node.as_str()),
+                        schema.subscription.as_ref().map(|name| name.node.as_str())
+                    ),
+                );
+            }
+            TypeSystemDefinition::Type(ty) => {
+                let ty = &ty.node;
+                let name = ty.name.node.as_str();
+                if name.starts_with("__") || BUILTIN_SCALARS.contains(&name) {
+                    continue;
+                }
+
+                let mut def = String::new();
+                write_description(&mut def, ty.description.as_ref().map(|d| &d.node));
+                match &ty.kind {
+                    TypeKind::Scalar => def.push_str("scalar"),
+                    TypeKind::Object(object) => {
+                        def.push_str("type");
+                        write_implements(&mut def, &object.implements);
+                        write_fields(&mut def, &object.fields);
+                    }
+                    TypeKind::Interface(interface) => {
+                        def.push_str("interface");
+                        write_implements(&mut def, &interface.implements);
+                        write_fields(&mut def, &interface.fields);
+                    }
+                    TypeKind::Union(union) => {
+                        let mut members = union
+                            .members
+                            .iter()
+                            .map(|member| member.node.as_str())
+                            .collect::<Vec<_>>();
+                        members.sort_unstable();
+                        let _ = write!(def, "union = {}", members.join(" | "));
+                    }
+                    TypeKind::Enum(enum_type) => {
+                        let mut values = enum_type
+                            .values
+                            .iter()
+                            .map(|value| {
+                                let value = &value.node;
+                                let mut def = String::new();
+                                write_description(
+                                    &mut def,
+                                    value.description.as_ref().map(|d| &d.node),
+                                );
+                                def.push_str(&value.value.node);
+                                write_deprecation(&mut def, &value.directives);
+                                def
+                            })
+                            .collect::<Vec<_>>();
+                        values.sort_unstable();
+                        let _ = write!(def, "enum {{ {} }}", values.join(", "));
+                    }
+                    TypeKind::InputObject(input_object) => {
+                        def.push_str("input");
+                        if ty
+                            .directives
+                            .iter()
+                            .any(|directive| directive.node.name.node == "oneOf")
+                        {
+                            def.push_str(" @oneOf");
+                        }
+                        let _ = write!(def, " {{ {} }}", input_values(&input_object.fields));
+                    }
+                }
+                defs.insert(name.to_string(), def);
+            }
+            TypeSystemDefinition::Directive(_) => {}
+        }
+    }
+
+    if !has_schema {
+        let root = |name: &str| defs.contains_key(name).then(|| name.to_string());
+        let schema = format!(
+            "query: Query, mutation: {:?}, subscription: {:?}",
+            root("Mutation").as_deref(),
+            root("Subscription").as_deref()
+        );
+        defs.insert("schema".to_string(), schema);
+    }
+    defs
+}
+
+fn write_description(def: &mut String, description: Option<&String>) {
+    if let Some(description) = description {
+        let lines = description
+            .trim()
+            .lines()
+            .map(str::trim)
+            .collect::<Vec<_>>();
+        let _ = write!(def, "{:?} ", lines.join("\n"));
+    }
+}
+
+fn write_deprecation(def: &mut String, directives: &[Positioned<ConstDirective>]) {
+    if let Some(directive) = directives
+        .iter()
+        .find(|directive| directive.node.name.node == "deprecated")
+    {
+        let reason = match directive.node.get_argument("reason").map(|arg| &arg.node) {
+            Some(Value::String(reason)) => reason.as_str(),
+            _ => "No longer supported",
+        };
+        let _ = write!(def, " @deprecated({:?})", reason);
+    }
+}
+
+fn write_implements(def: &mut String, implements: &[Positioned<Name>]) {
+    let mut implements = implements
+        .iter()
+        .map(|name| name.node.as_str())
+        .collect::<Vec<_>>();
+    implements.sort_unstable();
+    if !implements.is_empty() {
+        let _ = write!(def, " implements {}", implements.join(" & "));
+    }
+}
+
+fn write_fields(def: &mut String, fields: &[Positioned<FieldDefinition>]) {
+    let mut fields = fields
+        .iter()
+        .map(|field| {
+            let field = &field.node;
+            let mut def = String::new();
+            write_description(&mut def, field.description.as_ref().map(|d| &d.node));
+            let _ = write!(
+                def,
+                "{}({}): {}",
+                field.name.node,
+                input_values(&field.arguments),
+                field.ty.node
+            );
+            write_deprecation(&mut def, &field.directives);
+            def
+        })
+        .collect::<Vec<_>>();
+    fields.sort_unstable();
+    let _ = write!(def, " {{ {} }}", fields.join(", "));
+}
+
+fn input_values(values: &[Positioned<InputValueDefinition>]) -> String {
+    let mut values = values
+        .iter()
+        .map(|value| {
+            let value = &value.node;
+            let mut def = String::new();
+            write_description(&mut def, value.description.as_ref().map(|d| &d.node));
+            let _ = write!(def, "{}: {}", value.name.node, value.ty.node);
+            if let Some(default_value) = &value.default_value {
+                let _ = write!(def, " = {}", without_nulls(default_value.node.clone()));
+            }
+            write_deprecation(&mut def, &value.directives);
+            def
+        })
+        .collect::<Vec<_>>();
+    values.sort_unstable();
+    values.join(", ")
+}
+
+/// Input objects are converted back to values with all their fields, so the
+/// null fields of a default value are not significant.
+fn without_nulls(value: Value) -> Value {
+    match value {
+        Value::List(items) => Value::List(items.into_iter().map(without_nulls).collect()),
+        Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .filter(|(_, value)| *value != Value::Null)
+                .map(|(name, value)| (name, without_nulls(value)))
+                .collect(),
+        ),
+        value => value,
+    }
+}
@@ -1,6 +1,7 @@
 use std::{
     any::{Any, TypeId},
     collections::{HashMap, HashSet},
+    num::NonZeroUsize,
     ops::Deref,
     sync::Arc,
 };
@@ -8,22 +9,30 @@ use std::{
 use async_graphql_parser::types::ExecutableDocument;
 use futures_util::stream::{self, BoxStream, FuturesOrdered, StreamExt};
 
+#[cfg(feature = "introspection_cache")]
+use crate::model::IntrospectionCache;
 use crate::{
-    BatchRequest, BatchResponse, CacheControl, ContextBase, EmptyMutation, EmptySubscription,
-    Executor, InputType, ObjectType, OutputType, QueryEnv, Request, Response, ServerError,
+    Authorization, BatchRequest, BatchResponse, CacheControl, CancellationToken, ContextBase,
+    DeprecationReporting, EmptyMutation, EmptySubscription, ErrorCode, ErrorPolicy, Executor,
+    InputType, ObjectType, OutputType, QueryAnalysis, QueryEnv, Request, Response, ServerError,
     ServerResult, SubscriptionType, Variables,
     context::{Data, QueryEnvInner},
     custom_directive::CustomDirectiveFactory,
+    error_policy::apply_error_policy,
     extensions::{ExtensionFactory, Extensions},
     parser::{
         Positioned, parse_query,
-        types::{Directive, DocumentOperations, OperationType, Selection, SelectionSet},
+        types::{
+            Directive, DocumentOperations, OperationDefinition, OperationType, Selection,
+            SelectionSet,
+        },
     },
-    registry::{Registry, SDLExportOptions},
+    registry::{Contract, Registry, SDLExportOptions},
     resolver_utils::{resolve_container, resolve_container_serial},
+    runtime::Timer,
     subscription::collect_subscription_streams,
     types::QueryRoot,
-    validation::{ValidationMode, check_rules},
+    validation::{ValidationMode, analyze_document, check_rules, collect_deprecations},
 };
 
 /// Introspection mode
@@ -50,6 +59,15 @@ pub struct SchemaBuilder<Query, Mutation, Subscription> {
     depth: Option<usize>,
     recursive_depth: usize,
     max_directives: Option<usize>,
+    #[cfg(feature = "introspection_cache")]
+    introspection_cache: Option<usize>,
+    contract: Option<Contract>,
+    error_policy: Option<Arc<dyn ErrorPolicy>>,
+    deprecation_reporting: Option<DeprecationReporting>,
+    authorization: Option<Authorization>,
+    strict_int: bool,
+    timer: Option<Arc<dyn Timer>>,
+    concurrency_limit: Option<NonZeroUsize>,
     extensions: Vec<Box<dyn ExtensionFactory>>,
     custom_directives: HashMap<String, Box<dyn CustomDirectiveFactory>>,
 }
@@ -90,6 +108,99 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Set the maximum nesting of `__Type` selections in an introspection
+    /// query, such as `ofType { ofType { ... } }` chains. By default, there is
+    /// no limit.
+    ///
+    /// The introspection fields are still counted by
+    /// [`limit_complexity`](Self::limit_complexity) and
+    /// [`limit_depth`](Self::limit_depth).
+    #[must_use]
+    pub fn limit_introspection_depth(mut self, depth: usize) -> Self {
+        self.registry.introspection_depth = Some(depth);
+        self
+    }
+
+    /// Set the maximum number of `__schema` and `__type` fields an operation
+    /// can select. By default, there is no limit.
+    #[must_use]
+    pub fn limit_introspection_fields(mut self, count: usize) -> Self {
+        self.registry.introspection_fields = Some(count);
+        self
+    }
+
+    /// Reject the values of `Int` fields outside of the 32-bit signed range
+    /// with a field error, as required by the GraphQL specification.
+    ///
+    /// The Rust integer types wider than 32 bits, such as `i64`, are exposed
+    /// as `Int` unless the `int64_as_long` feature is enabled, and the values
+    /// which don't fit are returned as is by default. Use [`Long`](crate::Long)
+    /// to expose a single field as a 64-bit integer.
+    #[must_use]
+    pub fn enable_strict_int(mut self) -> Self {
+        self.strict_int = true;
+        self
+    }
+
+    /// Cache the results of `__schema` queries.
+    ///
+    /// Results are cached per selection set and per set of visible types and
+    /// fields, at most `capacity` results are kept and the least recently used
+    /// ones are evicted. Extensions are not called for the fields of a cached
+    /// result.
+    #[cfg(feature = "introspection_cache")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "introspection_cache")))]
+    #[must_use]
+    pub fn enable_introspection_cache(mut self, capacity: usize) -> Self {
+        self.introspection_cache = Some(capacity);
+        self
+    }
+
+    /// Build a contract schema, filtered by tags.
+    ///
+    /// See [`Contract`] for the filtering rules.
+    #[must_use]
+    pub fn contract(mut self, contract: Contract) -> Self {
+        self.contract = Some(contract);
+        self
+    }
+
+    /// Set the policy classifying the errors returned by the resolvers, the
+    /// messages of internal errors are masked before being sent to the
+    /// client.
+    ///
+    /// Parse and validation errors are always sent as is. See
+    /// [`MaskInternalErrors`](crate::MaskInternalErrors).
+    #[must_use]
+    pub fn error_policy(mut self, policy: impl ErrorPolicy) -> Self {
+        self.error_policy = Some(Arc::new(policy));
+        self
+    }
+
+    /// Collect the usage of deprecated fields, arguments, input fields and
+    /// enum values, see [`DeprecationReporting`].
+    #[must_use]
+    pub fn deprecation_reporting(mut self, reporting: DeprecationReporting) -> Self {
+        self.deprecation_reporting = Some(reporting);
+        self
+    }
+
+    /// Enforce the `@authenticated` and `@requiresScopes` requirements of the
+    /// fields and types at execution time, see [`Authorization`].
+    #[must_use]
+    pub fn authorization(mut self, authorization: Authorization) -> Self {
+        self.authorization = Some(authorization);
+        self
+    }
+
+    /// Set the timer used to enforce the field timeouts and the request
+    /// deadlines. Without a timer they are not enforced.
+    #[must_use]
+    pub fn timer(mut self, timer: impl Timer) -> Self {
+        self.timer = Some(Arc::new(timer));
+        self
+    }
+
     /// Set the maximum complexity a query can have. By default, there is no
     /// limit.
     #[must_use]
@@ -115,6 +226,25 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Set the maximum number of items of each list and sibling fields of
+    /// each object resolved concurrently. (default: no limit)
+    ///
+    /// The limit applies to every list and selection set separately, so
+    /// nested lists can resolve up to the product of their limits at once.
+    /// It can be lowered for the value of a field with the `max_concurrency`
+    /// attribute.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_concurrency` is zero.
+    #[must_use]
+    pub fn limit_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.concurrency_limit = Some(
+            NonZeroUsize::new(max_concurrency).expect("the concurrency limit must not be zero"),
+        );
+        self
+    }
+
     /// Set the maximum number of directives on a single field. (default: no
     /// limit)
     pub fn limit_directives(mut self, max_directives: usize) -> Self {
@@ -220,6 +350,24 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
         self
     }
 
+    /// Expose the constraints of the built-in validators as `@constraint`
+    /// directives on the arguments and input fields, in the SDL and in the
+    /// `appliedDirectives` field of the introspection.
+    ///
+    /// The `@constraint` directive is compatible with
+    /// [graphql-constraint-directive](https://github.com/confuser/graphql-constraint-directive),
+    /// the validators it cannot express are exported as `@validation`
+    /// directives.
+    #[must_use]
+    pub fn enable_constraint_directives(mut self) -> Self {
+        self.registry.constraint_directives = true;
+        self.registry
+            .add_directive(Registry::constraint_directive());
+        self.registry
+            .add_directive(Registry::validation_directive());
+        self
+    }
+
     /// Disable field suggestions.
     #[must_use]
     pub fn disable_suggestions(mut self) -> Self {
@@ -264,6 +412,10 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
             self.registry.create_federation_types();
         }
 
+        if let Some(contract) = self.contract {
+            self.registry.apply_contract(contract);
+        }
+
         Schema(Arc::new(SchemaInner {
             validation_mode: self.validation_mode,
             query: self.query,
@@ -278,6 +430,14 @@ impl<Query, Mutation, Subscription> SchemaBuilder<Query, Mutation, Subscription>
                 registry: self.registry,
                 data: self.data,
                 custom_directives: self.custom_directives,
+                #[cfg(feature = "introspection_cache")]
+                introspection_cache: self.introspection_cache.and_then(IntrospectionCache::new),
+                error_policy: self.error_policy,
+                deprecation_reporting: self.deprecation_reporting,
+                authorization: self.authorization,
+                strict_int: self.strict_int,
+                timer: self.timer,
+                concurrency_limit: self.concurrency_limit,
             })),
         }))
     }
@@ -288,6 +448,14 @@ pub struct SchemaEnvInner {
     pub registry: Registry,
     pub data: Data,
     pub custom_directives: HashMap<String, Box<dyn CustomDirectiveFactory>>,
+    #[cfg(feature = "introspection_cache")]
+    pub(crate) introspection_cache: Option<IntrospectionCache>,
+    pub(crate) error_policy: Option<Arc<dyn ErrorPolicy>>,
+    pub(crate) deprecation_reporting: Option<DeprecationReporting>,
+    pub(crate) authorization: Option<Authorization>,
+    pub(crate) strict_int: bool,
+    pub(crate) timer: Option<Arc<dyn Timer>>,
+    pub(crate) concurrency_limit: Option<NonZeroUsize>,
 }
 
 #[doc(hidden)]
@@ -391,6 +559,15 @@ where
             depth: None,
             recursive_depth: 32,
             max_directives: None,
+            #[cfg(feature = "introspection_cache")]
+            introspection_cache: None,
+            contract: None,
+            error_policy: None,
+            deprecation_reporting: None,
+            authorization: None,
+            strict_int: false,
+            timer: None,
+            concurrency_limit: None,
             extensions: Default::default(),
             custom_directives: Default::default(),
         }
@@ -413,10 +590,14 @@ where
                 Some(Subscription::type_name().to_string())
             },
             introspection_mode: IntrospectionMode::Enabled,
+            introspection_depth: None,
+            introspection_fields: None,
             enable_federation: false,
             federation_subscription: false,
             ignore_name_conflicts,
             enable_suggestions: true,
+            contract: None,
+            constraint_directives: false,
         };
         registry.add_system_types();
 
@@ -467,11 +648,16 @@ where
         self.0.env.registry.names()
     }
 
-    fn create_extensions(&self, session_data: Arc<Data>) -> Extensions {
+    fn create_extensions(
+        &self,
+        session_data: Arc<Data>,
+        cancellation_token: CancellationToken,
+    ) -> Extensions {
         Extensions::new(
             self.0.extensions.iter().map(|f| f.create()),
             self.0.env.clone(),
             session_data,
+            cancellation_token,
         )
     }
 
@@ -484,6 +670,8 @@ where
             schema_env: &self.0.env,
             query_env: &env,
             execute_data,
+            deadline: env.deadline,
+            concurrency_limit: None,
         };
 
         let res = match &env.operation.node.ty {
@@ -500,7 +688,8 @@ where
             OperationType::Subscription => Err(ServerError::new(
                 "Subscriptions are not supported on this transport.",
                 None,
-            )),
+            )
+            .with_code(ErrorCode::OperationResolutionFailure)),
         };
 
         let mut resp = match res {
@@ -511,13 +700,38 @@ where
 
         resp.errors
             .extend(std::mem::take(&mut *env.errors.lock().unwrap()));
+        apply_error_policy(self.0.env.error_policy.as_deref(), &mut resp.errors);
+        if let Some(deprecation_reporting) = &self.0.env.deprecation_reporting {
+            deprecation_reporting.report(env.extensions.deprecations(), &mut resp);
+        }
         resp
     }
 
+    /// Parses and validates a query without executing it, and returns its
+    /// complexity, depth, cache control, and the types and fields it
+    /// references.
+    ///
+    /// The complexity and depth limits are not checked, so that the cost of
+    /// a query exceeding them can still be reported.
+    ///
+    /// The query is validated against the schema after its contract is
+    /// applied. If only introspection is allowed by the schema or the
+    /// request, no types, fields or deprecations are reported, since the
+    /// other fields are not executed.
+    pub fn analyze(&self, request: impl Into<Request>) -> Result<QueryAnalysis, Vec<ServerError>> {
+        analyze_request(
+            request.into(),
+            &self.0.env.registry,
+            self.0.recursive_depth,
+            self.0.max_directives,
+        )
+    }
+
     /// Execute a GraphQL query.
     pub async fn execute(&self, request: impl Into<Request>) -> Response {
         let request = request.into();
-        let extensions = self.create_extensions(Default::default());
+        let extensions =
+            self.create_extensions(Default::default(), request.cancellation_token.clone());
         let request_fut = {
             let extensions = extensions.clone();
             async move {
@@ -531,6 +745,7 @@ where
                     self.0.max_directives,
                     self.0.complexity,
                     self.0.depth,
+                    self.0.env.deprecation_reporting.as_ref(),
                 )
                 .await
                 {
@@ -577,7 +792,8 @@ where
     ) -> BoxStream<'static, Response> {
         let schema = self.clone();
         let request = request.into();
-        let extensions = self.create_extensions(session_data.clone());
+        let extensions =
+            self.create_extensions(session_data.clone(), request.cancellation_token.clone());
 
         let stream = futures_util::stream::StreamExt::boxed({
             let extensions = extensions.clone();
@@ -593,6 +809,7 @@ where
                     schema.0.max_directives,
                     schema.0.complexity,
                     schema.0.depth,
+                    env.deprecation_reporting.as_ref(),
                 )
                 .await
                 {
@@ -642,11 +859,14 @@ where
                     collect_subscription_streams(&ctx, &schema.0.subscription, &mut streams)
                 };
                 if let Err(err) = collect_result {
-                    yielder.yield_item(Response::from_errors(vec![err])).await;
+                    let mut errors = vec![err];
+                    apply_error_policy(schema.0.env.error_policy.as_deref(), &mut errors);
+                    yielder.yield_item(Response::from_errors(errors)).await;
                 }
 
                 let mut stream = stream::select_all(streams);
-                while let Some(resp) = stream.next().await {
+                while let Some(mut resp) = stream.next().await {
+                    apply_error_policy(schema.0.env.error_policy.as_deref(), &mut resp.errors);
                     yielder.yield_item(resp).await;
                 }
             })
@@ -705,7 +925,8 @@ fn check_max_directives(doc: &ExecutableDocument, max_directives: usize) -> Serv
                                 field.node.name.node, limit_directives
                             ),
                             Some(field.pos),
-                        ));
+                        )
+                        .with_code(ErrorCode::GraphqlValidationFailed));
                     }
                     check_selection_set(doc, &field.node.selection_set, limit_directives)?;
                 }
@@ -750,7 +971,8 @@ fn check_recursive_depth(doc: &ExecutableDocument, max_depth: usize) -> ServerRe
                     max_depth
                 ),
                 Some(selection_set.pos),
-            ));
+            )
+            .with_code(ErrorCode::QueryTooDeep));
         }
 
         for selection in &selection_set.node.items {
@@ -857,6 +1079,7 @@ pub(crate) async fn prepare_request(
     max_directives: Option<usize>,
     complexity: Option<usize>,
     depth: Option<usize>,
+    deprecation_reporting: Option<&DeprecationReporting>,
 ) -> Result<(QueryEnv, CacheControl), Vec<ServerError>> {
     let mut request = extensions.prepare_request(request).await?;
     let query_data = Arc::new(std::mem::take(&mut request.data));
@@ -899,21 +1122,72 @@ pub(crate) async fn prepare_request(
         extensions.validation(&mut validation_fut).await?
     };
 
-    let operation = if let Some(operation_name) = &request.operation_name {
-        match document.operations {
+    if let Some(deprecation_reporting) = deprecation_reporting {
+        let deprecations = collect_deprecations(
+            registry,
+            &document,
+            Some(&request.variables),
+            request.operation_name.as_deref(),
+        );
+        deprecation_reporting.check(&deprecations)?;
+        extensions.attach_deprecations(deprecations);
+    }
+
+    let operation = take_operation(document.operations, request.operation_name.as_deref());
+    let (operation_name, mut operation) = operation.map_err(|err| vec![err])?;
+
+    // remove skipped fields
+    let mut condition_variables = request.variables.clone();
+    for variable_definition in &operation.node.variable_definitions {
+        if let Some(default_value) = &variable_definition.node.default_value {
+            condition_variables
+                .entry(variable_definition.node.name.node.clone())
+                .or_insert_with(|| default_value.node.clone());
+        }
+    }
+    for fragment in document.fragments.values_mut() {
+        remove_skipped_selection(&mut fragment.node.selection_set.node, &condition_variables);
+    }
+    remove_skipped_selection(&mut operation.node.selection_set.node, &condition_variables);
+
+    let env = QueryEnvInner {
+        extensions,
+        variables: request.variables,
+        operation_name,
+        operation,
+        fragments: document.fragments,
+        uploads: request.uploads,
+        upload_stream: request.upload_stream,
+        session_data,
+        query_data,
+        http_headers: Default::default(),
+        introspection_mode: request.introspection_mode,
+        errors: Default::default(),
+        deadline: request.deadline,
+    };
+    Ok((QueryEnv::new(env), validation_result.cache_control))
+}
+
+fn take_operation(
+    operations: DocumentOperations,
+    operation_name: Option<&str>,
+) -> ServerResult<(Option<String>, Positioned<OperationDefinition>)> {
+    if let Some(operation_name) = operation_name {
+        match operations {
             DocumentOperations::Single(_) => None,
             DocumentOperations::Multiple(mut operations) => operations
-                .remove(operation_name.as_str())
-                .map(|operation| (Some(operation_name.clone()), operation)),
+                .remove(operation_name)
+                .map(|operation| (Some(operation_name.to_string()), operation)),
         }
         .ok_or_else(|| {
             ServerError::new(
                 format!(r#"Unknown operation named "{}""#, operation_name),
                 None,
             )
+            .with_code(ErrorCode::OperationResolutionFailure)
         })
     } else {
-        match document.operations {
+        match operations {
             DocumentOperations::Single(operation) => Ok((None, operation)),
             DocumentOperations::Multiple(map) if map.len() == 1 => {
                 let (operation_name, operation) = map.into_iter().next().unwrap();
@@ -922,30 +1196,53 @@ pub(crate) async fn prepare_request(
             DocumentOperations::Multiple(_) => Err(ServerError::new(
                 "Operation name required in request.",
                 None,
-            )),
+            )
+            .with_code(ErrorCode::OperationResolutionFailure)),
         }
-    };
+    }
+}
 
-    let (operation_name, mut operation) = operation.map_err(|err| vec![err])?;
+pub(crate) fn analyze_request(
+    request: Request,
+    registry: &Registry,
+    recursive_depth: usize,
+    max_directives: Option<usize>,
+) -> Result<QueryAnalysis, Vec<ServerError>> {
+    let document = match request.parsed_query {
+        Some(document) => document,
+        None => parse_query(&request.query).map_err(|err| vec![err.into()])?,
+    };
+    check_recursive_depth(&document, recursive_depth)?;
+    if let Some(max_directives) = max_directives {
+        check_max_directives(&document, max_directives)?;
+    }
 
-    // remove skipped fields
-    for fragment in document.fragments.values_mut() {
-        remove_skipped_selection(&mut fragment.node.selection_set.node, &request.variables);
+    let (validation_result, mut usage) = analyze_document(
+        registry,
+        &document,
+        Some(&request.variables),
+        request.operation_name.as_deref(),
+    )?;
+    let (operation_name, operation) =
+        take_operation(document.operations, request.operation_name.as_deref())
+            .map_err(|err| vec![err])?;
+
+    // Only the introspection fields are executed in this mode, and they are
+    // not reported.
+    if registry.introspection_mode == IntrospectionMode::IntrospectionOnly
+        || request.introspection_mode == IntrospectionMode::IntrospectionOnly
+    {
+        usage = Default::default();
     }
-    remove_skipped_selection(&mut operation.node.selection_set.node, &request.variables);
 
-    let env = QueryEnvInner {
-        extensions,
-        variables: request.variables,
+    Ok(QueryAnalysis {
         operation_name,
-        operation,
-        fragments: document.fragments,
-        uploads: request.uploads,
-        session_data,
-        query_data,
-        http_headers: Default::default(),
-        introspection_mode: request.introspection_mode,
-        errors: Default::default(),
-    };
-    Ok((QueryEnv::new(env), validation_result.cache_control))
+        operation_type: operation.node.ty,
+        complexity: validation_result.complexity,
+        depth: validation_result.depth,
+        cache_control: validation_result.cache_control,
+        types: usage.types,
+        fields: usage.fields,
+        deprecations: usage.deprecations,
+    })
 }
@@ -4,7 +4,8 @@ use futures_util::stream::{Stream, StreamExt};
 
 use crate::{
     Context, ContextSelectionSet, PathSegment, Response, ServerError, ServerResult,
-    parser::types::Selection, registry, registry::Registry,
+    authorization::check_subscription_field_access, parser::types::Selection, registry,
+    registry::Registry,
 };
 
 /// A GraphQL subscription object
@@ -47,6 +48,10 @@ pub(crate) fn collect_subscription_streams<'a, T: SubscriptionType + 'static>(
                 asynk_strim::stream_fn(move |mut yielder| async move {
                     let ctx = ctx.with_field(field);
                     let field_name = ctx.item.node.response_key().node.clone();
+                    if let Err(err) = check_subscription_field_access(&ctx, &T::type_name()) {
+                        yielder.yield_item(Response::from_errors(vec![err])).await;
+                        return;
+                    }
                     let stream = root.create_field_stream(&ctx);
                     if let Some(mut stream) = stream {
                         while let Some(resp) = stream.next().await {
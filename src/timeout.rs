@@ -0,0 +1,90 @@
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use futures_util::future::{Either, select};
+
+use crate::{
+    Context, ErrorCode, ServerError, ServerResult, Value,
+    registry::{MetaField, MetaTypeName},
+    runtime::Timer,
+};
+
+/// Awaits `fut`, returning `None` if `deadline` passes first.
+///
+/// The deadline is not enforced without a timer.
+pub(crate) async fn run_until_deadline<F: Future>(
+    timer: Option<&dyn Timer>,
+    deadline: Option<Instant>,
+    fut: F,
+) -> Option<F::Output> {
+    let (Some(timer), Some(deadline)) = (timer, deadline) else {
+        return Some(fut.await);
+    };
+    let delay = timer.delay(deadline.saturating_duration_since(Instant::now()));
+    futures_util::pin_mut!(fut);
+    match select(fut, delay).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
+}
+
+/// Applies the timeout of the field of `ctx` on the object `parent_type` to
+/// the deadline of `ctx`.
+///
+/// Returns the field if its deadline must be enforced, that is for the root
+/// fields and for the fields whose timeout makes the deadline earlier. The
+/// other fields are already bounded by the deadline of a parent field.
+pub(crate) fn field_deadline<'a>(
+    ctx: &mut Context<'a>,
+    parent_type: &str,
+) -> Option<&'a MetaField> {
+    ctx.schema_env.timer.as_ref()?;
+    if ctx.is_for_introspection {
+        return None;
+    }
+    let field = ctx
+        .schema_env
+        .registry
+        .types
+        .get(parent_type)?
+        .field_by_name(ctx.item.node.name.node.as_str())?;
+    if let Some(deadline) = field.timeout.and_then(timeout_deadline)
+        && ctx.deadline.is_none_or(|d| deadline < d)
+    {
+        ctx.deadline = Some(deadline);
+        return Some(field);
+    }
+    let is_root = ctx.path_node.is_some_and(|node| node.parent.is_none());
+    ctx.deadline.filter(|_| is_root).map(|_| field)
+}
+
+fn timeout_deadline(timeout: Duration) -> Option<Instant> {
+    Instant::now().checked_add(timeout)
+}
+
+/// Resolves the value of `field` with `fut`, or a `TIMEOUT` error if the
+/// deadline of `ctx` passes first.
+///
+/// The error is propagated to the parent field if the field is non-null.
+pub(crate) async fn resolve_until_deadline(
+    ctx: &Context<'_>,
+    field: &MetaField,
+    fut: impl Future<Output = ServerResult<Value>>,
+) -> ServerResult<Value> {
+    if let Some(res) = run_until_deadline(ctx.schema_env.timer.as_deref(), ctx.deadline, fut).await
+    {
+        return res;
+    }
+
+    let err = ctx.set_error_path(
+        ServerError::new("Field timed out", Some(ctx.item.pos)).with_code(ErrorCode::Timeout),
+    );
+    if MetaTypeName::create(&field.ty).is_non_null() {
+        Err(err)
+    } else {
+        ctx.add_error(err);
+        Ok(Value::Null)
+    }
+}
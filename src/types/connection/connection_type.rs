@@ -2,12 +2,12 @@ use std::{borrow::Cow, marker::PhantomData};
 
 use super::{DisableNodesField, EnableNodesField, NodesFieldSwitcherSealed};
 use crate::{
-    Object, ObjectType, OutputType, TypeName,
+    Context, Object, ObjectType, OutputType, Result, TypeName,
     connection::{
         ConnectionNameType, DefaultConnectionName, DefaultEdgeName, EdgeNameType, PageInfo,
         edge::Edge,
     },
-    types::connection::{CursorType, EmptyFields},
+    types::connection::{CursorType, EmptyFields, cursor::encode_cursor},
 };
 
 /// Connection type
@@ -110,13 +110,21 @@ where
     EdgeName: EdgeNameType,
 {
     /// Information to aid in pagination.
-    async fn page_info(&self) -> PageInfo {
-        PageInfo {
+    async fn page_info(&self, ctx: &Context<'_>) -> Result<PageInfo> {
+        Ok(PageInfo {
             has_previous_page: self.has_previous_page,
             has_next_page: self.has_next_page,
-            start_cursor: self.edges.first().map(|edge| edge.cursor.encode_cursor()),
-            end_cursor: self.edges.last().map(|edge| edge.cursor.encode_cursor()),
-        }
+            start_cursor: self
+                .edges
+                .first()
+                .map(|edge| encode_cursor(&edge.cursor, ctx))
+                .transpose()?,
+            end_cursor: self
+                .edges
+                .last()
+                .map(|edge| encode_cursor(&edge.cursor, ctx))
+                .transpose()?,
+        })
     }
 
     /// A list of edges.
@@ -144,13 +152,21 @@ where
     EdgeName: EdgeNameType,
 {
     /// Information to aid in pagination.
-    async fn page_info(&self) -> PageInfo {
-        PageInfo {
+    async fn page_info(&self, ctx: &Context<'_>) -> Result<PageInfo> {
+        Ok(PageInfo {
             has_previous_page: self.has_previous_page,
             has_next_page: self.has_next_page,
-            start_cursor: self.edges.first().map(|edge| edge.cursor.encode_cursor()),
-            end_cursor: self.edges.last().map(|edge| edge.cursor.encode_cursor()),
-        }
+            start_cursor: self
+                .edges
+                .first()
+                .map(|edge| encode_cursor(&edge.cursor, ctx))
+                .transpose()?,
+            end_cursor: self
+                .edges
+                .last()
+                .map(|edge| encode_cursor(&edge.cursor, ctx))
+                .transpose()?,
+        })
     }
 
     /// A list of edges.
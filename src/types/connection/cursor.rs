@@ -9,14 +9,14 @@ use std::{
 
 use serde::{Serialize, de::DeserializeOwned};
 
-use crate::ID;
+use crate::{Context, Error, ErrorCode, ID, Result};
 
 /// Cursor type
 ///
 /// A custom scalar that serializes as a string.
 /// <https://relay.dev/graphql/connections.htm#sec-Cursor>
 pub trait CursorType: Sized {
-    /// Error type for `decode_cursor`.
+    /// Error type for decoding and encoding cursors.
     type Error: Display;
 
     /// Decode cursor from string.
@@ -24,6 +24,30 @@ pub trait CursorType: Sized {
 
     /// Encode cursor to string.
     fn encode_cursor(&self) -> String;
+
+    /// Decode cursor from string, with the context of the field.
+    ///
+    /// The default implementation calls [`CursorType::decode_cursor`].
+    fn decode_cursor_with_context(ctx: &Context<'_>, s: &str) -> Result<Self, Self::Error> {
+        let _ = ctx;
+        Self::decode_cursor(s)
+    }
+
+    /// Encode cursor to string, with the context of the field.
+    ///
+    /// The default implementation calls [`CursorType::encode_cursor`].
+    fn encode_cursor_with_context(&self, ctx: &Context<'_>) -> Result<String, Self::Error> {
+        let _ = ctx;
+        Ok(self.encode_cursor())
+    }
+}
+
+/// Encodes `cursor` with the context of the field, as a field error on
+/// failure.
+pub(crate) fn encode_cursor<C: CursorType>(cursor: &C, ctx: &Context<'_>) -> Result<String> {
+    cursor
+        .encode_cursor_with_context(ctx)
+        .map_err(|err| Error::new(err.to_string()).with_code(ErrorCode::InternalServerError))
 }
 
 macro_rules! cursor_type_int_impl {
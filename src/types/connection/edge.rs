@@ -1,9 +1,9 @@
 use std::{borrow::Cow, marker::PhantomData};
 
 use crate::{
-    ComplexObject, ObjectType, OutputType, SimpleObject, TypeName,
+    ComplexObject, Context, ObjectType, OutputType, Result, SimpleObject, TypeName,
     connection::{DefaultEdgeName, EmptyFields},
-    types::connection::{CursorType, EdgeNameType},
+    types::connection::{CursorType, EdgeNameType, cursor::encode_cursor},
 };
 
 /// An edge in a connection.
@@ -36,8 +36,8 @@ where
     Name: EdgeNameType,
 {
     /// A cursor for use in pagination
-    async fn cursor(&self) -> String {
-        self.cursor.encode_cursor()
+    async fn cursor(&self, ctx: &Context<'_>) -> Result<String> {
+        encode_cursor(&self.cursor, ctx)
     }
 }
 
@@ -0,0 +1,395 @@
+use std::{fmt::Display, future::Future};
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use super::{Connection, CursorType, Edge, EmptyFields, OpaqueCursor, parse_arguments};
+use crate::{Context, Error, Object, OutputType, Result};
+
+/// A cursor wrapping the key of a [`Paginator`].
+pub trait KeysetCursor: CursorType {
+    /// The key type, usually a tuple of the ordered columns.
+    type Key;
+
+    /// Create a cursor from a key.
+    fn from_key(key: Self::Key) -> Self;
+
+    /// Returns the key of the cursor.
+    fn into_key(self) -> Self::Key;
+}
+
+impl<T> KeysetCursor for OpaqueCursor<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    type Key = T;
+
+    fn from_key(key: Self::Key) -> Self {
+        OpaqueCursor(key)
+    }
+
+    fn into_key(self) -> Self::Key {
+        self.0
+    }
+}
+
+/// The order of the rows to load.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum KeysetOrder {
+    /// Ascending key order.
+    Ascending,
+    /// Descending key order.
+    Descending,
+}
+
+/// The rows to load, passed to the loader of a [`Paginator`].
+///
+/// With SQL, this is
+/// `WHERE key > after AND key < before ORDER BY key ASC|DESC LIMIT limit`,
+/// using a row value comparison such as `(created_at, id) > ($1, $2)` for
+/// multi-column keys.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct KeysetQuery<K> {
+    /// Only load the rows with a key greater than this one.
+    pub after: Option<K>,
+    /// Only load the rows with a key less than this one.
+    pub before: Option<K>,
+    /// The maximum number of rows to load.
+    pub limit: Option<usize>,
+    /// The order of the rows.
+    pub order: KeysetOrder,
+}
+
+/// The `totalCount` field added to a connection by
+/// [`Paginator::load_with_total_count`].
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TotalCount {
+    /// The total number of items, `None` if the field was not selected.
+    pub total_count: Option<usize>,
+}
+
+#[Object(internal)]
+impl TotalCount {
+    /// The total number of items.
+    async fn total_count(&self) -> usize {
+        self.total_count.unwrap_or_default()
+    }
+}
+
+/// Keyset pagination on top of the Relay arguments.
+///
+/// The paginator loads the rows with a loader closure taking a
+/// [`KeysetQuery`], and builds the cursors of the edges from the key of the
+/// nodes. It loads one more row than requested to compute `hasNextPage` with
+/// `first` and `hasPreviousPage` with `last`, and loads a single row before
+/// `after` (or after `before`) to compute `hasPreviousPage` (or
+/// `hasNextPage`).
+///
+/// The cursor type is usually an [`OpaqueCursor`], any [`KeysetCursor`] can be
+/// used instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::{connection::*, *};
+///
+/// #[derive(SimpleObject, Clone)]
+/// struct User {
+///     name: String,
+///     age: i32,
+/// }
+///
+/// fn users() -> Vec<User> {
+///     (0..10)
+///         .map(|i| User { name: format!("user{}", i), age: 20 + i / 2 })
+///         .collect()
+/// }
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn users(
+///         &self,
+///         after: Option<String>,
+///         before: Option<String>,
+///         first: Option<i32>,
+///         last: Option<i32>,
+///     ) -> Result<Connection<OpaqueCursor<(i32, String)>, User>> {
+///         Paginator::new(after, before, first, last)?
+///             .max_page_size(100)
+///             .load(
+///                 |user: &User| (user.age, user.name.clone()),
+///                 |query| async move {
+///                     // SELECT * FROM users WHERE (age, name) > $after AND (age, name) < $before
+///                     // ORDER BY age, name ASC|DESC LIMIT $limit
+///                     let mut users: Vec<User> = users()
+///                         .into_iter()
+///                         .filter(|user| {
+///                             let key = (user.age, user.name.clone());
+///                             query.after.as_ref().is_none_or(|after| &key > after)
+///                                 && query.before.as_ref().is_none_or(|before| &key < before)
+///                         })
+///                         .collect();
+///                     if query.order == KeysetOrder::Descending {
+///                         users.reverse();
+///                     }
+///                     users.truncate(query.limit.unwrap_or(usize::MAX));
+///                     Ok::<_, Error>(users)
+///                 },
+///             )
+///             .await
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+///
+/// let query = "{ users(first: 2) { nodes { name } pageInfo { hasPreviousPage hasNextPage } } }";
+/// assert_eq!(
+///     schema.execute(query).await.into_result().unwrap().data,
+///     value!({
+///         "users": {
+///             "nodes": [{ "name": "user0" }, { "name": "user1" }],
+///             "pageInfo": { "hasPreviousPage": false, "hasNextPage": true },
+///         }
+///     })
+/// );
+/// # });
+/// ```
+pub struct Paginator<C: KeysetCursor> {
+    after: Option<C::Key>,
+    before: Option<C::Key>,
+    first: Option<usize>,
+    last: Option<usize>,
+    max_page_size: Option<usize>,
+}
+
+impl<C> Paginator<C>
+where
+    C: KeysetCursor + Send + Sync,
+    C::Key: Clone,
+    <C as CursorType>::Error: Display + Send + Sync + 'static,
+{
+    /// Parses the pagination arguments.
+    pub fn new(
+        after: Option<String>,
+        before: Option<String>,
+        first: Option<i32>,
+        last: Option<i32>,
+    ) -> Result<Self> {
+        Self::parse(None, after, before, first, last)
+    }
+
+    /// Parses the pagination arguments, decoding the cursors with the
+    /// context of the field.
+    ///
+    /// This is required by the cursors reading their keys from the context,
+    /// such as `SignedCursor` and `EncryptedCursor`.
+    pub fn new_with_context(
+        ctx: &Context<'_>,
+        after: Option<String>,
+        before: Option<String>,
+        first: Option<i32>,
+        last: Option<i32>,
+    ) -> Result<Self> {
+        Self::parse(Some(ctx), after, before, first, last)
+    }
+
+    fn parse(
+        ctx: Option<&Context<'_>>,
+        after: Option<String>,
+        before: Option<String>,
+        first: Option<i32>,
+        last: Option<i32>,
+    ) -> Result<Self> {
+        let (after, before, first, last) = parse_arguments::<C>(ctx, after, before, first, last)?;
+        Ok(Self {
+            after: after.map(KeysetCursor::into_key),
+            before: before.map(KeysetCursor::into_key),
+            first,
+            last,
+            max_page_size: None,
+        })
+    }
+
+    /// Set the maximum page size, it is also the page size when neither
+    /// `first` nor `last` is specified.
+    #[must_use]
+    pub fn max_page_size(self, max_page_size: usize) -> Self {
+        Self {
+            max_page_size: Some(max_page_size),
+            ..self
+        }
+    }
+
+    /// Loads a page of nodes.
+    pub async fn load<Node, K, F, R, E>(self, key: K, loader: F) -> Result<Connection<C, Node>>
+    where
+        Node: OutputType,
+        K: Fn(&Node) -> C::Key,
+        F: Fn(KeysetQuery<C::Key>) -> R,
+        R: Future<Output = Result<Vec<Node>, E>>,
+        E: Into<Error>,
+    {
+        let (nodes, has_previous_page, has_next_page) = self.fetch(loader).await?;
+        Ok(make_connection(
+            nodes,
+            key,
+            has_previous_page,
+            has_next_page,
+            EmptyFields,
+        ))
+    }
+
+    /// Loads a page of nodes and adds a `totalCount` field to the
+    /// connection.
+    ///
+    /// The `count` closure is only called if the `totalCount` field is
+    /// selected.
+    pub async fn load_with_total_count<Node, K, F, R, E, CF, CR, CE>(
+        self,
+        ctx: &Context<'_>,
+        key: K,
+        loader: F,
+        count: CF,
+    ) -> Result<Connection<C, Node, TotalCount>>
+    where
+        Node: OutputType,
+        K: Fn(&Node) -> C::Key,
+        F: Fn(KeysetQuery<C::Key>) -> R,
+        R: Future<Output = Result<Vec<Node>, E>>,
+        E: Into<Error>,
+        CF: FnOnce() -> CR,
+        CR: Future<Output = Result<usize, CE>>,
+        CE: Into<Error>,
+    {
+        let total_count = if ctx.look_ahead().field("totalCount").exists() {
+            Some(count().await.map_err(Into::into)?)
+        } else {
+            None
+        };
+        let (nodes, has_previous_page, has_next_page) = self.fetch(loader).await?;
+        Ok(make_connection(
+            nodes,
+            key,
+            has_previous_page,
+            has_next_page,
+            TotalCount { total_count },
+        ))
+    }
+
+    async fn fetch<Node, F, R, E>(&self, loader: F) -> Result<(Vec<Node>, bool, bool)>
+    where
+        F: Fn(KeysetQuery<C::Key>) -> R,
+        R: Future<Output = Result<Vec<Node>, E>>,
+        E: Into<Error>,
+    {
+        for (name, size) in [("first", self.first), ("last", self.last)] {
+            if let (Some(size), Some(max_page_size)) = (size, self.max_page_size)
+                && size > max_page_size
+            {
+                return Err(Error::new(format!(
+                    "The \"{name}\" parameter must be less than or equal to {max_page_size}"
+                )));
+            }
+        }
+
+        if let (None, Some(last)) = (self.first, self.last) {
+            let mut nodes = loader(KeysetQuery {
+                after: self.after.clone(),
+                before: self.before.clone(),
+                limit: Some(last + 1),
+                order: KeysetOrder::Descending,
+            })
+            .await
+            .map_err(Into::into)?;
+            let has_previous_page = nodes.len() > last;
+            nodes.truncate(last);
+            nodes.reverse();
+            let has_next_page = match &self.before {
+                Some(before) => {
+                    exists(&loader, Some(before.clone()), None, KeysetOrder::Ascending).await?
+                }
+                None => false,
+            };
+            return Ok((nodes, has_previous_page, has_next_page));
+        }
+
+        let limit = self.first.or(self.max_page_size);
+        let mut nodes = loader(KeysetQuery {
+            after: self.after.clone(),
+            before: self.before.clone(),
+            limit: limit.map(|limit| limit + 1),
+            order: KeysetOrder::Ascending,
+        })
+        .await
+        .map_err(Into::into)?;
+        let mut has_next_page = false;
+        if let Some(limit) = limit {
+            has_next_page = nodes.len() > limit;
+            nodes.truncate(limit);
+        }
+        if let (false, None, Some(before)) = (has_next_page, self.first, &self.before) {
+            has_next_page =
+                exists(&loader, Some(before.clone()), None, KeysetOrder::Ascending).await?;
+        }
+        let has_previous_page = match (self.last, &self.after) {
+            (Some(last), _) => {
+                let has_previous_page = nodes.len() > last;
+                nodes.drain(..nodes.len().saturating_sub(last));
+                has_previous_page
+            }
+            (None, Some(after)) => {
+                exists(&loader, None, Some(after.clone()), KeysetOrder::Descending).await?
+            }
+            (None, None) => false,
+        };
+        Ok((nodes, has_previous_page, has_next_page))
+    }
+}
+
+/// Returns `true` if there is at least one row matching the bounds.
+async fn exists<K, Node, F, R, E>(
+    loader: &F,
+    after: Option<K>,
+    before: Option<K>,
+    order: KeysetOrder,
+) -> Result<bool>
+where
+    F: Fn(KeysetQuery<K>) -> R,
+    R: Future<Output = Result<Vec<Node>, E>>,
+    E: Into<Error>,
+{
+    let nodes = loader(KeysetQuery {
+        after,
+        before,
+        limit: Some(1),
+        order,
+    })
+    .await
+    .map_err(Into::into)?;
+    Ok(!nodes.is_empty())
+}
+
+fn make_connection<C, Node, K, ConnectionFields>(
+    nodes: Vec<Node>,
+    key: K,
+    has_previous_page: bool,
+    has_next_page: bool,
+    additional_fields: ConnectionFields,
+) -> Connection<C, Node, ConnectionFields>
+where
+    C: KeysetCursor + Send + Sync,
+    Node: OutputType,
+    K: Fn(&Node) -> C::Key,
+    ConnectionFields: crate::ObjectType,
+{
+    let mut connection =
+        Connection::with_additional_fields(has_previous_page, has_next_page, additional_fields);
+    connection.edges.extend(
+        nodes
+            .into_iter()
+            .map(|node| Edge::new(C::from_key(key(&node)), node)),
+    );
+    connection
+}
@@ -3,16 +3,22 @@
 mod connection_type;
 mod cursor;
 mod edge;
+mod keyset;
 mod page_info;
+#[cfg(feature = "secure_cursor")]
+mod secure_cursor;
 
 use std::{fmt::Display, future::Future};
 
 pub use connection_type::Connection;
 pub use cursor::{CursorType, OpaqueCursor};
 pub use edge::Edge;
+pub use keyset::{KeysetCursor, KeysetOrder, KeysetQuery, Paginator, TotalCount};
 pub use page_info::PageInfo;
+#[cfg(feature = "secure_cursor")]
+pub use secure_cursor::{CursorError, CursorKeys, EncryptedCursor, SignedCursor};
 
-use crate::{Error, ObjectType, OutputType, Result, SimpleObject};
+use crate::{Context, Error, ErrorCode, ObjectType, OutputType, Result, SimpleObject};
 
 /// Empty additional fields
 #[derive(Default, Copy, Clone, SimpleObject)]
@@ -351,6 +357,47 @@ where
     F: FnOnce(Option<Cursor>, Option<Cursor>, Option<usize>, Option<usize>) -> R,
     R: Future<Output = Result<T, E>>,
     E: Into<Error>,
+{
+    let (after, before, first, last) = parse_arguments(None, after, before, first, last)?;
+    f(after, before, first, last).await.map_err(Into::into)
+}
+
+/// Parses the parameters and executes the query, like [`query_with`], but
+/// decodes the cursors with the context of the field.
+///
+/// This is required by the cursors reading their keys from the context, such
+/// as `SignedCursor` and `EncryptedCursor`.
+pub async fn query_with_context<Cursor, T, F, R, E>(
+    ctx: &Context<'_>,
+    after: Option<String>,
+    before: Option<String>,
+    first: Option<i32>,
+    last: Option<i32>,
+    f: F,
+) -> Result<T>
+where
+    Cursor: CursorType + Send + Sync,
+    <Cursor as CursorType>::Error: Display + Send + Sync + 'static,
+    F: FnOnce(Option<Cursor>, Option<Cursor>, Option<usize>, Option<usize>) -> R,
+    R: Future<Output = Result<T, E>>,
+    E: Into<Error>,
+{
+    let (after, before, first, last) = parse_arguments(Some(ctx), after, before, first, last)?;
+    f(after, before, first, last).await.map_err(Into::into)
+}
+
+/// Validates the pagination arguments and decodes the cursors.
+#[allow(clippy::type_complexity)]
+fn parse_arguments<Cursor>(
+    ctx: Option<&Context<'_>>,
+    after: Option<String>,
+    before: Option<String>,
+    first: Option<i32>,
+    last: Option<i32>,
+) -> Result<(Option<Cursor>, Option<Cursor>, Option<usize>, Option<usize>)>
+where
+    Cursor: CursorType,
+    <Cursor as CursorType>::Error: Display + Send + Sync + 'static,
 {
     let first = match first {
         Some(first) if first < 0 => {
@@ -372,15 +419,15 @@ where
         None => None,
     };
 
-    let before = match before {
-        Some(before) => Some(Cursor::decode_cursor(&before).map_err(Error::new_with_source)?),
-        None => None,
-    };
-
-    let after = match after {
-        Some(after) => Some(Cursor::decode_cursor(&after).map_err(Error::new_with_source)?),
-        None => None,
+    let decode = |s: &str| {
+        match ctx {
+            Some(ctx) => Cursor::decode_cursor_with_context(ctx, s),
+            None => Cursor::decode_cursor(s),
+        }
+        .map_err(|err| Error::new_with_source(err).with_code(ErrorCode::BadUserInput))
     };
+    let before = before.as_deref().map(decode).transpose()?;
+    let after = after.as_deref().map(decode).transpose()?;
 
-    f(after, before, first, last).await.map_err(Into::into)
+    Ok((after, before, first, last))
 }
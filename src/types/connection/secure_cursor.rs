@@ -0,0 +1,337 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    ops::{Deref, DerefMut},
+};
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chacha20poly1305::{
+    XChaCha20Poly1305, XNonce,
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+};
+use hmac::{Hmac, Mac};
+use serde::{Serialize, de::DeserializeOwned};
+use sha2::Sha256;
+
+use super::{CursorType, KeysetCursor};
+use crate::Context;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const NONCE_SIZE: usize = 24;
+
+/// The secret keys of the [`SignedCursor`] and [`EncryptedCursor`] types,
+/// added to the schema data.
+///
+/// The current key signs and encrypts the new cursors. The previous keys are
+/// only used to decode the cursors, so that the keys can be rotated without
+/// invalidating the cursors held by the clients.
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "secure_cursor")))]
+pub struct CursorKeys {
+    keys: Vec<DerivedKeys>,
+}
+
+impl Debug for CursorKeys {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CursorKeys").finish_non_exhaustive()
+    }
+}
+
+impl CursorKeys {
+    /// Create the cursor keys with the current secret key.
+    pub fn new(secret: impl AsRef<[u8]>) -> Self {
+        Self {
+            keys: vec![DerivedKeys::new(secret.as_ref())],
+        }
+    }
+
+    /// Add a previous secret key, still accepted when decoding the cursors,
+    /// can be called several times.
+    #[must_use]
+    pub fn previous_key(mut self, secret: impl AsRef<[u8]>) -> Self {
+        self.keys.push(DerivedKeys::new(secret.as_ref()));
+        self
+    }
+
+    fn current(&self) -> &DerivedKeys {
+        &self.keys[0]
+    }
+}
+
+#[derive(Clone)]
+struct DerivedKeys {
+    signing: [u8; 32],
+    encryption: [u8; 32],
+}
+
+impl DerivedKeys {
+    fn new(secret: &[u8]) -> Self {
+        let derive = |label: &[u8]| -> [u8; 32] {
+            let mut mac =
+                <HmacSha256 as Mac>::new_from_slice(secret).expect("HMAC can take key of any size");
+            mac.update(label);
+            mac.finalize().into_bytes().into()
+        };
+        Self {
+            signing: derive(b"async-graphql cursor signing"),
+            encryption: derive(b"async-graphql cursor encryption"),
+        }
+    }
+
+    fn sign(&self, payload: &[u8]) -> HmacSha256 {
+        let mut mac = <HmacSha256 as Mac>::new_from_slice(&self.signing)
+            .expect("HMAC can take key of any size");
+        mac.update(payload);
+        mac
+    }
+
+    fn cipher(&self) -> XChaCha20Poly1305 {
+        XChaCha20Poly1305::new(&self.encryption.into())
+    }
+}
+
+/// An error when encoding or decoding a [`SignedCursor`] or an
+/// [`EncryptedCursor`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, thiserror::Error)]
+#[cfg_attr(docsrs, doc(cfg(feature = "secure_cursor")))]
+pub enum CursorError {
+    /// The cursor is malformed or has been tampered with.
+    #[error("Invalid cursor")]
+    Invalid,
+    /// The schema data does not contain the [`CursorKeys`].
+    #[error("The cursor keys are not configured")]
+    MissingKeys,
+    /// The cursor was decoded without the context.
+    #[error("The cursor must be decoded with the context")]
+    MissingContext,
+}
+
+fn cursor_keys<'a>(ctx: &Context<'a>) -> Result<&'a CursorKeys, CursorError> {
+    ctx.data_opt::<CursorKeys>().ok_or(CursorError::MissingKeys)
+}
+
+/// A cursor signed with HMAC-SHA256, using the [`CursorKeys`] from the
+/// context.
+///
+/// The value is serialized to JSON, it can be read by the clients but not
+/// modified. The cursors must be decoded with
+/// [`query_with_context`](super::query_with_context) or
+/// [`Paginator::new_with_context`](super::Paginator::new_with_context).
+///
+/// Encoding the cursor fails with [`CursorError::MissingKeys`] if the
+/// [`CursorKeys`] are not in the schema data. Without the context,
+/// [`CursorType::encode_cursor`] returns an empty string, which is never a
+/// valid cursor.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::{connection::*, *};
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn numbers(
+///         &self,
+///         ctx: &Context<'_>,
+///         after: Option<String>,
+///         first: Option<i32>,
+///     ) -> Result<Connection<SignedCursor<usize>, usize>> {
+///         query_with_context(ctx, after, None, first, None, |after, _, first, _| async move {
+///             let start = after.map(|after: SignedCursor<usize>| *after + 1).unwrap_or(0);
+///             let end = start + first.unwrap_or(10);
+///             let mut connection = Connection::new(start > 0, true);
+///             connection
+///                 .edges
+///                 .extend((start..end).map(|n| Edge::new(SignedCursor(n), n)));
+///             Ok::<_, Error>(connection)
+///         })
+///         .await
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+///     .data(CursorKeys::new("current secret").previous_key("previous secret"))
+///     .finish();
+///
+/// let resp = schema.execute(r#"{ numbers(after: "forged") { nodes } }"#).await;
+/// assert_eq!(resp.errors[0].message, "Invalid cursor");
+/// # });
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "secure_cursor")))]
+pub struct SignedCursor<T>(pub T);
+
+impl<T> Deref for SignedCursor<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for SignedCursor<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> CursorType for SignedCursor<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    type Error = CursorError;
+
+    fn decode_cursor(_s: &str) -> Result<Self, Self::Error> {
+        Err(CursorError::MissingContext)
+    }
+
+    fn encode_cursor(&self) -> String {
+        String::new()
+    }
+
+    fn decode_cursor_with_context(ctx: &Context<'_>, s: &str) -> Result<Self, Self::Error> {
+        let keys = cursor_keys(ctx)?;
+        let (payload, tag) = s.split_once('.').ok_or(CursorError::Invalid)?;
+        let payload = URL_SAFE_NO_PAD
+            .decode(payload)
+            .map_err(|_| CursorError::Invalid)?;
+        let tag = URL_SAFE_NO_PAD
+            .decode(tag)
+            .map_err(|_| CursorError::Invalid)?;
+        if !keys
+            .keys
+            .iter()
+            .any(|key| key.sign(&payload).verify_slice(&tag).is_ok())
+        {
+            return Err(CursorError::Invalid);
+        }
+        serde_json::from_slice(&payload)
+            .map(Self)
+            .map_err(|_| CursorError::Invalid)
+    }
+
+    fn encode_cursor_with_context(&self, ctx: &Context<'_>) -> Result<String, Self::Error> {
+        let keys = cursor_keys(ctx)?;
+        let payload = serde_json::to_vec(&self.0).unwrap_or_default();
+        let tag = keys.current().sign(&payload).finalize().into_bytes();
+        Ok(format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(&payload),
+            URL_SAFE_NO_PAD.encode(tag)
+        ))
+    }
+}
+
+impl<T> KeysetCursor for SignedCursor<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    type Key = T;
+
+    fn from_key(key: Self::Key) -> Self {
+        SignedCursor(key)
+    }
+
+    fn into_key(self) -> Self::Key {
+        self.0
+    }
+}
+
+/// A cursor encrypted with XChaCha20-Poly1305, using the [`CursorKeys`] from
+/// the context.
+///
+/// The value is serialized to JSON, it can neither be read nor modified by
+/// the clients. The cursors must be decoded with
+/// [`query_with_context`](super::query_with_context) or
+/// [`Paginator::new_with_context`](super::Paginator::new_with_context).
+///
+/// Encoding the cursor fails with [`CursorError::MissingKeys`] if the
+/// [`CursorKeys`] are not in the schema data. Without the context,
+/// [`CursorType::encode_cursor`] returns an empty string, which is never a
+/// valid cursor.
+#[cfg_attr(docsrs, doc(cfg(feature = "secure_cursor")))]
+pub struct EncryptedCursor<T>(pub T);
+
+impl<T> Deref for EncryptedCursor<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for EncryptedCursor<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> CursorType for EncryptedCursor<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    type Error = CursorError;
+
+    fn decode_cursor(_s: &str) -> Result<Self, Self::Error> {
+        Err(CursorError::MissingContext)
+    }
+
+    fn encode_cursor(&self) -> String {
+        String::new()
+    }
+
+    fn decode_cursor_with_context(ctx: &Context<'_>, s: &str) -> Result<Self, Self::Error> {
+        let keys = cursor_keys(ctx)?;
+        let data = URL_SAFE_NO_PAD
+            .decode(s)
+            .map_err(|_| CursorError::Invalid)?;
+        if data.len() < NONCE_SIZE {
+            return Err(CursorError::Invalid);
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_SIZE);
+        let nonce = XNonce::from_slice(nonce);
+        let payload = keys
+            .keys
+            .iter()
+            .find_map(|key| key.cipher().decrypt(nonce, ciphertext).ok())
+            .ok_or(CursorError::Invalid)?;
+        serde_json::from_slice(&payload)
+            .map(Self)
+            .map_err(|_| CursorError::Invalid)
+    }
+
+    fn encode_cursor_with_context(&self, ctx: &Context<'_>) -> Result<String, Self::Error> {
+        let keys = cursor_keys(ctx)?;
+        let payload = serde_json::to_vec(&self.0).unwrap_or_default();
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = keys
+            .current()
+            .cipher()
+            .encrypt(&nonce, payload.as_slice())
+            .expect("encrypting a cursor cannot fail");
+        let mut data = nonce.to_vec();
+        data.extend_from_slice(&ciphertext);
+        Ok(URL_SAFE_NO_PAD.encode(data))
+    }
+}
+
+impl<T> KeysetCursor for EncryptedCursor<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    type Key = T;
+
+    fn from_key(key: Self::Key) -> Self {
+        EncryptedCursor(key)
+    }
+
+    fn into_key(self) -> Self::Key {
+        self.0
+    }
+}
@@ -65,6 +65,7 @@ impl OutputType for EmptyMutation {
             rust_typename: Some(std::any::type_name::<Self>()),
             directive_invocations: Default::default(),
             requires_scopes: Default::default(),
+            authenticated: false,
         })
     }
 
@@ -1,4 +1,7 @@
-use crate::{InputValueError, InputValueResult, Number, Scalar, ScalarType, Value};
+use crate::{
+    InputValueError, InputValueResult, Number, Scalar, ScalarType, Value,
+    types::long::{is_valid_safe_integer, parse_safe_integer, safe_integer_value},
+};
 
 /// The `Int` scalar type represents non-fractional whole numeric values.
 #[Scalar(internal, name = "Int")]
@@ -54,7 +57,7 @@ impl ScalarType for i16 {
     }
 
     fn is_valid(value: &Value) -> bool {
-        matches!(value, Value::Number(n) if n.is_i64()  )
+        matches!(value, Value::Number(n) if n.is_i64())
     }
 
     fn to_value(&self) -> Value {
@@ -94,6 +97,7 @@ impl ScalarType for i32 {
 }
 
 /// The `Int` scalar type represents non-fractional whole numeric values.
+#[cfg(not(feature = "int64_as_long"))]
 #[Scalar(internal, name = "Int")]
 impl ScalarType for i64 {
     fn parse(value: Value) -> InputValueResult<Self> {
@@ -211,6 +215,7 @@ impl ScalarType for u32 {
 }
 
 /// The `Int` scalar type represents non-fractional whole numeric values.
+#[cfg(not(feature = "int64_as_long"))]
 #[Scalar(internal, name = "Int")]
 impl ScalarType for u64 {
     fn parse(value: Value) -> InputValueResult<Self> {
@@ -235,6 +240,7 @@ impl ScalarType for u64 {
 }
 
 /// The `Int` scalar type represents non-fractional whole numeric values.
+#[cfg(not(feature = "int64_as_long"))]
 #[Scalar(internal, name = "Int")]
 impl ScalarType for usize {
     fn parse(value: Value) -> InputValueResult<Self> {
@@ -266,6 +272,7 @@ impl ScalarType for usize {
 }
 
 /// The `Int` scalar type represents non-fractional whole numeric values.
+#[cfg(not(feature = "int64_as_long"))]
 #[Scalar(internal, name = "Int")]
 impl ScalarType for isize {
     fn parse(value: Value) -> InputValueResult<Self> {
@@ -295,3 +302,109 @@ impl ScalarType for isize {
         Value::Number(Number::from(*self as i64))
     }
 }
+
+/// The `Long` scalar type represents 64-bit integers, as strings when they
+/// exceed 2^53.
+#[cfg(feature = "int64_as_long")]
+#[Scalar(internal, name = "Long")]
+impl ScalarType for i64 {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        parse_safe_integer(value, |n| n)
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        is_valid_safe_integer::<Self>(value)
+    }
+
+    fn to_value(&self) -> Value {
+        safe_integer_value(*self)
+    }
+}
+
+/// The `Long` scalar type represents 64-bit integers, as strings when they
+/// exceed 2^53.
+#[cfg(feature = "int64_as_long")]
+#[Scalar(internal, name = "Long")]
+impl ScalarType for u64 {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        parse_safe_integer(value, |n| n)
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        is_valid_safe_integer::<Self>(value)
+    }
+
+    fn to_value(&self) -> Value {
+        safe_integer_value(*self)
+    }
+}
+
+/// The `Long` scalar type represents 64-bit integers, as strings when they
+/// exceed 2^53.
+#[cfg(feature = "int64_as_long")]
+#[Scalar(internal, name = "Long")]
+impl ScalarType for isize {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        parse_safe_integer(value, |n| n)
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        is_valid_safe_integer::<Self>(value)
+    }
+
+    fn to_value(&self) -> Value {
+        safe_integer_value(*self)
+    }
+}
+
+/// The `Long` scalar type represents 64-bit integers, as strings when they
+/// exceed 2^53.
+#[cfg(feature = "int64_as_long")]
+#[Scalar(internal, name = "Long")]
+impl ScalarType for usize {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        parse_safe_integer(value, |n| n)
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        is_valid_safe_integer::<Self>(value)
+    }
+
+    fn to_value(&self) -> Value {
+        safe_integer_value(*self)
+    }
+}
+
+/// The `BigInt` scalar type represents 128-bit integers, as strings when they
+/// exceed 2^53.
+#[Scalar(internal, name = "BigInt")]
+impl ScalarType for i128 {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        parse_safe_integer(value, |n| n)
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        is_valid_safe_integer::<Self>(value)
+    }
+
+    fn to_value(&self) -> Value {
+        safe_integer_value(*self)
+    }
+}
+
+/// The `BigInt` scalar type represents 128-bit integers, as strings when they
+/// exceed 2^53.
+#[Scalar(internal, name = "BigInt")]
+impl ScalarType for u128 {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        parse_safe_integer(value, |n| n)
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        is_valid_safe_integer::<Self>(value)
+    }
+
+    fn to_value(&self) -> Value {
+        safe_integer_value(*self)
+    }
+}
@@ -41,6 +41,7 @@ where
             specified_by_url: None,
             directive_invocations: Default::default(),
             requires_scopes: Default::default(),
+            authenticated: false,
         })
     }
 
@@ -101,6 +102,7 @@ where
             specified_by_url: None,
             directive_invocations: Default::default(),
             requires_scopes: Default::default(),
+            authenticated: false,
         })
     }
 
@@ -0,0 +1,224 @@
+use std::{fmt::Display, str::FromStr};
+
+use num_traits::Bounded;
+use serde::{Deserialize, Serialize};
+
+use crate::{InputType, InputValueError, InputValueResult, Number, Scalar, ScalarType, Value};
+
+/// The largest integer represented exactly by a JavaScript number, `2^53 - 1`.
+const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+/// An integer type which can be exposed as the `Long` or `BigInt` scalars.
+pub trait SafeInteger:
+    Copy
+    + Display
+    + FromStr
+    + Bounded
+    + TryFrom<i64>
+    + TryFrom<u64>
+    + TryInto<i64>
+    + Send
+    + Sync
+    + 'static
+{
+}
+
+impl SafeInteger for i64 {}
+impl SafeInteger for u64 {}
+impl SafeInteger for isize {}
+impl SafeInteger for usize {}
+impl SafeInteger for i128 {}
+impl SafeInteger for u128 {}
+
+/// A 64-bit integer type which can be exposed as the [`Long`] scalar.
+///
+/// The 128-bit integers are exposed as the `BigInt` scalar instead.
+pub trait LongInteger: SafeInteger {}
+
+impl LongInteger for i64 {}
+impl LongInteger for u64 {}
+impl LongInteger for isize {}
+impl LongInteger for usize {}
+
+/// Parses an integer from a number, or from a string for the values which are
+/// not safe JavaScript integers.
+pub(crate) fn parse_safe_integer<T: SafeInteger, R: InputType>(
+    value: Value,
+    f: impl FnOnce(T) -> R,
+) -> InputValueResult<R> {
+    let n = match &value {
+        Value::Number(n) => match (n.as_i64(), n.as_u64()) {
+            (Some(n), _) => T::try_from(n).ok(),
+            (None, Some(n)) => T::try_from(n).ok(),
+            (None, None) => return Err(InputValueError::from("Invalid number")),
+        },
+        Value::String(s) => Some(
+            s.parse::<T>()
+                .map_err(|_| InputValueError::from("Invalid number"))?,
+        ),
+        _ => return Err(InputValueError::expected_type(value)),
+    };
+    n.map(f).ok_or_else(|| {
+        InputValueError::from(format!(
+            "Only integers from {} to {} are accepted.",
+            T::min_value(),
+            T::max_value()
+        ))
+    })
+}
+
+pub(crate) fn is_valid_safe_integer<T: SafeInteger>(value: &Value) -> bool {
+    match value {
+        Value::Number(n) => n.is_i64() || n.is_u64(),
+        Value::String(s) => s.parse::<T>().is_ok(),
+        _ => false,
+    }
+}
+
+/// Converts an integer to a number if it is a safe JavaScript integer,
+/// otherwise to a string.
+pub(crate) fn safe_integer_value<T: SafeInteger>(n: T) -> Value {
+    match n.try_into() {
+        Ok(n) if (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&n) => {
+            Value::Number(Number::from(n))
+        }
+        _ => Value::String(n.to_string()),
+    }
+}
+
+/// A 64-bit integer exposed as the `Long` scalar.
+///
+/// The values between `-(2^53 - 1)` and `2^53 - 1` are serialized as numbers,
+/// and the larger values as strings, so that they are not rounded by the
+/// JavaScript clients. Both numbers and strings are accepted as input.
+///
+/// Use the `int64_as_long` feature to expose every `i64`, `u64`, `isize` and
+/// `usize` as `Long`.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn id(&self) -> Long {
+///         Long(i64::MAX)
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+/// assert_eq!(
+///     schema.execute("{ id }").await.into_result().unwrap().data,
+///     value!({ "id": "9223372036854775807" })
+/// );
+/// # });
+/// ```
+#[derive(
+    Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Long<T = i64>(pub T);
+
+impl<T> From<T> for Long<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+/// The `Long` scalar type represents 64-bit integers, as strings when they
+/// exceed 2^53.
+#[Scalar(internal, name = "Long")]
+impl<T: LongInteger> ScalarType for Long<T> {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        parse_safe_integer(value, Long)
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        is_valid_safe_integer::<T>(value)
+    }
+
+    fn to_value(&self) -> Value {
+        safe_integer_value(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[tokio::test]
+    async fn test_long() {
+        struct Query;
+
+        #[Object(internal)]
+        impl Query {
+            async fn value(&self, n: Long) -> Long {
+                n
+            }
+
+            async fn unsigned(&self, n: Long<u64>) -> Long<u64> {
+                n
+            }
+
+            async fn big(&self, n: i128) -> i128 {
+                n
+            }
+        }
+
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        assert_eq!(
+            schema
+                .execute(
+                    r#"{
+                        value1: value(n: 100)
+                        value2: value(n: -9007199254740991)
+                        value3: value(n: "9007199254740992")
+                        value4: value(n: -9223372036854775808)
+                        unsigned(n: 18446744073709551615)
+                        big(n: "-170141183460469231731687303715884105728")
+                    }"#
+                )
+                .await
+                .into_result()
+                .unwrap()
+                .data,
+            value!({
+                "value1": 100,
+                "value2": -9007199254740991i64,
+                "value3": "9007199254740992",
+                "value4": "-9223372036854775808",
+                "unsigned": "18446744073709551615",
+                "big": "-170141183460469231731687303715884105728",
+            })
+        );
+
+        assert_eq!(
+            schema
+                .execute(r#"{ value(n: "abc") }"#)
+                .await
+                .into_result()
+                .unwrap_err()[0]
+                .message,
+            r#"Invalid value for argument "n", expected type "Long""#
+        );
+        assert_eq!(
+            schema
+                .execute("{ unsigned(n: -1) }")
+                .await
+                .into_result()
+                .unwrap_err()[0]
+                .message,
+            r#"Failed to parse "Long": Only integers from 0 to 18446744073709551615 are accepted."#
+        );
+        assert!(
+            schema
+                .sdl()
+                .contains("The `Long` scalar type represents 64-bit integers")
+        );
+    }
+}
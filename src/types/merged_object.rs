@@ -34,6 +34,16 @@ where
             Err(err) => Err(err),
         }
     }
+
+    async fn find_entities(
+        &self,
+        ctx: &Context<'_>,
+        representations: &[Value],
+        entities: &mut [Option<ServerResult<Value>>],
+    ) {
+        self.0.find_entities(ctx, representations, entities).await;
+        self.1.find_entities(ctx, representations, entities).await;
+    }
 }
 
 #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
@@ -88,6 +98,7 @@ where
                 rust_typename: Some(std::any::type_name::<Self>()),
                 directive_invocations: Default::default(),
                 requires_scopes: Default::default(),
+                authenticated: false,
             }
         })
     }
@@ -152,6 +163,7 @@ where
                 rust_typename: Some(std::any::type_name::<Self>()),
                 directive_invocations: Default::default(),
                 requires_scopes: Default::default(),
+                authenticated: false,
             }
         })
     }
@@ -192,6 +204,7 @@ impl SubscriptionType for MergedObjectTail {
             rust_typename: Some(std::any::type_name::<Self>()),
             directive_invocations: Default::default(),
             requires_scopes: Default::default(),
+            authenticated: false,
         })
     }
 
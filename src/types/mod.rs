@@ -7,6 +7,7 @@ mod empty_mutation;
 mod empty_subscription;
 mod id;
 mod json;
+mod long;
 mod maybe_undefined;
 mod merged_object;
 mod query_root;
@@ -21,9 +22,10 @@ pub use empty_mutation::EmptyMutation;
 pub use empty_subscription::EmptySubscription;
 pub use id::ID;
 pub use json::Json;
+pub use long::{Long, LongInteger, SafeInteger};
 pub use maybe_undefined::MaybeUndefined;
 pub use merged_object::{MergedObject, MergedObjectTail};
 pub(crate) use query_root::QueryRoot;
 #[cfg(feature = "string_number")]
 pub use string_number::StringNumber;
-pub use upload::{Upload, UploadValue};
+pub use upload::{Upload, UploadStream, UploadValue};
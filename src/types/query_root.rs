@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use crate::{
     Any, Context, ContextSelectionSet, ObjectType, OutputType, Positioned, ServerError,
     ServerResult, SimpleObject, Value,
-    model::{__Schema, __Type},
+    model::{__Type, resolve_schema},
     parser::types::Field,
     registry::{self, SDLExportOptions},
     resolver_utils::{ContainerType, resolve_container},
@@ -32,16 +32,7 @@ impl<T: ObjectType> ContainerType for QueryRoot<T> {
             IntrospectionMode::Enabled | IntrospectionMode::IntrospectionOnly,
         ) {
             if ctx.item.node.name.node == "__schema" {
-                let mut ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
-                ctx_obj.is_for_introspection = true;
-                let visible_types = ctx.schema_env.registry.find_visible_types(ctx);
-                return OutputType::resolve(
-                    &__Schema::new(&ctx.schema_env.registry, &visible_types),
-                    &ctx_obj,
-                    ctx.item,
-                )
-                .await
-                .map(Some);
+                return resolve_schema(ctx).await.map(Some);
             } else if ctx.item.node.name.node == "__type" {
                 let (_, type_name) = ctx.param_value::<String>("name", None)?;
                 let mut ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
@@ -71,14 +62,31 @@ impl<T: ObjectType> ContainerType for QueryRoot<T> {
         if ctx.schema_env.registry.enable_federation || ctx.schema_env.registry.has_entities() {
             if ctx.item.node.name.node == "_entities" {
                 let (_, representations) = ctx.param_value::<Vec<Any>>("representations", None)?;
-                let res = futures_util::future::try_join_all(representations.iter().map(
-                    |item| async move {
-                        self.inner.find_entity(ctx, &item.0).await?.ok_or_else(|| {
-                            ServerError::new("Entity not found.", Some(ctx.item.pos))
-                        })
-                    },
-                ))
-                .await?;
+                let representations = representations
+                    .into_iter()
+                    .map(|item| item.0)
+                    .collect::<Vec<_>>();
+                let mut entities = representations.iter().map(|_| None).collect::<Vec<_>>();
+                self.inner
+                    .find_entities(ctx, &representations, &mut entities)
+                    .await;
+                let res =
+                    futures_util::future::try_join_all(representations.iter().zip(entities).map(
+                        |(item, entity)| async move {
+                            match entity {
+                                // Batch entity resolvers report errors per representation.
+                                Some(Ok(value)) => Ok(value),
+                                Some(Err(err)) => {
+                                    ctx.add_error(err);
+                                    Ok(Value::Null)
+                                }
+                                None => self.inner.find_entity(ctx, item).await?.ok_or_else(|| {
+                                    ServerError::new("Entity not found.", Some(ctx.item.pos))
+                                }),
+                            }
+                        },
+                    ))
+                    .await?;
                 return Ok(Some(Value::List(res)));
             } else if ctx.item.node.name.node == "_service" {
                 let mut ctx_obj = ctx.with_selection_set(&ctx.item.node.selection_set);
@@ -1,6 +1,14 @@
-use std::{borrow::Cow, ops::Deref, sync::Arc};
+use std::{
+    borrow::Cow,
+    io,
+    ops::Deref,
+    pin::Pin,
+    sync::Arc,
+    task::{Context as TaskContext, Poll},
+};
 
-use futures_util::AsyncRead;
+use bytes::Bytes;
+use futures_util::{AsyncRead, Stream, StreamExt, TryStreamExt, stream::BoxStream};
 
 use crate::{
     Context, InputType, InputValueError, InputValueResult, Value, registry, registry::MetaTypeId,
@@ -74,6 +82,32 @@ impl UploadValue {
     }
 }
 
+/// A file upload read from the request body.
+///
+/// This is a stream of the chunks of the file, see [`Upload::stream`].
+pub struct UploadStream {
+    /// The name of the file.
+    pub filename: String,
+    /// The content type of the file.
+    pub content_type: Option<String>,
+    pub(crate) content: BoxStream<'static, io::Result<Bytes>>,
+}
+
+impl UploadStream {
+    /// Convert to a `AsyncRead`.
+    pub fn into_async_read(self) -> impl AsyncRead + Send + 'static {
+        self.content.into_async_read()
+    }
+}
+
+impl Stream for UploadStream {
+    type Item = io::Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Option<Self::Item>> {
+        self.content.poll_next_unpin(cx)
+    }
+}
+
 /// Uploaded file
 ///
 /// **Reference:** <https://github.com/jaydenseric/graphql-multipart-request-spec>
@@ -113,14 +147,70 @@ impl UploadValue {
 /// --form 'map={ "0": ["variables.file"] }' \
 /// --form '0=@myFile.txt'
 /// ```
+///
+/// # Streaming
+///
+/// By default the files are stored before the request is executed. With
+/// [`MultipartOptions::stream_uploads`](crate::http::MultipartOptions::stream_uploads),
+/// they are read from the request body by [`Upload::stream`] instead, one at a
+/// time and in the order of the `map` part.
+///
+/// ```
+/// use async_graphql::{futures_util::TryStreamExt, *};
+///
+/// struct Mutation;
+///
+/// #[Object]
+/// impl Mutation {
+///     async fn upload(&self, ctx: &Context<'_>, files: Vec<Upload>) -> Result<usize> {
+///         let mut size = 0;
+///         for file in files {
+///             let mut stream = file.stream(ctx).await?;
+///             while let Some(chunk) = stream.try_next().await? {
+///                 size += chunk.len();
+///             }
+///         }
+///         Ok(size)
+///     }
+/// }
+/// ```
 #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct Upload(pub usize);
 
 impl Upload {
     /// Get the upload value.
+    ///
+    /// Fails if the uploads are streamed, see
+    /// [`MultipartOptions::stream_uploads`](crate::http::MultipartOptions::stream_uploads).
     pub fn value(&self, ctx: &Context<'_>) -> std::io::Result<UploadValue> {
+        if ctx.query_env.upload_stream.is_some() {
+            return Err(io::Error::other(
+                "the uploads are streamed, use `Upload::stream` to read them",
+            ));
+        }
         ctx.query_env.uploads[self.0].try_clone()
     }
+
+    /// Get a stream of the upload content.
+    ///
+    /// If the uploads are streamed, the content is read from the request body,
+    /// so the uploads must be read one at a time and in the order of the `map`
+    /// part of the request. Otherwise this reads the stored upload value.
+    pub async fn stream(&self, ctx: &Context<'_>) -> std::io::Result<UploadStream> {
+        match &ctx.query_env.upload_stream {
+            Some(uploads) => uploads.open(self.0).await,
+            None => {
+                let value = self.value(ctx)?;
+                Ok(UploadStream {
+                    filename: value.filename.clone(),
+                    content_type: value.content_type.clone(),
+                    content: crate::http::ReaderStream::new(value.into_async_read())
+                        .map_ok(Bytes::from)
+                        .boxed(),
+                })
+            }
+        }
+    }
 }
 
 impl Deref for Upload {
@@ -151,6 +241,7 @@ impl InputType for Upload {
             ),
             directive_invocations: Default::default(),
             requires_scopes: Default::default(),
+            authenticated: false,
         })
     }
 
@@ -8,11 +8,15 @@ mod utils;
 mod visitor;
 mod visitors;
 
+use std::collections::BTreeSet;
+
 pub use visitor::VisitorContext;
 use visitor::{VisitorNil, visit};
 
 use crate::{
-    CacheControl, ServerError, Variables, parser::types::ExecutableDocument, registry::Registry,
+    CacheControl, ErrorCode, Pos, ServerError, Variables,
+    parser::types::{ExecutableDocument, OperationType},
+    registry::Registry,
 };
 
 /// Validation results.
@@ -28,6 +32,42 @@ pub struct ValidationResult {
     pub depth: usize,
 }
 
+/// The usage of a deprecated element in an operation.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DeprecatedUsage {
+    /// The schema coordinate of the deprecated element, such as `Type.field`.
+    pub coordinate: String,
+    /// The reason of the deprecation.
+    pub reason: Option<String>,
+    /// The position of the usage in the query.
+    pub pos: Pos,
+}
+
+/// The analysis of an operation, returned by
+/// [`Schema::analyze`](crate::Schema::analyze).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct QueryAnalysis {
+    /// The name of the operation.
+    pub operation_name: Option<String>,
+    /// The type of the operation.
+    pub operation_type: OperationType,
+    /// Query complexity
+    pub complexity: usize,
+    /// Query depth
+    pub depth: usize,
+    /// Cache control
+    pub cache_control: CacheControl,
+    /// The names of the types referenced by the operation, including the
+    /// types of its arguments, variables and fragment type conditions.
+    /// Introspection types are not included.
+    pub types: BTreeSet<String>,
+    /// The fields referenced by the operation, as `Type.field` coordinates.
+    pub fields: BTreeSet<String>,
+    /// The deprecated fields used by the operation.
+    pub deprecations: Vec<DeprecatedUsage>,
+}
+
 /// Validation mode
 #[derive(Copy, Clone, Debug)]
 pub enum ValidationMode {
@@ -51,6 +91,8 @@ pub(crate) fn check_rules(
     let mut cache_control = CacheControl::default();
     let mut complexity = 0;
     let mut depth = 0;
+    let mut introspection_depth = 0;
+    let mut introspection_fields = 0;
 
     let mut ctx = VisitorContext::new(registry, doc, variables, operation_name);
     let errors = match mode {
@@ -85,7 +127,11 @@ pub(crate) fn check_rules(
                     cache_control: &mut cache_control,
                 })
                 .with(visitors::ComplexityCalculate::new(&mut complexity))
-                .with(visitors::DepthCalculate::new(&mut depth));
+                .with(visitors::DepthCalculate::new(&mut depth))
+                .with(visitors::IntrospectionCalculate::new(
+                    &mut introspection_depth,
+                    &mut introspection_fields,
+                ));
             visit(&mut visitor, &mut ctx, doc);
             ctx.errors
         }
@@ -97,7 +143,11 @@ pub(crate) fn check_rules(
                     cache_control: &mut cache_control,
                 })
                 .with(visitors::ComplexityCalculate::new(&mut complexity))
-                .with(visitors::DepthCalculate::new(&mut depth));
+                .with(visitors::DepthCalculate::new(&mut depth))
+                .with(visitors::IntrospectionCalculate::new(
+                    &mut introspection_depth,
+                    &mut introspection_fields,
+                ));
             visit(&mut visitor, &mut ctx, doc);
             ctx.errors
         }
@@ -107,13 +157,35 @@ pub(crate) fn check_rules(
     if let Some(limit_complexity) = limit_complexity
         && complexity > limit_complexity
     {
-        return Err(vec![ServerError::new("Query is too complex.", None)]);
+        return Err(vec![
+            ServerError::new("Query is too complex.", None).with_code(ErrorCode::QueryTooComplex),
+        ]);
     }
 
     if let Some(limit_depth) = limit_depth
         && depth > limit_depth
     {
-        return Err(vec![ServerError::new("Query is nested too deep.", None)]);
+        return Err(vec![
+            ServerError::new("Query is nested too deep.", None).with_code(ErrorCode::QueryTooDeep),
+        ]);
+    }
+
+    if let Some(limit_introspection_depth) = registry.introspection_depth
+        && introspection_depth > limit_introspection_depth
+    {
+        return Err(vec![
+            ServerError::new("Introspection query is nested too deep.", None)
+                .with_code(ErrorCode::QueryTooDeep),
+        ]);
+    }
+
+    if let Some(limit_introspection_fields) = registry.introspection_fields
+        && introspection_fields > limit_introspection_fields
+    {
+        return Err(vec![
+            ServerError::new("Too many introspection fields in the operation.", None)
+                .with_code(ErrorCode::QueryTooComplex),
+        ]);
     }
 
     if !errors.is_empty() {
@@ -126,3 +198,40 @@ pub(crate) fn check_rules(
         depth,
     })
 }
+
+/// Collects the deprecated elements used by the selected operation.
+pub(crate) fn collect_deprecations(
+    registry: &Registry,
+    doc: &ExecutableDocument,
+    variables: Option<&Variables>,
+    operation_name: Option<&str>,
+) -> Vec<DeprecatedUsage> {
+    let mut usage = visitors::Usage::default();
+    let mut ctx = VisitorContext::new(registry, doc, variables, operation_name);
+    visit(&mut visitors::UsageCollect::new(&mut usage), &mut ctx, doc);
+    usage.deprecations
+}
+
+/// Validates the document without limits, and collects the usage of the
+/// selected operation.
+pub(crate) fn analyze_document(
+    registry: &Registry,
+    doc: &ExecutableDocument,
+    variables: Option<&Variables>,
+    operation_name: Option<&str>,
+) -> Result<(ValidationResult, visitors::Usage), Vec<ServerError>> {
+    let validation_result = check_rules(
+        registry,
+        doc,
+        variables,
+        operation_name,
+        ValidationMode::Strict,
+        None,
+        None,
+    )?;
+
+    let mut usage = visitors::Usage::default();
+    let mut ctx = VisitorContext::new(registry, doc, variables, operation_name);
+    visit(&mut visitors::UsageCollect::new(&mut usage), &mut ctx, doc);
+    Ok((validation_result, usage))
+}
@@ -19,6 +19,10 @@ pub struct ArgumentsOfCorrectType<'a> {
 }
 
 impl<'a> Visitor<'a> for ArgumentsOfCorrectType<'a> {
+    fn name(&self) -> Option<&'static str> {
+        Some("ArgumentsOfCorrectType")
+    }
+
     fn enter_operation_definition(
         &mut self,
         ctx: &mut VisitorContext<'a>,
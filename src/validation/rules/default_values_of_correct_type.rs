@@ -13,6 +13,10 @@ use crate::{
 pub struct DefaultValuesOfCorrectType;
 
 impl<'a> Visitor<'a> for DefaultValuesOfCorrectType {
+    fn name(&self) -> Option<&'static str> {
+        Some("DefaultValuesOfCorrectType")
+    }
+
     fn enter_variable_definition(
         &mut self,
         ctx: &mut VisitorContext<'a>,
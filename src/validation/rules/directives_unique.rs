@@ -13,6 +13,10 @@ use crate::{
 pub struct DirectivesUnique;
 
 impl<'a> Visitor<'a> for DirectivesUnique {
+    fn name(&self) -> Option<&'static str> {
+        Some("DirectivesUnique")
+    }
+
     fn enter_operation_definition(
         &mut self,
         ctx: &mut VisitorContext<'a>,
@@ -12,6 +12,10 @@ use crate::{
 pub struct FieldsOnCorrectType;
 
 impl<'a> Visitor<'a> for FieldsOnCorrectType {
+    fn name(&self) -> Option<&'static str> {
+        Some("FieldsOnCorrectType")
+    }
+
     fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
         if let Some(parent_type) = ctx.parent_type() {
             if let Some(registry::MetaType::Union { .. })
@@ -8,6 +8,10 @@ use crate::{
 pub struct FragmentsOnCompositeTypes;
 
 impl<'a> Visitor<'a> for FragmentsOnCompositeTypes {
+    fn name(&self) -> Option<&'static str> {
+        Some("FragmentsOnCompositeTypes")
+    }
+
     fn enter_fragment_definition(
         &mut self,
         ctx: &mut VisitorContext<'a>,
@@ -39,6 +39,10 @@ impl KnownArgumentNames<'_> {
 }
 
 impl<'a> Visitor<'a> for KnownArgumentNames<'a> {
+    fn name(&self) -> Option<&'static str> {
+        Some("KnownArgumentNames")
+    }
+
     fn enter_directive(
         &mut self,
         ctx: &mut VisitorContext<'a>,
@@ -14,6 +14,10 @@ pub struct KnownDirectives {
 }
 
 impl<'a> Visitor<'a> for KnownDirectives {
+    fn name(&self) -> Option<&'static str> {
+        Some("KnownDirectives")
+    }
+
     fn enter_operation_definition(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
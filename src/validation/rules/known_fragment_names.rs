@@ -8,6 +8,10 @@ use crate::{
 pub struct KnownFragmentNames;
 
 impl<'a> Visitor<'a> for KnownFragmentNames {
+    fn name(&self) -> Option<&'static str> {
+        Some("KnownFragmentNames")
+    }
+
     fn enter_fragment_spread(
         &mut self,
         ctx: &mut VisitorContext<'a>,
@@ -9,6 +9,10 @@ use crate::{
 pub struct KnownTypeNames;
 
 impl<'a> Visitor<'a> for KnownTypeNames {
+    fn name(&self) -> Option<&'static str> {
+        Some("KnownTypeNames")
+    }
+
     fn enter_fragment_definition(
         &mut self,
         ctx: &mut VisitorContext<'a>,
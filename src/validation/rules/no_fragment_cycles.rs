@@ -56,6 +56,10 @@ pub struct NoFragmentCycles<'a> {
 }
 
 impl<'a> Visitor<'a> for NoFragmentCycles<'a> {
+    fn name(&self) -> Option<&'static str> {
+        Some("NoFragmentCycles")
+    }
+
     fn exit_document(&mut self, ctx: &mut VisitorContext<'a>, _doc: &'a ExecutableDocument) {
         let mut detector = CycleDetector {
             visited: HashSet::new(),
@@ -53,6 +53,10 @@ impl<'a> NoUndefinedVariables<'a> {
 }
 
 impl<'a> Visitor<'a> for NoUndefinedVariables<'a> {
+    fn name(&self) -> Option<&'static str> {
+        Some("NoUndefinedVariables")
+    }
+
     fn exit_document(&mut self, ctx: &mut VisitorContext<'a>, _doc: &'a ExecutableDocument) {
         for (op_name, (def_pos, def_vars)) in &self.defined_variables {
             let mut undef = Vec::new();
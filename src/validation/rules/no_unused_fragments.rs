@@ -35,6 +35,10 @@ impl<'a> NoUnusedFragments<'a> {
 }
 
 impl<'a> Visitor<'a> for NoUnusedFragments<'a> {
+    fn name(&self) -> Option<&'static str> {
+        Some("NoUnusedFragments")
+    }
+
     fn exit_document(&mut self, ctx: &mut VisitorContext<'a>, doc: &'a ExecutableDocument) {
         let mut reachable = HashSet::new();
 
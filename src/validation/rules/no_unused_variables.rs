@@ -53,6 +53,10 @@ impl<'a> NoUnusedVariables<'a> {
 }
 
 impl<'a> Visitor<'a> for NoUnusedVariables<'a> {
+    fn name(&self) -> Option<&'static str> {
+        Some("NoUnusedVariables")
+    }
+
     fn exit_document(&mut self, ctx: &mut VisitorContext<'a>, _doc: &'a ExecutableDocument) {
         for (op_name, def_vars) in &self.defined_variables {
             let mut used = HashSet::new();
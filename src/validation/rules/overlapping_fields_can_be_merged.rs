@@ -10,6 +10,10 @@ use crate::{
 pub struct OverlappingFieldsCanBeMerged;
 
 impl<'a> Visitor<'a> for OverlappingFieldsCanBeMerged {
+    fn name(&self) -> Option<&'static str> {
+        Some("OverlappingFieldsCanBeMerged")
+    }
+
     fn enter_selection_set(
         &mut self,
         ctx: &mut VisitorContext<'a>,
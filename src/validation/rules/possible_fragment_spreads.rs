@@ -12,6 +12,10 @@ pub struct PossibleFragmentSpreads<'a> {
 }
 
 impl<'a> Visitor<'a> for PossibleFragmentSpreads<'a> {
+    fn name(&self) -> Option<&'static str> {
+        Some("PossibleFragmentSpreads")
+    }
+
     fn enter_document(&mut self, _ctx: &mut VisitorContext<'a>, doc: &'a ExecutableDocument) {
         for (name, fragment) in doc.fragments.iter() {
             self.fragment_types
@@ -9,6 +9,10 @@ use crate::{
 pub struct ProvidedNonNullArguments;
 
 impl<'a> Visitor<'a> for ProvidedNonNullArguments {
+    fn name(&self) -> Option<&'static str> {
+        Some("ProvidedNonNullArguments")
+    }
+
     fn enter_directive(
         &mut self,
         ctx: &mut VisitorContext<'a>,
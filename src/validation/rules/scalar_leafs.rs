@@ -8,6 +8,10 @@ use crate::{
 pub struct ScalarLeafs;
 
 impl<'a> Visitor<'a> for ScalarLeafs {
+    fn name(&self) -> Option<&'static str> {
+        Some("ScalarLeafs")
+    }
+
     fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
         if let Some(ty) = ctx.parent_type()
             && let Some(schema_field) = ty.field_by_name(&field.node.name.node)
@@ -14,6 +14,10 @@ pub struct UniqueArgumentNames<'a> {
 }
 
 impl<'a> Visitor<'a> for UniqueArgumentNames<'a> {
+    fn name(&self) -> Option<&'static str> {
+        Some("UniqueArgumentNames")
+    }
+
     fn enter_directive(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
@@ -12,6 +12,10 @@ pub struct UniqueVariableNames<'a> {
 }
 
 impl<'a> Visitor<'a> for UniqueVariableNames<'a> {
+    fn name(&self) -> Option<&'static str> {
+        Some("UniqueVariableNames")
+    }
+
     fn enter_operation_definition(
         &mut self,
         _ctx: &mut VisitorContext<'a>,
@@ -8,6 +8,10 @@ use crate::{
 pub struct UploadFile;
 
 impl<'a> Visitor<'a> for UploadFile {
+    fn name(&self) -> Option<&'static str> {
+        Some("UploadFile")
+    }
+
     fn enter_operation_definition(
         &mut self,
         ctx: &mut VisitorContext<'a>,
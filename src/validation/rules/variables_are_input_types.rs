@@ -8,6 +8,10 @@ use crate::{
 pub struct VariablesAreInputTypes;
 
 impl<'a> Visitor<'a> for VariablesAreInputTypes {
+    fn name(&self) -> Option<&'static str> {
+        Some("VariablesAreInputTypes")
+    }
+
     fn enter_variable_definition(
         &mut self,
         ctx: &mut VisitorContext<'a>,
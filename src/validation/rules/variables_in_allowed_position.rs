@@ -70,6 +70,10 @@ impl<'a> VariableInAllowedPosition<'a> {
 }
 
 impl<'a> Visitor<'a> for VariableInAllowedPosition<'a> {
+    fn name(&self) -> Option<&'static str> {
+        Some("VariableInAllowedPosition")
+    }
+
     fn exit_document(&mut self, ctx: &mut VisitorContext<'a>, _doc: &'a ExecutableDocument) {
         for (op_scope, var_defs) in &self.variable_defs {
             self.collect_incorrect_usages(op_scope, var_defs, ctx, &mut HashSet::new());
@@ -6,7 +6,8 @@ use std::{
 use async_graphql_value::Value;
 
 use crate::{
-    InputType, Name, Pos, Positioned, ServerError, ServerResult, Variables,
+    ErrorCode, ErrorExtensionValues, InputType, Name, Pos, Positioned, ServerError, ServerResult,
+    Variables,
     parser::types::{
         Directive, ExecutableDocument, Field, FragmentDefinition, FragmentSpread, InlineFragment,
         OperationDefinition, OperationType, Selection, SelectionSet, TypeCondition,
@@ -161,6 +162,11 @@ pub(crate) trait Visitor<'a> {
         VisitMode::Normal
     }
 
+    /// The name of the validation rule, added to the errors it reports.
+    fn name(&self) -> Option<&'static str> {
+        None
+    }
+
     fn enter_document(&mut self, _ctx: &mut VisitorContext<'a>, _doc: &'a ExecutableDocument) {}
     fn exit_document(&mut self, _ctx: &mut VisitorContext<'a>, _doc: &'a ExecutableDocument) {}
 
@@ -326,6 +332,20 @@ impl<A, B> VisitorCons<A, B> {
 
 impl Visitor<'_> for VisitorNil {}
 
+fn visit_rule<'a, V: Visitor<'a>>(
+    visitor: &mut V,
+    ctx: &mut VisitorContext<'a>,
+    f: impl FnOnce(&mut V, &mut VisitorContext<'a>),
+) {
+    let start = ctx.errors.len();
+    f(visitor, ctx);
+    if let Some(name) = visitor.name() {
+        for err in &mut ctx.errors[start..] {
+            err.rule.get_or_insert(name);
+        }
+    }
+}
+
 impl<'a, A, B> Visitor<'a> for VisitorCons<A, B>
 where
     A: Visitor<'a> + 'a,
@@ -336,12 +356,12 @@ where
     }
 
     fn enter_document(&mut self, ctx: &mut VisitorContext<'a>, doc: &'a ExecutableDocument) {
-        self.0.enter_document(ctx, doc);
+        visit_rule(&mut self.0, ctx, |v, ctx| v.enter_document(ctx, doc));
         self.1.enter_document(ctx, doc);
     }
 
     fn exit_document(&mut self, ctx: &mut VisitorContext<'a>, doc: &'a ExecutableDocument) {
-        self.0.exit_document(ctx, doc);
+        visit_rule(&mut self.0, ctx, |v, ctx| v.exit_document(ctx, doc));
         self.1.exit_document(ctx, doc);
     }
 
@@ -351,8 +371,9 @@ where
         name: Option<&'a Name>,
         operation_definition: &'a Positioned<OperationDefinition>,
     ) {
-        self.0
-            .enter_operation_definition(ctx, name, operation_definition);
+        visit_rule(&mut self.0, ctx, |v, ctx| {
+            v.enter_operation_definition(ctx, name, operation_definition)
+        });
         self.1
             .enter_operation_definition(ctx, name, operation_definition);
     }
@@ -363,8 +384,9 @@ where
         name: Option<&'a Name>,
         operation_definition: &'a Positioned<OperationDefinition>,
     ) {
-        self.0
-            .exit_operation_definition(ctx, name, operation_definition);
+        visit_rule(&mut self.0, ctx, |v, ctx| {
+            v.exit_operation_definition(ctx, name, operation_definition)
+        });
         self.1
             .exit_operation_definition(ctx, name, operation_definition);
     }
@@ -375,8 +397,9 @@ where
         name: &'a Name,
         fragment_definition: &'a Positioned<FragmentDefinition>,
     ) {
-        self.0
-            .enter_fragment_definition(ctx, name, fragment_definition);
+        visit_rule(&mut self.0, ctx, |v, ctx| {
+            v.enter_fragment_definition(ctx, name, fragment_definition)
+        });
         self.1
             .enter_fragment_definition(ctx, name, fragment_definition);
     }
@@ -387,8 +410,9 @@ where
         name: &'a Name,
         fragment_definition: &'a Positioned<FragmentDefinition>,
     ) {
-        self.0
-            .exit_fragment_definition(ctx, name, fragment_definition);
+        visit_rule(&mut self.0, ctx, |v, ctx| {
+            v.exit_fragment_definition(ctx, name, fragment_definition)
+        });
         self.1
             .exit_fragment_definition(ctx, name, fragment_definition);
     }
@@ -398,7 +422,9 @@ where
         ctx: &mut VisitorContext<'a>,
         variable_definition: &'a Positioned<VariableDefinition>,
     ) {
-        self.0.enter_variable_definition(ctx, variable_definition);
+        visit_rule(&mut self.0, ctx, |v, ctx| {
+            v.enter_variable_definition(ctx, variable_definition)
+        });
         self.1.enter_variable_definition(ctx, variable_definition);
     }
 
@@ -407,7 +433,9 @@ where
         ctx: &mut VisitorContext<'a>,
         variable_definition: &'a Positioned<VariableDefinition>,
     ) {
-        self.0.exit_variable_definition(ctx, variable_definition);
+        visit_rule(&mut self.0, ctx, |v, ctx| {
+            v.exit_variable_definition(ctx, variable_definition)
+        });
         self.1.exit_variable_definition(ctx, variable_definition);
     }
 
@@ -416,7 +444,7 @@ where
         ctx: &mut VisitorContext<'a>,
         directive: &'a Positioned<Directive>,
     ) {
-        self.0.enter_directive(ctx, directive);
+        visit_rule(&mut self.0, ctx, |v, ctx| v.enter_directive(ctx, directive));
         self.1.enter_directive(ctx, directive);
     }
 
@@ -425,7 +453,7 @@ where
         ctx: &mut VisitorContext<'a>,
         directive: &'a Positioned<Directive>,
     ) {
-        self.0.exit_directive(ctx, directive);
+        visit_rule(&mut self.0, ctx, |v, ctx| v.exit_directive(ctx, directive));
         self.1.exit_directive(ctx, directive);
     }
 
@@ -435,7 +463,9 @@ where
         name: &'a Positioned<Name>,
         value: &'a Positioned<Value>,
     ) {
-        self.0.enter_argument(ctx, name, value);
+        visit_rule(&mut self.0, ctx, |v, ctx| {
+            v.enter_argument(ctx, name, value)
+        });
         self.1.enter_argument(ctx, name, value);
     }
 
@@ -445,7 +475,7 @@ where
         name: &'a Positioned<Name>,
         value: &'a Positioned<Value>,
     ) {
-        self.0.exit_argument(ctx, name, value);
+        visit_rule(&mut self.0, ctx, |v, ctx| v.exit_argument(ctx, name, value));
         self.1.exit_argument(ctx, name, value);
     }
 
@@ -454,7 +484,9 @@ where
         ctx: &mut VisitorContext<'a>,
         selection_set: &'a Positioned<SelectionSet>,
     ) {
-        self.0.enter_selection_set(ctx, selection_set);
+        visit_rule(&mut self.0, ctx, |v, ctx| {
+            v.enter_selection_set(ctx, selection_set)
+        });
         self.1.enter_selection_set(ctx, selection_set);
     }
 
@@ -463,7 +495,9 @@ where
         ctx: &mut VisitorContext<'a>,
         selection_set: &'a Positioned<SelectionSet>,
     ) {
-        self.0.exit_selection_set(ctx, selection_set);
+        visit_rule(&mut self.0, ctx, |v, ctx| {
+            v.exit_selection_set(ctx, selection_set)
+        });
         self.1.exit_selection_set(ctx, selection_set);
     }
 
@@ -472,7 +506,7 @@ where
         ctx: &mut VisitorContext<'a>,
         selection: &'a Positioned<Selection>,
     ) {
-        self.0.enter_selection(ctx, selection);
+        visit_rule(&mut self.0, ctx, |v, ctx| v.enter_selection(ctx, selection));
         self.1.enter_selection(ctx, selection);
     }
 
@@ -481,17 +515,17 @@ where
         ctx: &mut VisitorContext<'a>,
         selection: &'a Positioned<Selection>,
     ) {
-        self.0.exit_selection(ctx, selection);
+        visit_rule(&mut self.0, ctx, |v, ctx| v.exit_selection(ctx, selection));
         self.1.exit_selection(ctx, selection);
     }
 
     fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
-        self.0.enter_field(ctx, field);
+        visit_rule(&mut self.0, ctx, |v, ctx| v.enter_field(ctx, field));
         self.1.enter_field(ctx, field);
     }
 
     fn exit_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
-        self.0.exit_field(ctx, field);
+        visit_rule(&mut self.0, ctx, |v, ctx| v.exit_field(ctx, field));
         self.1.exit_field(ctx, field);
     }
 
@@ -500,7 +534,9 @@ where
         ctx: &mut VisitorContext<'a>,
         fragment_spread: &'a Positioned<FragmentSpread>,
     ) {
-        self.0.enter_fragment_spread(ctx, fragment_spread);
+        visit_rule(&mut self.0, ctx, |v, ctx| {
+            v.enter_fragment_spread(ctx, fragment_spread)
+        });
         self.1.enter_fragment_spread(ctx, fragment_spread);
     }
 
@@ -509,7 +545,9 @@ where
         ctx: &mut VisitorContext<'a>,
         fragment_spread: &'a Positioned<FragmentSpread>,
     ) {
-        self.0.exit_fragment_spread(ctx, fragment_spread);
+        visit_rule(&mut self.0, ctx, |v, ctx| {
+            v.exit_fragment_spread(ctx, fragment_spread)
+        });
         self.1.exit_fragment_spread(ctx, fragment_spread);
     }
 
@@ -518,7 +556,9 @@ where
         ctx: &mut VisitorContext<'a>,
         inline_fragment: &'a Positioned<InlineFragment>,
     ) {
-        self.0.enter_inline_fragment(ctx, inline_fragment);
+        visit_rule(&mut self.0, ctx, |v, ctx| {
+            v.enter_inline_fragment(ctx, inline_fragment)
+        });
         self.1.enter_inline_fragment(ctx, inline_fragment);
     }
 
@@ -527,7 +567,9 @@ where
         ctx: &mut VisitorContext<'a>,
         inline_fragment: &'a Positioned<InlineFragment>,
     ) {
-        self.0.exit_inline_fragment(ctx, inline_fragment);
+        visit_rule(&mut self.0, ctx, |v, ctx| {
+            v.exit_inline_fragment(ctx, inline_fragment)
+        });
         self.1.exit_inline_fragment(ctx, inline_fragment);
     }
 }
@@ -792,7 +834,12 @@ fn visit_fragment_spread<'a, V: Visitor<'a>>(
             .fragments
             .get(fragment_spread.node.fragment_name.node.as_str())
     {
-        visit_selection_set(v, ctx, &fragment.node.selection_set);
+        ctx.with_type(
+            ctx.registry
+                .types
+                .get(fragment.node.type_condition.node.on.node.as_str()),
+            |ctx| visit_selection_set(v, ctx, &fragment.node.selection_set),
+        );
     }
     v.exit_fragment_spread(ctx, fragment_spread);
 }
@@ -812,6 +859,7 @@ fn visit_inline_fragment<'a, V: Visitor<'a>>(
 pub(crate) struct RuleError {
     pub(crate) locations: Vec<Pos>,
     pub(crate) message: String,
+    pub(crate) rule: Option<&'static str>,
 }
 
 impl RuleError {
@@ -819,6 +867,7 @@ impl RuleError {
         Self {
             locations,
             message: msg.into(),
+            rule: None,
         }
     }
 }
@@ -846,12 +895,17 @@ impl Display for RuleError {
 
 impl From<RuleError> for ServerError {
     fn from(e: RuleError) -> Self {
+        let mut extensions = ErrorExtensionValues::default();
+        extensions.set("code", ErrorCode::GraphqlValidationFailed);
+        if let Some(rule) = e.rule {
+            extensions.set("rule", rule);
+        }
         Self {
             message: e.message,
             source: None,
             locations: e.locations,
             path: Vec::new(),
-            extensions: None,
+            extensions: Some(extensions),
         }
     }
 }
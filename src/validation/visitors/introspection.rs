@@ -0,0 +1,148 @@
+use async_graphql_parser::types::{Field, OperationDefinition};
+use async_graphql_value::Name;
+
+use crate::{
+    Positioned,
+    validation::visitor::{VisitMode, Visitor, VisitorContext},
+};
+
+/// Returns `true` if the field is the `__schema` or `__type` field of the
+/// query root.
+fn is_introspection_field(ctx: &VisitorContext<'_>, field: &Positioned<Field>) -> bool {
+    matches!(field.node.name.node.as_str(), "__schema" | "__type")
+        && ctx
+            .parent_type()
+            .is_some_and(|ty| ty.name() == ctx.registry.query_type)
+}
+
+pub struct IntrospectionCalculate<'a> {
+    max_depth: &'a mut usize,
+    max_fields: &'a mut usize,
+    current_depth: usize,
+    current_fields: usize,
+}
+
+impl<'a> IntrospectionCalculate<'a> {
+    pub fn new(max_depth: &'a mut usize, max_fields: &'a mut usize) -> Self {
+        Self {
+            max_depth,
+            max_fields,
+            current_depth: 0,
+            current_fields: 0,
+        }
+    }
+}
+
+fn is_type_field(ctx: &VisitorContext<'_>) -> bool {
+    ctx.current_type().is_some_and(|ty| ty.name() == "__Type")
+}
+
+impl<'ctx> Visitor<'ctx> for IntrospectionCalculate<'_> {
+    fn mode(&self) -> VisitMode {
+        VisitMode::Inline
+    }
+
+    fn enter_operation_definition(
+        &mut self,
+        _ctx: &mut VisitorContext<'ctx>,
+        _name: Option<&'ctx Name>,
+        _operation_definition: &'ctx Positioned<OperationDefinition>,
+    ) {
+        self.current_fields = 0;
+    }
+
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'ctx>, field: &'ctx Positioned<Field>) {
+        if is_introspection_field(ctx, field) {
+            self.current_fields += 1;
+            *self.max_fields = (*self.max_fields).max(self.current_fields);
+        }
+        if is_type_field(ctx) {
+            self.current_depth += 1;
+            *self.max_depth = (*self.max_depth).max(self.current_depth);
+        }
+    }
+
+    fn exit_field(&mut self, ctx: &mut VisitorContext<'ctx>, _field: &'ctx Positioned<Field>) {
+        if is_type_field(ctx) {
+            self.current_depth -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        EmptyMutation, EmptySubscription, Object, Schema, parser::parse_query, validation::visit,
+    };
+
+    struct Query;
+
+    #[Object(internal)]
+    impl Query {
+        async fn value(&self) -> i32 {
+            1
+        }
+    }
+
+    fn check_introspection(query: &str, expect_depth: usize, expect_fields: usize) {
+        let registry =
+            Schema::<Query, EmptyMutation, EmptySubscription>::create_registry(Default::default());
+        let doc = parse_query(query).unwrap();
+        let mut ctx = VisitorContext::new(&registry, &doc, None, None);
+        let mut depth = 0;
+        let mut fields = 0;
+        let mut introspection_calculate = IntrospectionCalculate::new(&mut depth, &mut fields);
+        visit(&mut introspection_calculate, &mut ctx, &doc);
+        assert_eq!((depth, fields), (expect_depth, expect_fields));
+    }
+
+    #[test]
+    fn introspection() {
+        check_introspection("{ value }", 0, 0);
+
+        check_introspection(
+            r#"{
+            __schema { # 0
+                queryType { name } # 1
+                types { # 1
+                    fields {
+                        type { ofType { ofType { name } } } # 4
+                    }
+                }
+            }
+        }"#,
+            4,
+            1,
+        );
+
+        check_introspection(
+            r#"
+        fragment TypeRef on __Type {
+            kind name ofType { kind name ofType { name } } # 3
+        }
+
+        query {
+            a: __type(name: "Query") { ...TypeRef } # 1
+            b: __type(name: "Int") { name }
+            __schema { queryType { name } }
+        }"#,
+            3,
+            3,
+        );
+
+        check_introspection(
+            r#"
+        query A {
+            a: __type(name: "Query") { name }
+            b: __type(name: "Int") { name }
+        }
+
+        query B {
+            __type(name: "Query") { name }
+        }"#,
+            1,
+            2,
+        );
+    }
+}
@@ -1,7 +1,11 @@
 mod cache_control;
 mod complexity;
 mod depth;
+mod introspection;
+mod usage;
 
 pub use cache_control::CacheControlCalculate;
 pub use complexity::ComplexityCalculate;
 pub use depth::DepthCalculate;
+pub use introspection::IntrospectionCalculate;
+pub use usage::{Usage, UsageCollect};
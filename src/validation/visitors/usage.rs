@@ -0,0 +1,219 @@
+use std::collections::BTreeSet;
+
+use async_graphql_parser::types::{
+    FragmentSpread, InlineFragment, OperationDefinition, VariableDefinition,
+};
+use async_graphql_value::{ConstValue, Name};
+
+use crate::{
+    Pos, Positioned,
+    parser::types::Field,
+    registry::{Deprecation, MetaType, MetaTypeName, Registry},
+    validation::{
+        DeprecatedUsage,
+        visitor::{VisitMode, Visitor, VisitorContext},
+    },
+};
+
+/// The types and fields referenced by an operation, and the deprecated
+/// fields, arguments, input fields and enum values it uses.
+#[derive(Default)]
+pub struct Usage {
+    pub types: BTreeSet<String>,
+    pub fields: BTreeSet<String>,
+    pub deprecations: Vec<DeprecatedUsage>,
+}
+
+pub struct UsageCollect<'a> {
+    usage: &'a mut Usage,
+    active: bool,
+}
+
+impl<'a> UsageCollect<'a> {
+    pub fn new(usage: &'a mut Usage) -> Self {
+        Self {
+            usage,
+            active: false,
+        }
+    }
+
+    fn deprecated(
+        &mut self,
+        coordinate: impl FnOnce() -> String,
+        deprecation: &Deprecation,
+        pos: Pos,
+    ) {
+        if deprecation.is_deprecated() {
+            self.usage.deprecations.push(DeprecatedUsage {
+                coordinate: coordinate(),
+                reason: deprecation.reason().map(ToString::to_string),
+                pos,
+            });
+        }
+    }
+
+    fn add_type(&mut self, name: &str) {
+        // Introspection types are not part of the schema an operation uses.
+        if !name.starts_with("__") {
+            self.usage.types.insert(name.to_string());
+        }
+    }
+
+    fn collect_input_value(&mut self, registry: &Registry, ty: &str, value: &ConstValue, pos: Pos) {
+        match MetaTypeName::create(ty) {
+            MetaTypeName::NonNull(ty) => self.collect_input_value(registry, ty, value, pos),
+            MetaTypeName::List(ty) => match value {
+                ConstValue::List(items) => {
+                    for item in items {
+                        self.collect_input_value(registry, ty, item, pos);
+                    }
+                }
+                value => self.collect_input_value(registry, ty, value, pos),
+            },
+            MetaTypeName::Named(name) => {
+                self.add_type(name);
+                self.collect_named_input_value(registry, name, value, pos);
+            }
+        }
+    }
+
+    fn collect_named_input_value(
+        &mut self,
+        registry: &Registry,
+        name: &str,
+        value: &ConstValue,
+        pos: Pos,
+    ) {
+        match (registry.types.get(name), value) {
+            (Some(MetaType::InputObject { input_fields, .. }), ConstValue::Object(fields)) => {
+                for (field_name, value) in fields {
+                    if let Some(input_field) = input_fields.get(field_name.as_str()) {
+                        self.deprecated(
+                            || format!("{}.{}", name, field_name),
+                            &input_field.deprecation,
+                            pos,
+                        );
+                        self.collect_input_value(registry, &input_field.ty, value, pos);
+                    }
+                }
+            }
+            (Some(MetaType::Enum { enum_values, .. }), value) => {
+                let item = match value {
+                    ConstValue::Enum(item) => item.as_str(),
+                    ConstValue::String(item) => item.as_str(),
+                    _ => return,
+                };
+                if let Some(enum_value) = enum_values.get(item) {
+                    self.deprecated(
+                        || format!("{}.{}", name, item),
+                        &enum_value.deprecation,
+                        pos,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for UsageCollect<'_> {
+    fn mode(&self) -> VisitMode {
+        VisitMode::Inline
+    }
+
+    fn enter_operation_definition(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        name: Option<&'a Name>,
+        _operation_definition: &'a Positioned<OperationDefinition>,
+    ) {
+        self.active = match ctx.operation_name {
+            Some(operation_name) => name.map(Name::as_str) == Some(operation_name),
+            None => true,
+        };
+    }
+
+    fn enter_variable_definition(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        variable_definition: &'a Positioned<VariableDefinition>,
+    ) {
+        if self.active {
+            let ty = variable_definition.node.var_type.node.to_string();
+            self.add_type(MetaTypeName::concrete_typename(&ty));
+        }
+    }
+
+    fn enter_fragment_spread(
+        &mut self,
+        ctx: &mut VisitorContext<'a>,
+        fragment_spread: &'a Positioned<FragmentSpread>,
+    ) {
+        if !self.active {
+            return;
+        }
+        if let Some(fragment) = ctx.fragment(&fragment_spread.node.fragment_name.node) {
+            self.add_type(&fragment.node.type_condition.node.on.node);
+        }
+    }
+
+    fn enter_inline_fragment(
+        &mut self,
+        _ctx: &mut VisitorContext<'a>,
+        inline_fragment: &'a Positioned<InlineFragment>,
+    ) {
+        if !self.active {
+            return;
+        }
+        if let Some(type_condition) = &inline_fragment.node.type_condition {
+            self.add_type(&type_condition.node.on.node);
+        }
+    }
+
+    fn enter_field(&mut self, ctx: &mut VisitorContext<'a>, field: &'a Positioned<Field>) {
+        let name = field.node.name.node.as_str();
+        if !self.active || name.starts_with("__") {
+            return;
+        }
+        let Some(parent_type) = ctx.parent_type() else {
+            return;
+        };
+        if parent_type.name().starts_with("__") {
+            return;
+        }
+        let Some(registry_field) = parent_type.field_by_name(name) else {
+            return;
+        };
+
+        let coordinate = format!("{}.{}", parent_type.name(), name);
+        self.add_type(parent_type.name());
+        self.add_type(MetaTypeName::concrete_typename(&registry_field.ty));
+        self.deprecated(
+            || coordinate.clone(),
+            &registry_field.deprecation,
+            field.pos,
+        );
+
+        for (arg_name, value) in &field.node.arguments {
+            let Some(arg) = registry_field.args.get(arg_name.node.as_str()) else {
+                continue;
+            };
+            self.add_type(MetaTypeName::concrete_typename(&arg.ty));
+            self.deprecated(
+                || format!("{}({}:)", coordinate, arg_name.node),
+                &arg.deprecation,
+                arg_name.pos,
+            );
+            let value = value.node.clone().into_const_with(|name| {
+                ctx.variables
+                    .and_then(|variables| variables.get(&name))
+                    .cloned()
+                    .ok_or(())
+            });
+            if let Ok(value) = value {
+                self.collect_input_value(ctx.registry, &arg.ty, &value, arg_name.pos);
+            }
+        }
+        self.usage.fields.insert(coordinate);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{InputType, InputValueError};
+
+pub fn ascii<T: AsRef<str> + InputType>(value: &T) -> Result<(), InputValueError<T>> {
+    if value.as_ref().is_ascii() {
+        Ok(())
+    } else {
+        Err("the value must only contain ASCII characters".into())
+    }
+}
+
+pub fn alphanumeric<T: AsRef<str> + InputType>(value: &T) -> Result<(), InputValueError<T>> {
+    if value.as_ref().chars().all(|c| c.is_ascii_alphanumeric()) {
+        Ok(())
+    } else {
+        Err("the value must only contain ASCII letters and digits".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii() {
+        assert!(ascii(&"hello, world!".to_string()).is_ok());
+        assert!(ascii(&"héllo".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_alphanumeric() {
+        assert!(alphanumeric(&"abc123".to_string()).is_ok());
+        assert!(alphanumeric(&"abc 123".to_string()).is_err());
+        assert!(alphanumeric(&"abc_123".to_string()).is_err());
+        assert!(alphanumeric(&"é1".to_string()).is_err());
+    }
+}
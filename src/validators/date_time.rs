@@ -0,0 +1,149 @@
+use crate::{InputType, InputValueError};
+
+pub fn date<T: AsRef<str> + InputType>(value: &T) -> Result<(), InputValueError<T>> {
+    if is_date(value.as_ref()) {
+        Ok(())
+    } else {
+        Err("invalid date, must be in the ISO-8601 format YYYY-MM-DD".into())
+    }
+}
+
+pub fn time<T: AsRef<str> + InputType>(value: &T) -> Result<(), InputValueError<T>> {
+    let s = value.as_ref();
+    let is_valid = match s.len().checked_sub(offset_len(s)) {
+        Some(len) => is_time(&s[..len]),
+        None => false,
+    };
+    if is_valid {
+        Ok(())
+    } else {
+        Err("invalid time, must be in the ISO-8601 format hh:mm:ss".into())
+    }
+}
+
+pub fn date_time<T: AsRef<str> + InputType>(value: &T) -> Result<(), InputValueError<T>> {
+    let s = value.as_ref();
+    let offset_len = offset_len(s);
+    let is_valid = offset_len > 0
+        && s.len() > 11 + offset_len
+        && s.get(..10).is_some_and(is_date)
+        && matches!(s.get(10..11), Some("T" | "t"))
+        && s.get(11..s.len() - offset_len).is_some_and(is_time);
+    if is_valid {
+        Ok(())
+    } else {
+        Err("invalid date-time, must be in the ISO-8601 format YYYY-MM-DDThh:mm:ssZ".into())
+    }
+}
+
+fn parse_digits(s: &str) -> Option<u32> {
+    match s.chars().all(|c| c.is_ascii_digit()) {
+        true => s.parse().ok(),
+        false => None,
+    }
+}
+
+fn is_date(s: &str) -> bool {
+    let mut parts = s.splitn(3, '-');
+    let (Some(year), Some(month), Some(day)) = (parts.next(), parts.next(), parts.next()) else {
+        return false;
+    };
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return false;
+    }
+    let (Some(year), Some(month), Some(day)) =
+        (parse_digits(year), parse_digits(month), parse_digits(day))
+    else {
+        return false;
+    };
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => return false,
+    };
+    (1..=days_in_month).contains(&day)
+}
+
+/// Returns `true` if `s` is `hh:mm:ss` with optional fractional seconds.
+fn is_time(s: &str) -> bool {
+    let (s, fraction) = s.split_once('.').unwrap_or((s, "0"));
+    let mut parts = s.splitn(3, ':');
+    let (Some(hour), Some(minute), Some(second)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return false;
+    };
+    hour.len() == 2
+        && minute.len() == 2
+        && second.len() == 2
+        && !fraction.is_empty()
+        && fraction.chars().all(|c| c.is_ascii_digit())
+        && parse_digits(hour).is_some_and(|hour| hour < 24)
+        && parse_digits(minute).is_some_and(|minute| minute < 60)
+        // 60 is a leap second.
+        && parse_digits(second).is_some_and(|second| second <= 60)
+}
+
+/// Returns the length of the time zone offset at the end of `s`, `Z` or
+/// `±hh:mm`, or `0` if there is none.
+fn offset_len(s: &str) -> usize {
+    if s.ends_with(['Z', 'z']) {
+        return 1;
+    }
+    let Some(offset) = s.len().checked_sub(6).and_then(|start| s.get(start..)) else {
+        return 0;
+    };
+    let is_offset = offset.starts_with(['+', '-'])
+        && offset.get(3..4) == Some(":")
+        && offset
+            .get(1..3)
+            .and_then(parse_digits)
+            .is_some_and(|hour| hour < 24)
+        && offset
+            .get(4..6)
+            .and_then(parse_digits)
+            .is_some_and(|minute| minute < 60);
+    if is_offset { 6 } else { 0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date() {
+        assert!(date(&"2024-02-29".to_string()).is_ok());
+        assert!(date(&"2000-02-29".to_string()).is_ok());
+        assert!(date(&"1900-02-29".to_string()).is_err());
+        assert!(date(&"2023-02-29".to_string()).is_err());
+        assert!(date(&"2024-04-31".to_string()).is_err());
+        assert!(date(&"2024-13-01".to_string()).is_err());
+        assert!(date(&"2024-1-01".to_string()).is_err());
+        assert!(date(&"2024-01-01T00:00:00Z".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_time() {
+        assert!(time(&"23:59:59".to_string()).is_ok());
+        assert!(time(&"23:59:60.123".to_string()).is_ok());
+        assert!(time(&"08:30:00Z".to_string()).is_ok());
+        assert!(time(&"08:30:00+05:30".to_string()).is_ok());
+        assert!(time(&"24:00:00".to_string()).is_err());
+        assert!(time(&"08:60:00".to_string()).is_err());
+        assert!(time(&"08:30".to_string()).is_err());
+        assert!(time(&"08:30:00.".to_string()).is_err());
+        assert!(time(&"08:30:00+5:30".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_date_time() {
+        assert!(date_time(&"2024-05-01T08:30:00Z".to_string()).is_ok());
+        assert!(date_time(&"2024-05-01t08:30:00.5-07:00".to_string()).is_ok());
+        assert!(date_time(&"2024-05-01T08:30:00".to_string()).is_err());
+        assert!(date_time(&"2024-05-01 08:30:00Z".to_string()).is_err());
+        assert!(date_time(&"2024-05-32T08:30:00Z".to_string()).is_err());
+        assert!(date_time(&"2024-05-01TZ".to_string()).is_err());
+        assert!(date_time(&"2024-05-01".to_string()).is_err());
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{InputType, InputValueError};
+
+pub fn hex_color<T: AsRef<str> + InputType>(value: &T) -> Result<(), InputValueError<T>> {
+    let is_hex_color = value.as_ref().strip_prefix('#').is_some_and(|digits| {
+        matches!(digits.len(), 3 | 4 | 6 | 8) && digits.chars().all(|c| c.is_ascii_hexdigit())
+    });
+    if is_hex_color {
+        Ok(())
+    } else {
+        Err("invalid hex color".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_color() {
+        assert!(hex_color(&"#fff".to_string()).is_ok());
+        assert!(hex_color(&"#FFFA".to_string()).is_ok());
+        assert!(hex_color(&"#1a2B3c".to_string()).is_ok());
+        assert!(hex_color(&"#1a2b3c80".to_string()).is_ok());
+        assert!(hex_color(&"fff".to_string()).is_err());
+        assert!(hex_color(&"#ff".to_string()).is_err());
+        assert!(hex_color(&"#gggggg".to_string()).is_err());
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{InputType, InputValueError};
+
+pub fn hostname<T: AsRef<str> + InputType>(value: &T) -> Result<(), InputValueError<T>> {
+    if is_hostname(value.as_ref()) {
+        Ok(())
+    } else {
+        Err("invalid hostname".into())
+    }
+}
+
+/// Returns `true` if `s` is a valid hostname according to RFC 1123.
+pub(crate) fn is_hostname(s: &str) -> bool {
+    s.len() <= 253
+        && s.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hostname() {
+        assert!(hostname(&"localhost".to_string()).is_ok());
+        assert!(hostname(&"api.example.com".to_string()).is_ok());
+        assert!(hostname(&"xn--bcher-kva.example".to_string()).is_ok());
+        assert!(hostname(&"1.2.3.4".to_string()).is_ok());
+        assert!(hostname(&"".to_string()).is_err());
+        assert!(hostname(&"example..com".to_string()).is_err());
+        assert!(hostname(&"-example.com".to_string()).is_err());
+        assert!(hostname(&"example-.com".to_string()).is_err());
+        assert!(hostname(&"exa_mple.com".to_string()).is_err());
+        assert!(hostname(&format!("{}.com", "a".repeat(64))).is_err());
+    }
+}
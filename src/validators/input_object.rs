@@ -0,0 +1,216 @@
+use std::fmt::Display;
+
+#[cfg(feature = "dynamic-schema")]
+use crate::{ErrorCode, Pos, ServerError};
+use crate::{InputType, InputValueError, Name, Value};
+
+/// An error reported by an [`InputObjectValidator`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct InputFieldError {
+    /// The path of the invalid field, relative to the validated input object.
+    ///
+    /// It is empty for the errors about the whole object.
+    pub path: Vec<String>,
+    /// The error message.
+    pub message: String,
+}
+
+/// The errors reported by the [`InputObjectValidator`]s of an input object.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct InputFieldErrors(Vec<InputFieldError>);
+
+impl InputFieldErrors {
+    /// Create an empty list of errors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report an error on the field `name`.
+    pub fn add(&mut self, name: impl Into<String>, message: impl Display) -> &mut Self {
+        self.add_path([name], message)
+    }
+
+    /// Report an error on a nested field, such as `["range", "end"]`.
+    pub fn add_path<I, T>(&mut self, path: I, message: impl Display) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.0.push(InputFieldError {
+            path: path.into_iter().map(Into::into).collect(),
+            message: message.to_string(),
+        });
+        self
+    }
+
+    /// Report an error about the whole object.
+    pub fn add_object(&mut self, message: impl Display) -> &mut Self {
+        self.add_path(std::iter::empty::<String>(), message)
+    }
+
+    /// Report an error on the field `name` if `condition` is `false`.
+    pub fn ensure(
+        &mut self,
+        condition: bool,
+        name: impl Into<String>,
+        message: impl Display,
+    ) -> &mut Self {
+        if !condition {
+            self.add(name, message);
+        }
+        self
+    }
+
+    /// Returns `true` if no error has been reported.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of reported errors.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns an iterator over the reported errors.
+    pub fn iter(&self) -> impl Iterator<Item = &InputFieldError> {
+        self.0.iter()
+    }
+
+    /// Returns an error for the input type `T` if any error has been
+    /// reported.
+    ///
+    /// The messages are joined in the error message, and listed with their
+    /// paths in the `validationErrors` extension.
+    pub fn into_result<T: InputType>(self) -> Result<(), InputValueError<T>> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        Err(InputValueError::custom(self.message())
+            .with_extension("validationErrors", self.into_value()))
+    }
+
+    /// Returns the error of the input object `type_name`, like
+    /// [`into_result`](Self::into_result).
+    #[cfg(feature = "dynamic-schema")]
+    pub(crate) fn into_server_error(self, type_name: &str, pos: Pos) -> ServerError {
+        let mut err = ServerError::new(
+            format!(r#"Failed to parse "{}": {}"#, type_name, self.message()),
+            Some(pos),
+        )
+        .with_code(ErrorCode::BadUserInput);
+        err.extensions
+            .get_or_insert_with(Default::default)
+            .set("validationErrors", self.into_value());
+        err
+    }
+
+    fn message(&self) -> String {
+        self.0
+            .iter()
+            .map(|err| match err.path.is_empty() {
+                true => err.message.clone(),
+                false => format!("{}: {}", err.path.join("."), err.message),
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    fn into_value(self) -> Value {
+        Value::List(
+            self.0
+                .into_iter()
+                .map(|err| {
+                    Value::Object(
+                        [
+                            (
+                                Name::new("path"),
+                                Value::List(err.path.into_iter().map(Value::String).collect()),
+                            ),
+                            (Name::new("message"), Value::String(err.message)),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<'a> IntoIterator for &'a InputFieldErrors {
+    type Item = &'a InputFieldError;
+    type IntoIter = std::slice::Iter<'a, InputFieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Represents a validator checking several fields of an input object
+/// together.
+///
+/// Unlike [`CustomValidator`](crate::CustomValidator), it reports every error
+/// at once to `errors`, with the path of the invalid fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_graphql::*;
+///
+/// #[derive(InputObject)]
+/// #[graphql(object_validator = "check_range")]
+/// struct Range {
+///     start: i32,
+///     end: i32,
+///     step: Option<i32>,
+/// }
+///
+/// fn check_range(range: &Range, errors: &mut InputFieldErrors) {
+///     errors.ensure(range.end > range.start, "end", "must be after start");
+///     if let Some(step) = range.step {
+///         errors.ensure(step <= range.end - range.start, "step", "is too large");
+///     }
+/// }
+///
+/// struct Query;
+///
+/// #[Object]
+/// impl Query {
+///     async fn len(&self, range: Range) -> i32 {
+///         range.end - range.start
+///     }
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+/// let errors = schema
+///     .execute("{ len(range: { start: 10, end: 0, step: 20 }) }")
+///     .await
+///     .errors;
+/// assert_eq!(
+///     errors[0].message,
+///     r#"Failed to parse "Range": end: must be after start; step: is too large"#
+/// );
+/// assert_eq!(
+///     errors[0].extensions.as_ref().unwrap().get("validationErrors"),
+///     Some(&value!([
+///         { "path": ["end"], "message": "must be after start" },
+///         { "path": ["step"], "message": "is too large" },
+///     ]))
+/// );
+/// # });
+/// ```
+pub trait InputObjectValidator<T> {
+    /// Check the value, and report the errors to `errors`.
+    fn validate(&self, value: &T, errors: &mut InputFieldErrors);
+}
+
+impl<T, F> InputObjectValidator<T> for F
+where
+    F: Fn(&T, &mut InputFieldErrors),
+{
+    #[inline]
+    fn validate(&self, value: &T, errors: &mut InputFieldErrors) {
+        (self)(value, errors)
+    }
+}
@@ -0,0 +1,59 @@
+use std::net::IpAddr;
+
+use crate::{InputType, InputValueError};
+
+pub fn ip<T: AsRef<str> + InputType>(value: &T) -> Result<(), InputValueError<T>> {
+    if value.as_ref().parse::<IpAddr>().is_ok() {
+        Ok(())
+    } else {
+        Err("invalid ip".into())
+    }
+}
+
+pub fn cidr<T: AsRef<str> + InputType>(value: &T) -> Result<(), InputValueError<T>> {
+    let is_cidr = value
+        .as_ref()
+        .split_once('/')
+        .is_some_and(|(addr, prefix)| {
+            let max_prefix = match addr.parse::<IpAddr>() {
+                Ok(IpAddr::V4(_)) => 32,
+                Ok(IpAddr::V6(_)) => 128,
+                Err(_) => return false,
+            };
+            prefix.chars().all(|c| c.is_ascii_digit())
+                && prefix
+                    .parse::<u8>()
+                    .is_ok_and(|prefix| prefix <= max_prefix)
+        });
+    if is_cidr {
+        Ok(())
+    } else {
+        Err("invalid cidr".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ip() {
+        assert!(ip(&"127.0.0.1".to_string()).is_ok());
+        assert!(ip(&"::1".to_string()).is_ok());
+        assert!(ip(&"2001:db8::ff00:42:8329".to_string()).is_ok());
+        assert!(ip(&"256.0.0.1".to_string()).is_err());
+        assert!(ip(&"localhost".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_cidr() {
+        assert!(cidr(&"10.0.0.0/8".to_string()).is_ok());
+        assert!(cidr(&"192.168.1.1/32".to_string()).is_ok());
+        assert!(cidr(&"2001:db8::/32".to_string()).is_ok());
+        assert!(cidr(&"::/128".to_string()).is_ok());
+        assert!(cidr(&"10.0.0.0".to_string()).is_err());
+        assert!(cidr(&"10.0.0.0/33".to_string()).is_err());
+        assert!(cidr(&"10.0.0.0/+8".to_string()).is_err());
+        assert!(cidr(&"2001:db8::/129".to_string()).is_err());
+    }
+}
@@ -1,5 +1,11 @@
+mod ascii;
 mod chars_max_length;
 mod chars_min_length;
+mod date_time;
+mod hex_color;
+mod hostname;
+mod input_object;
+mod ip;
 mod max_items;
 mod max_length;
 mod maximum;
@@ -7,10 +13,23 @@ mod min_items;
 mod min_length;
 mod minimum;
 mod multiple_of;
+mod not_blank;
+mod one_of;
+mod phone;
 mod regex;
+mod starts_with;
+mod unique_items;
+mod url;
+mod uuid;
 
+pub use ascii::{alphanumeric, ascii};
 pub use chars_max_length::chars_max_length;
 pub use chars_min_length::chars_min_length;
+pub use date_time::{date, date_time, time};
+pub use hex_color::hex_color;
+pub use hostname::hostname;
+pub use input_object::{InputFieldError, InputFieldErrors, InputObjectValidator};
+pub use ip::{cidr, ip};
 pub use max_items::max_items;
 pub use max_length::max_length;
 pub use maximum::maximum;
@@ -18,8 +37,13 @@ pub use min_items::min_items;
 pub use min_length::min_length;
 pub use minimum::minimum;
 pub use multiple_of::multiple_of;
+pub use not_blank::not_blank;
+pub use one_of::one_of;
+pub use phone::phone;
+pub use starts_with::{ends_with, starts_with};
+pub use unique_items::unique_items;
 
-pub use self::regex::regex;
+pub use self::{regex::regex, url::url, uuid::uuid};
 use crate::{InputType, InputValueError};
 
 /// Represents a custom input value validator.
@@ -0,0 +1,36 @@
+use std::fmt::Display;
+
+use crate::{InputType, InputValueError};
+
+pub fn one_of<T, V>(value: &T, values: &[V]) -> Result<(), InputValueError<T>>
+where
+    T: PartialEq<V> + InputType,
+    V: Display,
+{
+    if values.iter().any(|v| value == v) {
+        Ok(())
+    } else {
+        Err(format!(
+            "the value must be one of {}",
+            values
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_of() {
+        assert!(one_of(&"red".to_string(), &["red", "green"]).is_ok());
+        assert!(one_of(&"blue".to_string(), &["red", "green"]).is_err());
+        assert!(one_of(&2, &[1, 2, 3]).is_ok());
+        assert!(one_of(&4, &[1, 2, 3]).is_err());
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{InputType, InputValueError};
+
+pub fn phone<T: AsRef<str> + InputType>(value: &T) -> Result<(), InputValueError<T>> {
+    let is_e164 = value.as_ref().strip_prefix('+').is_some_and(|digits| {
+        (2..=15).contains(&digits.len())
+            && !digits.starts_with('0')
+            && digits.chars().all(|c| c.is_ascii_digit())
+    });
+    if is_e164 {
+        Ok(())
+    } else {
+        Err("invalid phone number, must be in E.164 format".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phone() {
+        assert!(phone(&"+14155552671".to_string()).is_ok());
+        assert!(phone(&"+442071838750".to_string()).is_ok());
+        assert!(phone(&"14155552671".to_string()).is_err());
+        assert!(phone(&"+04155552671".to_string()).is_err());
+        assert!(phone(&"+1 415 555 2671".to_string()).is_err());
+        assert!(phone(&"+1234567890123456".to_string()).is_err());
+        assert!(phone(&"+1".to_string()).is_err());
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{InputType, InputValueError};
+
+pub fn starts_with<T: AsRef<str> + InputType>(
+    value: &T,
+    prefix: &'static str,
+) -> Result<(), InputValueError<T>> {
+    if value.as_ref().starts_with(prefix) {
+        Ok(())
+    } else {
+        Err(format!("the value must start with \"{}\"", prefix).into())
+    }
+}
+
+pub fn ends_with<T: AsRef<str> + InputType>(
+    value: &T,
+    suffix: &'static str,
+) -> Result<(), InputValueError<T>> {
+    if value.as_ref().ends_with(suffix) {
+        Ok(())
+    } else {
+        Err(format!("the value must end with \"{}\"", suffix).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starts_with() {
+        assert!(starts_with(&"sk_live_123".to_string(), "sk_").is_ok());
+        assert!(starts_with(&"pk_live_123".to_string(), "sk_").is_err());
+    }
+
+    #[test]
+    fn test_ends_with() {
+        assert!(ends_with(&"photo.png".to_string(), ".png").is_ok());
+        assert!(ends_with(&"photo.jpg".to_string(), ".png").is_err());
+    }
+}
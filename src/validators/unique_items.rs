@@ -0,0 +1,31 @@
+use std::{collections::HashSet, hash::Hash, ops::Deref};
+
+use crate::{InputType, InputValueError};
+
+pub fn unique_items<T: Deref<Target = [E]> + InputType, E: Hash + Eq>(
+    value: &T,
+) -> Result<(), InputValueError<T>> {
+    let items = value.deref();
+    let mut seen = HashSet::with_capacity(items.len());
+    match items.iter().position(|item| !seen.insert(item)) {
+        None => Ok(()),
+        Some(i) => Err(format!(
+            "the items must be unique, the item at index {} is a duplicate",
+            i
+        )
+        .into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unique_items() {
+        assert!(unique_items(&Vec::<i32>::new()).is_ok());
+        assert!(unique_items(&vec![1, 2, 3]).is_ok());
+        assert!(unique_items(&vec![1, 2, 1]).is_err());
+        assert!(unique_items(&vec!["a".to_string(), "a".to_string()]).is_err());
+    }
+}
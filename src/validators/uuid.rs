@@ -0,0 +1,37 @@
+use crate::{InputType, InputValueError};
+
+pub fn uuid<T: AsRef<str> + InputType>(
+    value: &T,
+    version: Option<u8>,
+) -> Result<(), InputValueError<T>> {
+    let s = value.as_ref();
+    let is_uuid = s.len() == 36
+        && s.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        });
+    if !is_uuid {
+        return Err("invalid uuid".into());
+    }
+    match version {
+        Some(version) if s[14..15] != format!("{:x}", version) => {
+            Err(format!("the uuid version is {}, must be {}", &s[14..15], version).into())
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uuid() {
+        assert!(uuid(&"67e55044-10b1-426f-9247-bb680e5fe0c8".to_string(), None).is_ok());
+        assert!(uuid(&"67E55044-10B1-426F-9247-BB680E5FE0C8".to_string(), Some(4)).is_ok());
+        assert!(uuid(&"67e55044-10b1-126f-9247-bb680e5fe0c8".to_string(), Some(4)).is_err());
+        assert!(uuid(&"67e5504410b1426f9247bb680e5fe0c8".to_string(), None).is_err());
+        assert!(uuid(&"67e55044-10b1-426f-9247-bb680e5fe0cg".to_string(), None).is_err());
+        assert!(uuid(&"67e55044-10b1-426f-9247_bb680e5fe0c8".to_string(), None).is_err());
+    }
+}
@@ -0,0 +1,264 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use async_graphql::{parser::types::OperationType, *};
+
+static EXECUTED: AtomicBool = AtomicBool::new(false);
+
+#[derive(SimpleObject)]
+struct Dog {
+    name: String,
+    #[graphql(deprecation = "Use `name`")]
+    nickname: String,
+}
+
+#[derive(SimpleObject)]
+struct Cat {
+    name: String,
+    lives: i32,
+}
+
+#[derive(Interface)]
+#[graphql(field(name = "name", ty = "&String"))]
+enum Pet {
+    Dog(Dog),
+    Cat(Cat),
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+enum Size {
+    Small,
+    Large,
+}
+
+#[derive(InputObject)]
+struct DogFilter {
+    size: Option<Size>,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    #[graphql(cache_control(max_age = 60))]
+    async fn pets(&self) -> Vec<Pet> {
+        EXECUTED.store(true, Ordering::SeqCst);
+        Vec::new()
+    }
+
+    #[graphql(complexity = "count * child_complexity")]
+    async fn dogs(&self, count: usize) -> Vec<Dog> {
+        EXECUTED.store(true, Ordering::SeqCst);
+        (0..count)
+            .map(|_| Dog {
+                name: String::new(),
+                nickname: String::new(),
+            })
+            .collect()
+    }
+
+    #[graphql(tag = "internal")]
+    async fn search(&self, filter: DogFilter, limit: Option<i32>) -> Vec<Dog> {
+        EXECUTED.store(true, Ordering::SeqCst);
+        let _ = (filter.size, limit);
+        Vec::new()
+    }
+}
+
+struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn adopt(&self, name: String) -> bool {
+        EXECUTED.store(true, Ordering::SeqCst);
+        !name.is_empty()
+    }
+}
+
+#[tokio::test]
+pub async fn test_analyze() {
+    let schema = Schema::build(Query, Mutation, EmptySubscription)
+        .limit_complexity(5)
+        .finish();
+
+    let query = r#"
+        query Pets {
+            pets {
+                name
+                ... on Cat { lives }
+                ...DogFields
+            }
+        }
+
+        fragment DogFields on Dog {
+            nickname
+        }
+
+        mutation Adopt {
+            adopt(name: "Rex")
+        }
+    "#;
+    let analysis = schema
+        .analyze(Request::new(query).operation_name("Pets"))
+        .unwrap();
+    assert_eq!(analysis.operation_name.as_deref(), Some("Pets"));
+    assert_eq!(analysis.operation_type, OperationType::Query);
+    assert_eq!(analysis.cache_control.max_age, 60);
+    assert_eq!(
+        analysis.types.into_iter().collect::<Vec<_>>(),
+        ["Cat", "Dog", "Int", "Pet", "Query", "String"]
+    );
+    assert_eq!(
+        analysis.fields.into_iter().collect::<Vec<_>>(),
+        ["Cat.lives", "Dog.nickname", "Pet.name", "Query.pets"]
+    );
+    assert_eq!(analysis.deprecations.len(), 1);
+    assert_eq!(analysis.deprecations[0].coordinate, "Dog.nickname");
+    assert_eq!(
+        analysis.deprecations[0].reason.as_deref(),
+        Some("Use `name`")
+    );
+    assert_eq!(
+        analysis.deprecations[0].pos,
+        Pos {
+            line: 11,
+            column: 13
+        }
+    );
+
+    let analysis = schema
+        .analyze(Request::new(query).operation_name("Adopt"))
+        .unwrap();
+    assert_eq!(analysis.operation_type, OperationType::Mutation);
+    assert_eq!(
+        analysis.fields.into_iter().collect::<Vec<_>>(),
+        ["Mutation.adopt"]
+    );
+
+    // The limits are not checked, the complexity is reported.
+    let analysis = schema
+        .analyze(
+            Request::new("query($count: Int!) { dogs(count: $count) { name } }")
+                .variables(Variables::from_value(value!({ "count": 10 }))),
+        )
+        .unwrap();
+    assert_eq!(analysis.complexity, 10);
+    assert_eq!(analysis.depth, 2);
+
+    let errors = schema.analyze("{ unknown }").unwrap_err();
+    assert_eq!(
+        errors[0].message,
+        r#"Unknown field "unknown" on type "Query"."#
+    );
+    assert_eq!(errors[0].code(), Some(ErrorCode::GraphqlValidationFailed));
+
+    let errors = schema.analyze(query).unwrap_err();
+    assert_eq!(errors[0].message, "Operation name required in request.");
+    assert!(!EXECUTED.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+pub async fn test_analyze_input_types() {
+    let schema = Schema::new(Query, Mutation, EmptySubscription);
+
+    let analysis = schema
+        .analyze(
+            Request::new(
+                r#"query($limit: Int) {
+                    search(filter: { size: LARGE }, limit: $limit) { name }
+                }"#,
+            )
+            .variables(Variables::from_value(value!({ "limit": 1 }))),
+        )
+        .unwrap();
+    assert_eq!(
+        analysis.types.into_iter().collect::<Vec<_>>(),
+        ["Dog", "DogFilter", "Int", "Query", "Size", "String"]
+    );
+    assert_eq!(
+        analysis.fields.into_iter().collect::<Vec<_>>(),
+        ["Dog.name", "Query.search"]
+    );
+
+    // Introspection types are not reported.
+    let analysis = schema
+        .analyze("{ __schema { types { name } } pets { __typename } }")
+        .unwrap();
+    assert_eq!(
+        analysis.types.into_iter().collect::<Vec<_>>(),
+        ["Pet", "Query"]
+    );
+    assert!(!EXECUTED.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+pub async fn test_analyze_contract_and_introspection_mode() {
+    let schema = Schema::build(Query, Mutation, EmptySubscription)
+        .contract(Contract::new().exclude_tag("internal"))
+        .finish();
+    let errors = schema
+        .analyze("{ search(filter: {}) { name } }")
+        .unwrap_err();
+    assert_eq!(
+        errors[0].message,
+        r#"Unknown field "search" on type "Query"."#
+    );
+
+    // Only the introspection fields are executed, so nothing is reported.
+    let analysis = schema
+        .analyze(
+            Request::new("{ pets { name } __schema { queryType { name } } }").only_introspection(),
+        )
+        .unwrap();
+    assert!(analysis.types.is_empty());
+    assert!(analysis.fields.is_empty());
+
+    let schema = Schema::build(Query, Mutation, EmptySubscription)
+        .introspection_only()
+        .finish();
+    let analysis = schema.analyze("{ dogs(count: 1) { nickname } }").unwrap();
+    assert!(analysis.fields.is_empty());
+    assert!(analysis.deprecations.is_empty());
+
+    // The introspection fields resolve to null, as when executed.
+    let schema = Schema::build(Query, Mutation, EmptySubscription)
+        .disable_introspection()
+        .finish();
+    let analysis = schema
+        .analyze("{ __type(name: \"Dog\") { name } pets { name } }")
+        .unwrap();
+    assert_eq!(
+        analysis.fields.into_iter().collect::<Vec<_>>(),
+        ["Pet.name", "Query.pets"]
+    );
+    assert!(!EXECUTED.load(Ordering::SeqCst));
+}
+
+#[cfg(feature = "dynamic-schema")]
+#[tokio::test]
+pub async fn test_analyze_dynamic() {
+    use async_graphql::dynamic::*;
+
+    let query = Object::new("Query")
+        .field(Field::new("value", TypeRef::named(TypeRef::INT), |_| {
+            FieldFuture::new(async { Ok(Some(Value::from(1))) })
+        }))
+        .field(
+            Field::new("oldValue", TypeRef::named(TypeRef::INT), |_| {
+                FieldFuture::new(async { Ok(Some(Value::from(1))) })
+            })
+            .deprecation(Some("Use `value`")),
+        );
+    let schema = dynamic::Schema::build("Query", None, None)
+        .register(query)
+        .finish()
+        .unwrap();
+
+    let analysis = schema.analyze("{ value oldValue }").unwrap();
+    assert_eq!(analysis.operation_type, OperationType::Query);
+    assert_eq!(analysis.complexity, 2);
+    assert_eq!(
+        analysis.fields.into_iter().collect::<Vec<_>>(),
+        ["Query.oldValue", "Query.value"]
+    );
+    assert_eq!(analysis.deprecations[0].coordinate, "Query.oldValue");
+}
@@ -0,0 +1,451 @@
+use async_graphql::*;
+
+#[derive(SimpleObject)]
+#[graphql(requires_scopes = "read:account")]
+struct Account {
+    id: i32,
+    #[graphql(authenticated)]
+    email: String,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn public(&self) -> i32 {
+        1
+    }
+
+    #[graphql(authenticated)]
+    async fn me(&self) -> Option<String> {
+        Some("alice".to_string())
+    }
+
+    #[graphql(requires_scopes = "read:a read:b", requires_scopes = "admin")]
+    async fn secret(&self) -> Option<i32> {
+        Some(2)
+    }
+
+    #[graphql(requires_scopes = "admin")]
+    async fn required(&self) -> i32 {
+        3
+    }
+
+    async fn account(&self) -> Option<Account> {
+        Some(Account {
+            id: 10,
+            email: "alice@example.com".to_string(),
+        })
+    }
+}
+
+fn build_schema(authorization: Authorization) -> Schema<Query, EmptyMutation, EmptySubscription> {
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .authorization(authorization)
+        .finish()
+}
+
+fn error_codes(resp: &Response) -> Vec<(String, String)> {
+    resp.errors
+        .iter()
+        .map(|err| {
+            (
+                err.path
+                    .iter()
+                    .map(|segment| match segment {
+                        PathSegment::Field(name) => name.clone(),
+                        PathSegment::Index(idx) => idx.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("."),
+                err.extensions
+                    .as_ref()
+                    .unwrap()
+                    .get("code")
+                    .unwrap()
+                    .to_string(),
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+pub async fn test_authorization_requires_scopes() {
+    let schema = build_schema(Authorization::new(ScopesFromData));
+    let query = "{ public secret }";
+
+    let resp = schema.execute(query).await;
+    assert_eq!(resp.data, value!({ "public": 1, "secret": null }));
+    assert_eq!(
+        error_codes(&resp),
+        vec![(
+            "secret".to_string(),
+            r#""UNAUTHORIZED_FIELD_OR_TYPE""#.to_string()
+        )]
+    );
+
+    for (scopes, authorized) in [
+        (vec!["read:a"], false),
+        (vec!["read:a", "read:b"], true),
+        (vec!["admin"], true),
+        (vec![], false),
+    ] {
+        let resp = schema
+            .execute(Request::new(query).data(Scopes::new(scopes.clone())))
+            .await;
+        assert_eq!(
+            resp.data,
+            if authorized {
+                value!({ "public": 1, "secret": 2 })
+            } else {
+                value!({ "public": 1, "secret": null })
+            },
+            "{:?}",
+            scopes
+        );
+        assert_eq!(resp.errors.is_empty(), authorized);
+    }
+}
+
+#[tokio::test]
+pub async fn test_authorization_authenticated() {
+    let schema = build_schema(Authorization::new(ScopesFromData));
+
+    let resp = schema.execute("{ me }").await;
+    assert_eq!(resp.data, value!({ "me": null }));
+    assert_eq!(resp.errors[0].message, "Unauthorized field or type");
+
+    let resp = schema
+        .execute(Request::new("{ me }").data(Scopes::default()))
+        .await;
+    assert_eq!(resp.into_result().unwrap().data, value!({ "me": "alice" }));
+}
+
+#[tokio::test]
+pub async fn test_authorization_non_null() {
+    let schema = build_schema(Authorization::new(ScopesFromData));
+
+    let resp = schema.execute("{ public required }").await;
+    assert_eq!(resp.data, Value::Null);
+    assert_eq!(
+        error_codes(&resp),
+        vec![(
+            "required".to_string(),
+            r#""UNAUTHORIZED_FIELD_OR_TYPE""#.to_string()
+        )]
+    );
+}
+
+#[tokio::test]
+pub async fn test_authorization_type() {
+    let schema = build_schema(Authorization::new(ScopesFromData));
+    let query = "{ account { id email } }";
+
+    let resp = schema.execute(query).await;
+    assert_eq!(resp.data, value!({ "account": null }));
+    assert_eq!(
+        error_codes(&resp),
+        vec![(
+            "account".to_string(),
+            r#""UNAUTHORIZED_FIELD_OR_TYPE""#.to_string()
+        )]
+    );
+
+    let resp = schema
+        .execute(Request::new(query).data(Scopes::new(["read:account"])))
+        .await;
+    assert_eq!(
+        resp.into_result().unwrap().data,
+        value!({ "account": { "id": 10, "email": "alice@example.com" } })
+    );
+}
+
+#[tokio::test]
+pub async fn test_authorization_disabled() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let resp = schema.execute("{ me secret required }").await;
+    assert_eq!(
+        resp.into_result().unwrap().data,
+        value!({ "me": "alice", "secret": 2, "required": 3 })
+    );
+}
+
+#[tokio::test]
+pub async fn test_authorization_introspection() {
+    let query = r#"{
+        query: __type(name: "Query") { fields { name } }
+        account: __type(name: "Account") { name }
+    }"#;
+
+    let schema = build_schema(Authorization::new(ScopesFromData));
+    let resp = schema.execute(query).await.into_result().unwrap();
+    assert_eq!(
+        resp.data,
+        value!({
+            "query": {
+                "fields": [
+                    { "name": "public" },
+                    { "name": "me" },
+                    { "name": "secret" },
+                    { "name": "required" },
+                    { "name": "account" },
+                ]
+            },
+            "account": { "name": "Account" },
+        })
+    );
+
+    let schema = build_schema(Authorization::new(ScopesFromData).hide_unauthorized());
+    let resp = schema.execute(query).await.into_result().unwrap();
+    assert_eq!(
+        resp.data,
+        value!({
+            "query": { "fields": [{ "name": "public" }] },
+            "account": null,
+        })
+    );
+
+    let resp = schema
+        .execute(Request::new(query).data(Scopes::new(["read:account"])))
+        .await
+        .into_result()
+        .unwrap();
+    assert_eq!(
+        resp.data,
+        value!({
+            "query": {
+                "fields": [
+                    { "name": "public" },
+                    { "name": "me" },
+                    { "name": "account" },
+                ]
+            },
+            "account": { "name": "Account" },
+        })
+    );
+}
+
+#[tokio::test]
+pub async fn test_authorization_sdl() {
+    let sdl = Schema::new(Query, EmptyMutation, EmptySubscription)
+        .sdl_with_options(SDLExportOptions::new().federation().compose_directive());
+    assert!(sdl.contains("me: String @authenticated"));
+    assert!(
+        sdl.contains(r#"secret: Int @requiresScopes(scopes: [["read:a", "read:b"], ["admin"]])"#)
+    );
+    assert!(sdl.contains(r#"type Account @requiresScopes(scopes: [["read:account"]])"#));
+    assert!(sdl.contains("email: String! @authenticated"));
+}
+
+#[cfg(feature = "dynamic-schema")]
+#[tokio::test]
+pub async fn test_authorization_dynamic_schema() {
+    use async_graphql::dynamic::*;
+
+    let query = Object::new("Query")
+        .field(Field::new(
+            "public",
+            TypeRef::named_nn(TypeRef::INT),
+            |_| FieldFuture::new(async { Ok(Some(Value::from(1))) }),
+        ))
+        .field(
+            Field::new("me", TypeRef::named(TypeRef::STRING), |_| {
+                FieldFuture::new(async { Ok(Some(Value::from("alice"))) })
+            })
+            .authenticated(),
+        )
+        .field(
+            Field::new("secret", TypeRef::named(TypeRef::INT), |_| {
+                FieldFuture::new(async { Ok(Some(Value::from(2))) })
+            })
+            .requires_scopes(["admin"]),
+        );
+    let schema = Schema::build("Query", None, None)
+        .register(query)
+        .authorization(Authorization::new(ScopesFromData))
+        .finish()
+        .unwrap();
+
+    let resp = schema.execute("{ public me secret }").await;
+    assert_eq!(
+        resp.data,
+        value!({ "public": 1, "me": null, "secret": null })
+    );
+    assert_eq!(resp.errors.len(), 2);
+
+    let resp = schema
+        .execute(Request::new("{ public me secret }").data(Scopes::new(["admin"])))
+        .await;
+    assert_eq!(
+        resp.into_result().unwrap().data,
+        value!({ "public": 1, "me": "alice", "secret": 2 })
+    );
+}
+
+#[tokio::test]
+pub async fn test_authorization_subscription() {
+    use futures_util::stream::{self, Stream, StreamExt};
+
+    struct Subscription;
+
+    #[Subscription]
+    impl Subscription {
+        async fn values(&self) -> impl Stream<Item = i32> {
+            stream::iter([1, 2])
+        }
+
+        #[graphql(requires_scopes = "admin")]
+        async fn secrets(&self) -> impl Stream<Item = i32> {
+            stream::iter([3, 4])
+        }
+
+        async fn accounts(&self) -> impl Stream<Item = Account> {
+            stream::iter([Account {
+                id: 10,
+                email: "alice@example.com".to_string(),
+            }])
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, Subscription)
+        .authorization(Authorization::new(ScopesFromData))
+        .finish();
+
+    let responses = schema
+        .execute_stream("subscription { values }")
+        .map(|resp| resp.into_result().unwrap().data)
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(
+        responses,
+        vec![value!({ "values": 1 }), value!({ "values": 2 })]
+    );
+
+    for query in [
+        "subscription { secrets }",
+        "subscription { accounts { id } }",
+    ] {
+        let responses = schema.execute_stream(query).collect::<Vec<_>>().await;
+        assert_eq!(responses.len(), 1);
+        assert_eq!(
+            responses[0].errors[0].code(),
+            Some(ErrorCode::UnauthorizedFieldOrType)
+        );
+    }
+
+    let responses = schema
+        .execute_stream(Request::new("subscription { secrets }").data(Scopes::new(["admin"])))
+        .map(|resp| resp.into_result().unwrap().data)
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(
+        responses,
+        vec![value!({ "secrets": 3 }), value!({ "secrets": 4 })]
+    );
+}
+
+#[tokio::test]
+pub async fn test_authorization_interface_field() {
+    #[derive(SimpleObject)]
+    struct User {
+        name: String,
+        email: Option<String>,
+    }
+
+    #[derive(Interface)]
+    #[graphql(
+        field(name = "name", ty = "&String"),
+        field(name = "email", ty = "&Option<String>", requires_scopes = "read:email")
+    )]
+    enum Node {
+        User(User),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn node(&self) -> Node {
+            User {
+                name: "alice".to_string(),
+                email: Some("alice@example.com".to_string()),
+            }
+            .into()
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .authorization(Authorization::new(ScopesFromData))
+        .finish();
+
+    // The requirements of the interface field apply to the fields of the
+    // implementations, even when they are queried on the object type.
+    for query in [
+        "{ node { name email } }",
+        "{ node { ... on User { name email } } }",
+    ] {
+        let resp = schema.execute(query).await;
+        assert_eq!(
+            resp.data,
+            value!({ "node": { "name": "alice", "email": null } })
+        );
+        assert_eq!(
+            error_codes(&resp),
+            vec![(
+                "node.email".to_string(),
+                "\"UNAUTHORIZED_FIELD_OR_TYPE\"".to_string()
+            )]
+        );
+
+        let resp = schema
+            .execute(Request::new(query).data(Scopes::new(["read:email"])))
+            .await;
+        assert_eq!(
+            resp.into_result().unwrap().data,
+            value!({ "node": { "name": "alice", "email": "alice@example.com" } })
+        );
+    }
+}
+
+#[cfg(feature = "dynamic-schema")]
+#[tokio::test]
+pub async fn test_authorization_dynamic_subscription() {
+    use async_graphql::dynamic::*;
+    use futures_util::StreamExt;
+
+    let query =
+        Object::new("Query").field(Field::new("value", TypeRef::named_nn(TypeRef::INT), |_| {
+            FieldFuture::new(async { Ok(Some(Value::from(1))) })
+        }));
+    let subscription = Subscription::new("Subscription").field(
+        SubscriptionField::new("secrets", TypeRef::named_nn(TypeRef::INT), |_| {
+            SubscriptionFieldFuture::new(async {
+                Ok(futures_util::stream::iter([Ok(Value::from(3))]))
+            })
+        })
+        .requires_scopes(["admin"]),
+    );
+    let schema = Schema::build("Query", None, Some("Subscription"))
+        .register(query)
+        .register(subscription)
+        .authorization(Authorization::new(ScopesFromData))
+        .finish()
+        .unwrap();
+
+    let responses = schema
+        .execute_stream("subscription { secrets }")
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(responses.len(), 1);
+    assert_eq!(
+        responses[0].errors[0].code(),
+        Some(ErrorCode::UnauthorizedFieldOrType)
+    );
+
+    let responses = schema
+        .execute_stream(Request::new("subscription { secrets }").data(Scopes::new(["admin"])))
+        .map(|resp| resp.into_result().unwrap().data)
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(responses, vec![value!({ "secrets": 3 })]);
+}
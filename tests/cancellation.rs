@@ -0,0 +1,182 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use async_graphql::{
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextExecute},
+    *,
+};
+
+#[derive(Default, Clone)]
+struct Cancelled(Arc<AtomicBool>);
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn is_cancelled(&self, ctx: &Context<'_>) -> bool {
+        ctx.is_cancelled()
+    }
+
+    async fn slow(&self, ctx: &Context<'_>) -> i32 {
+        let token = ctx.cancellation_token().clone();
+        let cancelled = ctx.data_unchecked::<Cancelled>().clone();
+        tokio::spawn(async move {
+            token.cancelled().await;
+            cancelled.0.store(true, Ordering::SeqCst);
+        });
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        1
+    }
+}
+
+#[tokio::test]
+pub async fn test_cancellation_token() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let resp = schema.execute("{ isCancelled }").await;
+    assert_eq!(resp.data, value!({ "isCancelled": false }));
+
+    let token = CancellationToken::new();
+    token.cancel();
+    let resp = schema
+        .execute(Request::new("{ isCancelled }").cancellation_token(token))
+        .await;
+    assert_eq!(resp.data, value!({ "isCancelled": true }));
+}
+
+#[tokio::test]
+pub async fn test_cancel_on_drop() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(Cancelled::default())
+        .finish();
+    let cancelled = schema.data::<Cancelled>().unwrap().clone();
+
+    let request = Request::new("{ slow }");
+    let cancel = request.cancellation_token.clone().drop_guard();
+    let execute = async move {
+        let resp = schema.execute(request).await;
+        cancel.disarm();
+        resp
+    };
+    assert!(
+        tokio::time::timeout(Duration::from_millis(50), execute)
+            .await
+            .is_err()
+    );
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(cancelled.0.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+pub async fn test_cancellation_token_in_extension_context() {
+    struct MyExtensionImpl(Cancelled);
+
+    #[async_trait::async_trait]
+    impl Extension for MyExtensionImpl {
+        async fn execute(
+            &self,
+            ctx: &ExtensionContext<'_>,
+            operation_name: Option<&str>,
+            next: NextExecute<'_>,
+        ) -> Response {
+            self.0
+                .0
+                .store(ctx.cancellation_token().is_cancelled(), Ordering::SeqCst);
+            next.run(ctx, operation_name).await
+        }
+    }
+
+    struct MyExtension(Cancelled);
+
+    impl ExtensionFactory for MyExtension {
+        fn create(&self) -> Arc<dyn Extension> {
+            Arc::new(MyExtensionImpl(self.0.clone()))
+        }
+    }
+
+    let cancelled = Cancelled::default();
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(MyExtension(cancelled.clone()))
+        .finish();
+
+    let token = CancellationToken::new();
+    token.cancel();
+    schema
+        .execute(Request::new("{ isCancelled }").cancellation_token(token))
+        .await;
+    assert!(cancelled.0.load(Ordering::SeqCst));
+}
+
+#[cfg(all(feature = "dataloader", feature = "tokio"))]
+#[tokio::test]
+pub async fn test_dataloader_stops_when_cancelled() {
+    use std::{collections::HashMap, time::Instant};
+
+    use async_graphql::{
+        dataloader::{DataLoader, Loader},
+        runtime::{TokioSpawner, TokioTimer},
+    };
+
+    struct DropGuard(Cancelled);
+
+    impl Drop for DropGuard {
+        fn drop(&mut self) {
+            self.0.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    struct SlowLoader(Cancelled);
+
+    #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+    impl Loader<i32> for SlowLoader {
+        type Value = i32;
+        type Error = Arc<String>;
+
+        async fn load(&self, keys: &[i32]) -> Result<HashMap<i32, i32>, Self::Error> {
+            let _guard = DropGuard(self.0.clone());
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok(keys.iter().map(|key| (*key, *key)).collect())
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self, ctx: &Context<'_>) -> Result<Option<i32>> {
+            let loader = ctx.data_unchecked::<DataLoader<SlowLoader>>();
+            Ok(loader.load_one_with_context(ctx, 1).await?)
+        }
+    }
+
+    let cancelled = Cancelled::default();
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(DataLoader::new(
+            SlowLoader(cancelled.clone()),
+            TokioSpawner::current(),
+            TokioTimer::default(),
+        ))
+        .finish();
+
+    let token = CancellationToken::new();
+    tokio::spawn({
+        let token = token.clone();
+        async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            token.cancel();
+        }
+    });
+    let start = Instant::now();
+    let resp = schema
+        .execute(Request::new("{ value }").cancellation_token(token))
+        .await;
+    assert!(start.elapsed() < Duration::from_secs(1));
+    assert_eq!(resp.data, value!({ "value": null }));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(cancelled.0.load(Ordering::SeqCst));
+}
@@ -330,6 +330,10 @@ pub async fn test_complex_object_with_generic_concrete_type() {
         })
     );
 
+    #[cfg(not(feature = "int64_as_long"))]
+    let i64_type = "Int";
+    #[cfg(feature = "int64_as_long")]
+    let i64_type = "Long";
     assert_eq!(
         schema
             .execute(r#"{ __type(name: "MyObji64f32") { fields { name type { kind ofType { name } } } } }"#)
@@ -344,7 +348,7 @@ pub async fn test_complex_object_with_generic_concrete_type() {
                         "name": "a",
                         "type": {
                             "kind": "NON_NULL",
-                            "ofType": { "name": "Int" },
+                            "ofType": { "name": i64_type },
                         },
                     },
                     {
@@ -0,0 +1,127 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_graphql::*;
+
+#[derive(Default)]
+struct Counter {
+    current: AtomicUsize,
+    max: AtomicUsize,
+}
+
+impl Counter {
+    async fn track<T>(&self, value: T) -> T {
+        let current = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max.fetch_max(current, Ordering::SeqCst);
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+        self.current.fetch_sub(1, Ordering::SeqCst);
+        value
+    }
+}
+
+struct Item(i32);
+
+#[Object]
+impl Item {
+    async fn value(&self, ctx: &Context<'_>) -> i32 {
+        ctx.data_unchecked::<Counter>().track(self.0).await
+    }
+}
+
+#[derive(SimpleObject)]
+struct Values {
+    #[graphql(max_concurrency = 2)]
+    items: Vec<Item>,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn items(&self) -> Vec<Item> {
+        (0..100).map(Item).collect()
+    }
+
+    #[graphql(max_concurrency = 4)]
+    async fn limited_items(&self) -> Vec<Item> {
+        (0..100).map(Item).collect()
+    }
+
+    async fn values(&self) -> Values {
+        Values {
+            items: (0..100).map(Item).collect(),
+        }
+    }
+
+    async fn a(&self, ctx: &Context<'_>) -> i32 {
+        ctx.data_unchecked::<Counter>().track(1).await
+    }
+
+    async fn b(&self, ctx: &Context<'_>) -> i32 {
+        ctx.data_unchecked::<Counter>().track(2).await
+    }
+
+    async fn c(&self, ctx: &Context<'_>) -> i32 {
+        ctx.data_unchecked::<Counter>().track(3).await
+    }
+}
+
+async fn max_concurrency(
+    builder: SchemaBuilder<Query, EmptyMutation, EmptySubscription>,
+    query: &str,
+) -> (Value, usize) {
+    let schema = builder.data(Counter::default()).finish();
+    let resp = schema.execute(query).await;
+    assert!(resp.errors.is_empty(), "{:?}", resp.errors);
+    let counter = schema.data::<Counter>().unwrap();
+    (resp.data, counter.max.load(Ordering::SeqCst))
+}
+
+fn items() -> Value {
+    Value::List((0..100).map(|value| value!({ "value": value })).collect())
+}
+
+#[tokio::test]
+pub async fn test_unlimited_concurrency() {
+    let builder = Schema::build(Query, EmptyMutation, EmptySubscription);
+    let (data, max) = max_concurrency(builder, "{ items { value } }").await;
+    assert_eq!(data, value!({ "items": items() }));
+    assert_eq!(max, 100);
+}
+
+#[tokio::test]
+pub async fn test_field_max_concurrency() {
+    let builder = Schema::build(Query, EmptyMutation, EmptySubscription);
+    let (data, max) = max_concurrency(builder, "{ limitedItems { value } }").await;
+    assert_eq!(data, value!({ "limitedItems": items() }));
+    assert_eq!(max, 4);
+
+    let builder = Schema::build(Query, EmptyMutation, EmptySubscription);
+    let (data, max) = max_concurrency(builder, "{ values { items { value } } }").await;
+    assert_eq!(data, value!({ "values": { "items": items() } }));
+    assert_eq!(max, 2);
+}
+
+#[tokio::test]
+pub async fn test_schema_concurrency_limit() {
+    let builder = Schema::build(Query, EmptyMutation, EmptySubscription).limit_concurrency(8);
+    let (data, max) = max_concurrency(builder, "{ items { value } }").await;
+    assert_eq!(data, value!({ "items": items() }));
+    assert_eq!(max, 8);
+
+    let builder = Schema::build(Query, EmptyMutation, EmptySubscription).limit_concurrency(2);
+    let (data, max) = max_concurrency(builder, "{ a b c }").await;
+    assert_eq!(data, value!({ "a": 1, "b": 2, "c": 3 }));
+    assert_eq!(max, 2);
+
+    // The limit of a field lowers the limit of the schema, but cannot raise it.
+    let builder = Schema::build(Query, EmptyMutation, EmptySubscription).limit_concurrency(8);
+    let (_, max) = max_concurrency(builder, "{ limitedItems { value } }").await;
+    assert_eq!(max, 4);
+
+    let builder = Schema::build(Query, EmptyMutation, EmptySubscription).limit_concurrency(3);
+    let (data, max) = max_concurrency(builder, "{ limitedItems { value } }").await;
+    assert_eq!(data, value!({ "limitedItems": items() }));
+    assert_eq!(max, 3);
+}
@@ -225,3 +225,298 @@ pub async fn test_connection_nodes_disabled() {
         "Unknown field \"nodes\" on type \"IntConnection\"."
     );
 }
+
+#[tokio::test]
+pub async fn test_keyset_paginator() {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    #[derive(SimpleObject, Clone)]
+    struct Item {
+        group: i32,
+        id: i32,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn items(
+            &self,
+            ctx: &Context<'_>,
+            after: Option<String>,
+            before: Option<String>,
+            first: Option<i32>,
+            last: Option<i32>,
+        ) -> Result<Connection<OpaqueCursor<(i32, i32)>, Item, TotalCount>> {
+            let counts = ctx.data_unchecked::<Arc<AtomicUsize>>().clone();
+            Paginator::new(after, before, first, last)?
+                .max_page_size(4)
+                .load_with_total_count(
+                    ctx,
+                    |item: &Item| (item.group, item.id),
+                    |query| async move {
+                        let mut items: Vec<Item> = (0..10)
+                            .map(|id| Item { group: id / 3, id })
+                            .filter(|item| {
+                                let key = (item.group, item.id);
+                                query.after.is_none_or(|after| key > after)
+                                    && query.before.is_none_or(|before| key < before)
+                            })
+                            .collect();
+                        if query.order == KeysetOrder::Descending {
+                            items.reverse();
+                        }
+                        items.truncate(query.limit.unwrap_or(usize::MAX));
+                        Ok::<_, Error>(items)
+                    },
+                    || async move {
+                        counts.fetch_add(1, Ordering::SeqCst);
+                        Ok::<_, Error>(10)
+                    },
+                )
+                .await
+        }
+    }
+
+    let counts = Arc::new(AtomicUsize::new(0));
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(counts.clone())
+        .finish();
+    let cursor = |group: i32, id: i32| OpaqueCursor((group, id)).encode_cursor();
+    let execute = |args: String| {
+        let schema = schema.clone();
+        async move {
+            schema
+                .execute(format!(
+                    "{{ items({args}) {{ nodes {{ id }} pageInfo {{ hasPreviousPage hasNextPage }} }} }}"
+                ))
+                .await
+                .into_result()
+                .map(|resp| resp.data)
+        }
+    };
+    let page = |ids: &[i32], has_previous_page: bool, has_next_page: bool| {
+        let nodes: Vec<Value> = ids.iter().map(|id| value!({ "id": id })).collect();
+        value!({
+            "items": {
+                "nodes": nodes,
+                "pageInfo": {
+                    "hasPreviousPage": has_previous_page,
+                    "hasNextPage": has_next_page,
+                },
+            }
+        })
+    };
+
+    assert_eq!(
+        execute("first: 3".to_string()).await.unwrap(),
+        page(&[0, 1, 2], false, true)
+    );
+    assert_eq!(
+        execute(format!("first: 3, after: {:?}", cursor(0, 2)))
+            .await
+            .unwrap(),
+        page(&[3, 4, 5], true, true)
+    );
+    assert_eq!(
+        execute(format!("first: 4, after: {:?}", cursor(2, 7)))
+            .await
+            .unwrap(),
+        page(&[8, 9], true, false)
+    );
+    assert_eq!(
+        execute("last: 3".to_string()).await.unwrap(),
+        page(&[7, 8, 9], true, false)
+    );
+    assert_eq!(
+        execute(format!("last: 2, before: {:?}", cursor(1, 3)))
+            .await
+            .unwrap(),
+        page(&[1, 2], true, true)
+    );
+    assert_eq!(
+        execute(format!(
+            "after: {:?}, before: {:?}",
+            cursor(0, 1),
+            cursor(2, 6)
+        ))
+        .await
+        .unwrap(),
+        page(&[2, 3, 4, 5], true, true)
+    );
+    assert_eq!(
+        execute("first: null".to_string()).await.unwrap(),
+        page(&[0, 1, 2, 3], false, true)
+    );
+    assert_eq!(
+        execute("first: 5".to_string()).await.unwrap_err()[0].message,
+        "The \"first\" parameter must be less than or equal to 4"
+    );
+    assert_eq!(counts.load(Ordering::SeqCst), 0);
+
+    assert_eq!(
+        schema
+            .execute("{ items(first: 1) { totalCount nodes { id } } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "items": { "totalCount": 10, "nodes": [{ "id": 0 }] } })
+    );
+    assert_eq!(counts.load(Ordering::SeqCst), 1);
+}
+
+#[cfg(feature = "secure_cursor")]
+#[tokio::test]
+pub async fn test_secure_cursors() {
+    use base64::Engine;
+
+    struct Query;
+
+    #[derive(SimpleObject)]
+    struct Number {
+        value: i32,
+    }
+
+    #[Object]
+    impl Query {
+        async fn signed(
+            &self,
+            ctx: &Context<'_>,
+            after: Option<String>,
+            first: Option<i32>,
+        ) -> Result<Connection<SignedCursor<i32>, i32>> {
+            Paginator::new_with_context(ctx, after, None, first, None)?
+                .load(
+                    |n: &i32| *n,
+                    |query| async move { Ok::<_, Error>(numbers(query)) },
+                )
+                .await
+        }
+
+        async fn encrypted(
+            &self,
+            ctx: &Context<'_>,
+            after: Option<String>,
+            first: Option<i32>,
+        ) -> Result<Connection<EncryptedCursor<i32>, Number>> {
+            Paginator::new_with_context(ctx, after, None, first, None)?
+                .load(
+                    |n: &Number| n.value,
+                    |query| async move {
+                        let numbers = numbers(query).into_iter().map(|value| Number { value });
+                        Ok::<_, Error>(numbers.collect())
+                    },
+                )
+                .await
+        }
+    }
+
+    fn numbers(query: KeysetQuery<i32>) -> Vec<i32> {
+        let mut numbers: Vec<i32> = (0..10)
+            .filter(|n| query.after.is_none_or(|after| *n > after))
+            .collect();
+        if query.order == KeysetOrder::Descending {
+            numbers.reverse();
+        }
+        numbers.truncate(query.limit.unwrap_or(usize::MAX));
+        numbers
+    }
+
+    fn schema(keys: CursorKeys) -> Schema<Query, EmptyMutation, EmptySubscription> {
+        Schema::build(Query, EmptyMutation, EmptySubscription)
+            .data(keys)
+            .finish()
+    }
+
+    async fn page(
+        schema: &Schema<Query, EmptyMutation, EmptySubscription>,
+        field: &str,
+        after: Option<&str>,
+    ) -> std::result::Result<(Vec<i64>, String), Vec<ServerError>> {
+        let selection = if field == "encrypted" {
+            "{ value }"
+        } else {
+            ""
+        };
+        let after = after
+            .map(|after| format!(", after: {after:?}"))
+            .unwrap_or_default();
+        let data = schema
+            .execute(format!(
+                "{{ {field}(first: 2{after}) {{ nodes {selection} pageInfo {{ endCursor }} }} }}"
+            ))
+            .await
+            .into_result()?
+            .data
+            .into_json()
+            .unwrap();
+        Ok((
+            data[field]["nodes"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|node| node.get("value").unwrap_or(node).as_i64().unwrap())
+                .collect(),
+            data[field]["pageInfo"]["endCursor"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        ))
+    }
+
+    let old_schema = schema(CursorKeys::new("old"));
+    let new_schema = schema(CursorKeys::new("new").previous_key("old"));
+
+    for field in ["signed", "encrypted"] {
+        let (nodes, cursor) = page(&old_schema, field, None).await.unwrap();
+        assert_eq!(nodes, [0, 1]);
+
+        // The cursors of the previous key are still accepted.
+        let (nodes, new_cursor) = page(&new_schema, field, Some(&cursor)).await.unwrap();
+        assert_eq!(nodes, [2, 3]);
+
+        // The cursors of the new key are rejected by the old schema.
+        let errors = page(&old_schema, field, Some(&new_cursor))
+            .await
+            .unwrap_err();
+        assert_eq!(errors[0].message, "Invalid cursor");
+        assert_eq!(errors[0].code(), Some(ErrorCode::BadUserInput));
+
+        // Tampered cursors are rejected.
+        let mut tampered = new_cursor.into_bytes();
+        tampered[2] = if tampered[2] == b'A' { b'B' } else { b'A' };
+        let tampered = String::from_utf8(tampered).unwrap();
+        let errors = page(&new_schema, field, Some(&tampered)).await.unwrap_err();
+        assert_eq!(errors[0].message, "Invalid cursor");
+    }
+
+    // A signed cursor can be read but not forged.
+    let (_, cursor) = page(&new_schema, "signed", None).await.unwrap();
+    let (payload, _) = cursor.split_once('.').unwrap();
+    let engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    assert_eq!(engine.decode(payload).unwrap(), b"1");
+    let forged = format!(
+        "{}.{}",
+        engine.encode("5"),
+        cursor.split_once('.').unwrap().1
+    );
+    let errors = page(&new_schema, "signed", Some(&forged))
+        .await
+        .unwrap_err();
+    assert_eq!(errors[0].message, "Invalid cursor");
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let errors = page(&schema, "signed", Some("abc")).await.unwrap_err();
+    assert_eq!(errors[0].message, "The cursor keys are not configured");
+
+    // Encoding the cursors without the keys is a field error.
+    for field in ["signed", "encrypted"] {
+        let errors = page(&schema, field, None).await.unwrap_err();
+        assert_eq!(errors[0].message, "The cursor keys are not configured");
+        assert_eq!(errors[0].code(), Some(ErrorCode::InternalServerError));
+    }
+}
@@ -0,0 +1,179 @@
+use async_graphql::*;
+
+fn error_code(code: &str) -> Option<ErrorExtensionValues> {
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("code", code);
+    Some(extensions)
+}
+
+#[derive(SimpleObject)]
+struct User {
+    id: ID,
+    name: String,
+    #[graphql(tag = "internal")]
+    email: String,
+    #[graphql(tag = "internal")]
+    audit: Audit,
+}
+
+#[derive(SimpleObject)]
+struct Audit {
+    created_by: String,
+}
+
+#[derive(SimpleObject)]
+#[graphql(tag = "partner")]
+struct Invoice {
+    amount: i32,
+    #[graphql(tag = "internal")]
+    margin: i32,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+enum Role {
+    Member,
+    #[graphql(tag = "internal")]
+    Admin,
+}
+
+#[derive(InputObject)]
+struct UserFilter {
+    name: Option<String>,
+    #[graphql(tag = "internal")]
+    role: Option<Role>,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    #[graphql(tag = "public")]
+    async fn user(&self, filter: Option<UserFilter>) -> User {
+        let _ = filter;
+        User {
+            id: "1".into(),
+            name: "sunli".to_string(),
+            email: "sunli@example.com".to_string(),
+            audit: Audit {
+                created_by: "admin".to_string(),
+            },
+        }
+    }
+
+    #[graphql(tag = "partner")]
+    async fn invoices(&self) -> Vec<Invoice> {
+        vec![Invoice {
+            amount: 10,
+            margin: 2,
+        }]
+    }
+
+    #[graphql(tag = "public")]
+    async fn roles(&self) -> Vec<Role> {
+        vec![Role::Member]
+    }
+}
+
+#[tokio::test]
+pub async fn test_contract_exclude() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .contract(Contract::new().exclude_tag("internal"))
+        .finish();
+
+    let sdl = schema.sdl();
+    assert!(sdl.contains("type User"));
+    assert!(sdl.contains("type Invoice"));
+    assert!(!sdl.contains("email"));
+    assert!(!sdl.contains("margin"));
+    assert!(!sdl.contains("ADMIN"));
+    assert!(!sdl.contains("role: Role"));
+    // `Audit` is only reachable from an excluded field.
+    assert!(!sdl.contains("Audit"));
+
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Role") { enumValues { name } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "__type": { "enumValues": [{ "name": "MEMBER" }] } })
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ __type(name: "Audit") { name } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "__type": null })
+    );
+
+    assert_eq!(
+        schema
+            .execute("{ user { name } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "user": { "name": "sunli" } })
+    );
+    assert_eq!(
+        schema
+            .execute("{ user { email } }")
+            .await
+            .into_result()
+            .unwrap_err()[0]
+            .message,
+        r#"Unknown field "email" on type "User"."#
+    );
+}
+
+#[tokio::test]
+pub async fn test_contract_include() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .contract(
+            Contract::new()
+                .include_tag("partner")
+                .exclude_tag("internal"),
+        )
+        .finish();
+
+    let sdl = schema.sdl();
+    assert!(sdl.contains("invoices: [Invoice!]!"));
+    assert!(sdl.contains("amount: Int!"));
+    assert!(!sdl.contains("margin"));
+    assert!(!sdl.contains("user"));
+    assert!(!sdl.contains("roles"));
+    assert!(!sdl.contains("Role"));
+    assert!(!sdl.contains("UserFilter"));
+}
+
+#[tokio::test]
+pub async fn test_contract_execution() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .contract(Contract::new().exclude_tag("internal"))
+        .validation_mode(ValidationMode::Fast)
+        .finish();
+
+    assert_eq!(
+        schema
+            .execute("{ invoices { amount margin } }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Cannot query field "margin" on type "Invoice"."#.to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 21
+            }],
+            path: vec![
+                PathSegment::Field("invoices".to_string()),
+                PathSegment::Index(0)
+            ],
+            extensions: error_code("GRAPHQL_VALIDATION_FAILED"),
+        }]
+    );
+}
@@ -0,0 +1,272 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use async_graphql::{
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextExecute},
+    *,
+};
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+enum Unit {
+    Meter,
+    #[graphql(deprecation = "Use `METER`")]
+    Foot,
+}
+
+#[derive(InputObject)]
+struct Filter {
+    unit: Option<Unit>,
+    #[graphql(deprecation)]
+    limit: Option<i32>,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn value(&self) -> i32 {
+        10
+    }
+
+    #[graphql(deprecation = "Use `value`")]
+    async fn old_value(&self) -> i32 {
+        10
+    }
+
+    async fn length(
+        &self,
+        unit: Option<Unit>,
+        filter: Option<Filter>,
+        #[graphql(deprecation = "Use `unit`")] metric: Option<bool>,
+    ) -> i32 {
+        let _ = (unit, filter, metric);
+        10
+    }
+}
+
+fn coordinates(resp: &Response) -> Vec<String> {
+    match resp.extensions.get("deprecations") {
+        Some(Value::List(items)) => items
+            .iter()
+            .map(|item| match item {
+                Value::Object(map) => map["coordinate"].to_string(),
+                _ => unreachable!(),
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[tokio::test]
+pub async fn test_deprecation_warn() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .deprecation_reporting(DeprecationReporting::new().warn())
+        .finish();
+
+    let resp = schema.execute("{ value }").await;
+    assert!(resp.errors.is_empty());
+    assert!(!resp.extensions.contains_key("deprecations"));
+
+    let resp = schema
+        .execute(
+            r#"{
+                oldValue
+                length(metric: true, unit: FOOT, filter: { unit: FOOT, limit: 1 })
+            }"#,
+        )
+        .await;
+    assert!(resp.errors.is_empty());
+    assert_eq!(
+        coordinates(&resp),
+        vec![
+            r#""Query.oldValue""#,
+            r#""Query.length(metric:)""#,
+            r#""Unit.FOOT""#,
+            r#""Unit.FOOT""#,
+            r#""Filter.limit""#,
+        ]
+    );
+    assert_eq!(
+        resp.extensions["deprecations"],
+        value!([
+            {
+                "coordinate": "Query.oldValue",
+                "reason": "Use `value`",
+                "locations": [{ "line": 2, "column": 17 }],
+            },
+            {
+                "coordinate": "Query.length(metric:)",
+                "reason": "Use `unit`",
+                "locations": [{ "line": 3, "column": 24 }],
+            },
+            {
+                "coordinate": "Unit.FOOT",
+                "reason": "Use `METER`",
+                "locations": [{ "line": 3, "column": 38 }],
+            },
+            {
+                "coordinate": "Unit.FOOT",
+                "reason": "Use `METER`",
+                "locations": [{ "line": 3, "column": 50 }],
+            },
+            {
+                "coordinate": "Filter.limit",
+                "reason": null,
+                "locations": [{ "line": 3, "column": 50 }],
+            },
+        ])
+    );
+}
+
+#[tokio::test]
+pub async fn test_deprecation_variables() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .deprecation_reporting(DeprecationReporting::new().warn())
+        .finish();
+
+    let query = "query($unit: Unit, $filter: Filter) { length(unit: $unit, filter: $filter) }";
+    let resp = schema
+        .execute(Request::new(query).variables(Variables::from_value(value!({
+            "unit": "METER",
+            "filter": { "limit": 5 },
+        }))))
+        .await;
+    assert!(resp.errors.is_empty());
+    assert_eq!(coordinates(&resp), vec![r#""Filter.limit""#]);
+
+    let resp = schema
+        .execute(Request::new(query).variables(Variables::from_value(value!({
+            "unit": "FOOT",
+        }))))
+        .await;
+    assert!(resp.errors.is_empty());
+    assert_eq!(coordinates(&resp), vec![r#""Unit.FOOT""#]);
+}
+
+#[tokio::test]
+pub async fn test_deprecation_operation_name() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .deprecation_reporting(DeprecationReporting::new().warn())
+        .finish();
+
+    let query = "query A { oldValue } query B { value }";
+    let resp = schema
+        .execute(Request::new(query).operation_name("B"))
+        .await;
+    assert!(resp.errors.is_empty());
+    assert!(!resp.extensions.contains_key("deprecations"));
+
+    let resp = schema
+        .execute(Request::new(query).operation_name("A"))
+        .await;
+    assert_eq!(coordinates(&resp), vec![r#""Query.oldValue""#]);
+}
+
+#[tokio::test]
+pub async fn test_deprecation_reject_after() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .deprecation_reporting(
+            DeprecationReporting::new().reject_after(SystemTime::now() + Duration::from_secs(3600)),
+        )
+        .finish();
+    let resp = schema.execute("{ oldValue }").await;
+    assert!(resp.errors.is_empty());
+    assert!(!resp.extensions.contains_key("deprecations"));
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .deprecation_reporting(
+            DeprecationReporting::new().reject_after(SystemTime::now() - Duration::from_secs(3600)),
+        )
+        .finish();
+    assert_eq!(
+        schema
+            .execute("{ value }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "value": 10 })
+    );
+    assert_eq!(
+        schema
+            .execute("{ oldValue length(unit: FOOT) }")
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![
+            ServerError {
+                message: r#""Query.oldValue" is no longer supported. Use `value`"#.to_string(),
+                source: None,
+                locations: vec![Pos { line: 1, column: 3 }],
+                path: Vec::new(),
+                extensions: Some({
+                    let mut extensions = ErrorExtensionValues::default();
+                    extensions.set("code", "GRAPHQL_VALIDATION_FAILED");
+                    extensions
+                }),
+            },
+            ServerError {
+                message: r#""Unit.FOOT" is no longer supported. Use `METER`"#.to_string(),
+                source: None,
+                locations: vec![Pos {
+                    line: 1,
+                    column: 19
+                }],
+                path: Vec::new(),
+                extensions: Some({
+                    let mut extensions = ErrorExtensionValues::default();
+                    extensions.set("code", "GRAPHQL_VALIDATION_FAILED");
+                    extensions
+                }),
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+pub async fn test_deprecation_extension_context() {
+    #[derive(Default, Clone)]
+    struct Collected(Arc<Mutex<Vec<String>>>);
+
+    struct MyExtensionImpl(Collected);
+
+    #[async_trait::async_trait]
+    impl Extension for MyExtensionImpl {
+        async fn execute(
+            &self,
+            ctx: &ExtensionContext<'_>,
+            operation_name: Option<&str>,
+            next: NextExecute<'_>,
+        ) -> Response {
+            self.0.0.lock().unwrap().extend(
+                ctx.deprecated_usage()
+                    .iter()
+                    .map(|usage| usage.coordinate.clone()),
+            );
+            next.run(ctx, operation_name).await
+        }
+    }
+
+    struct MyExtension(Collected);
+
+    impl ExtensionFactory for MyExtension {
+        fn create(&self) -> Arc<dyn Extension> {
+            Arc::new(MyExtensionImpl(self.0.clone()))
+        }
+    }
+
+    let collected = Collected::default();
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .deprecation_reporting(DeprecationReporting::new())
+        .extension(MyExtension(collected.clone()))
+        .finish();
+    let resp = schema.execute("{ oldValue length(metric: false) }").await;
+    assert!(resp.errors.is_empty());
+    assert!(!resp.extensions.contains_key("deprecations"));
+    assert_eq!(
+        *collected.0.lock().unwrap(),
+        vec!["Query.oldValue", "Query.length(metric:)"]
+    );
+}
@@ -0,0 +1,154 @@
+use async_graphql::*;
+use futures_util::stream::{Stream, StreamExt};
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn not_found(&self) -> Option<Result<i32>> {
+        Some(Err(
+            Error::new("User not found").extend_with(|_, e| e.set("code", "NOT_FOUND"))
+        ))
+    }
+
+    async fn database(&self) -> Option<Result<i32>> {
+        Some(Err(
+            std::io::Error::other("connection refused to 10.0.0.1").into()
+        ))
+    }
+
+    async fn forbidden(&self) -> Option<Result<i32>> {
+        Some(Err(
+            Error::new("Forbidden").extend_with(|_, e| e.set("code", "FORBIDDEN"))
+        ))
+    }
+}
+
+struct Subscription;
+
+#[Subscription]
+impl Subscription {
+    async fn values(&self) -> impl Stream<Item = Result<i32>> {
+        futures_util::stream::iter(vec![Ok(1), Err(Error::new("stream failed"))])
+    }
+}
+
+fn code(err: &ServerError) -> Option<&Value> {
+    err.extensions.as_ref().and_then(|ext| ext.get("code"))
+}
+
+#[tokio::test]
+pub async fn test_mask_internal_errors() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .error_policy(MaskInternalErrors::new())
+        .finish();
+
+    let resp = schema.execute("{ notFound database }").await;
+    assert_eq!(resp.errors.len(), 2);
+
+    assert_eq!(resp.errors[0].message, "User not found");
+    assert_eq!(code(&resp.errors[0]), Some(&value!("NOT_FOUND")));
+
+    let masked = &resp.errors[1];
+    assert_eq!(masked.message, "Internal server error");
+    assert_eq!(
+        masked.path,
+        vec![PathSegment::Field("database".to_string())]
+    );
+    assert_eq!(code(masked), Some(&value!("INTERNAL_SERVER_ERROR")));
+    let correlation_id = masked
+        .extensions
+        .as_ref()
+        .and_then(|ext| ext.get("correlationId"))
+        .unwrap();
+    assert!(matches!(correlation_id, Value::String(id) if id.len() == 32));
+
+    let original = masked.source::<ServerError>().unwrap();
+    assert_eq!(original.message, "connection refused to 10.0.0.1");
+    assert!(original.source::<std::io::Error>().is_some());
+    assert!(!serde_json::to_string(&resp).unwrap().contains("10.0.0.1"));
+
+    // Parse and validation errors are not masked.
+    let resp = schema.execute("{ unknown }").await;
+    assert_eq!(
+        resp.errors[0].message,
+        r#"Unknown field "unknown" on type "Query"."#
+    );
+}
+
+#[tokio::test]
+pub async fn test_mask_internal_errors_options() {
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .error_policy(
+            MaskInternalErrors::new()
+                .message("Something went wrong")
+                .user_code("NOT_FOUND"),
+        )
+        .finish();
+
+    let resp = schema.execute("{ notFound forbidden }").await;
+    assert_eq!(resp.errors[0].message, "User not found");
+    assert_eq!(resp.errors[1].message, "Something went wrong");
+}
+
+#[tokio::test]
+pub async fn test_error_policy_fn() {
+    let schema = Schema::build(Query, EmptyMutation, Subscription)
+        .error_policy(|err: &ServerError| {
+            if err.source::<std::io::Error>().is_some() {
+                ErrorClass::Internal
+            } else {
+                ErrorClass::User
+            }
+        })
+        .finish();
+
+    let resp = schema.execute("{ forbidden database }").await;
+    assert_eq!(resp.errors[0].message, "Forbidden");
+    assert_eq!(resp.errors[1].message, "Internal server error");
+
+    let schema = Schema::build(Query, EmptyMutation, Subscription)
+        .error_policy(MaskInternalErrors::new())
+        .finish();
+    let mut stream = schema.execute_stream("subscription { values }");
+    assert_eq!(
+        stream.next().await.unwrap().into_result().unwrap().data,
+        value!({ "values": 1 })
+    );
+    assert_eq!(
+        stream.next().await.unwrap().errors[0].message,
+        "Internal server error"
+    );
+}
+
+#[cfg(feature = "dynamic-schema")]
+#[tokio::test]
+pub async fn test_error_policy_dynamic() {
+    use async_graphql::dynamic::*;
+
+    let query = Object::new("Query")
+        .field(Field::new("value", TypeRef::named(TypeRef::INT), |_| {
+            FieldFuture::new(async { Err::<Option<FieldValue>, _>("secret".into()) })
+        }))
+        .field(Field::new("user", TypeRef::named(TypeRef::INT), |_| {
+            FieldFuture::new(async {
+                Err::<Option<FieldValue>, _>(
+                    Error::new("User not found").extend_with(|_, e| e.set("code", "NOT_FOUND")),
+                )
+            })
+        }));
+    let schema = dynamic::Schema::build("Query", None, None)
+        .register(query)
+        .error_policy(MaskInternalErrors::new())
+        .finish()
+        .unwrap();
+
+    let resp = schema.execute("{ value }").await;
+    assert_eq!(resp.errors[0].message, "Internal server error");
+    assert_eq!(
+        resp.errors[0].source::<ServerError>().unwrap().message,
+        "secret"
+    );
+    let resp = schema.execute("{ user }").await;
+    assert_eq!(resp.errors[0].message, "User not found");
+}
@@ -42,3 +42,289 @@ async fn test_spaces() {
     let expected = include_str!("schemas/test_space_schema.graphql");
     assert_eq!(sdl, expected);
 }
+
+#[tokio::test]
+async fn test_sdl_from_introspection() {
+    /// A shape
+    #[derive(Interface)]
+    #[graphql(field(name = "area", ty = "&f64"))]
+    enum Shape {
+        Circle(Circle),
+        Square(Square),
+    }
+
+    /// A circle
+    #[derive(SimpleObject)]
+    struct Circle {
+        area: f64,
+        #[graphql(deprecation = "Use `area`")]
+        radius: f64,
+    }
+
+    #[derive(SimpleObject)]
+    struct Square {
+        area: f64,
+    }
+
+    #[derive(Union)]
+    enum Item {
+        Circle(Circle),
+        Square(Square),
+    }
+
+    /// The unit
+    #[derive(Enum, Copy, Clone, Eq, PartialEq)]
+    enum Unit {
+        Meter,
+        #[graphql(deprecation)]
+        Foot,
+    }
+
+    #[derive(InputObject)]
+    struct Filter {
+        #[graphql(default = 10)]
+        limit: i32,
+        unit: Option<Unit>,
+    }
+
+    #[derive(OneofObject)]
+    enum ShapeBy {
+        Id(ID),
+        Name(String),
+    }
+
+    /// A color
+    struct Color(String);
+
+    #[Scalar(specified_by_url = "https://www.w3.org/TR/css-color-4")]
+    impl ScalarType for Color {
+        fn parse(value: Value) -> InputValueResult<Self> {
+            match value {
+                Value::String(s) => Ok(Color(s)),
+                _ => Err(InputValueError::expected_type(value)),
+            }
+        }
+
+        fn to_value(&self) -> Value {
+            Value::String(self.0.clone())
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        /// All the shapes
+        async fn shapes(&self, filter: Option<Filter>) -> Vec<Shape> {
+            let _ = filter;
+            Vec::new()
+        }
+
+        async fn shape(&self, by: ShapeBy, #[graphql(default)] unit: Option<Unit>) -> Option<Item> {
+            let _ = (by, unit);
+            None
+        }
+
+        async fn color(&self) -> Option<Color> {
+            None
+        }
+    }
+
+    struct Mutation;
+
+    #[Object]
+    impl Mutation {
+        async fn reset(&self) -> bool {
+            true
+        }
+    }
+
+    let schema = Schema::new(Query, Mutation, EmptySubscription);
+    let query = r#"
+        query IntrospectionQuery {
+          __schema {
+            queryType { name }
+            mutationType { name }
+            subscriptionType { name }
+            types { ...FullType }
+            directives {
+              name
+              description
+              locations
+              isRepeatable
+              args { ...InputValue }
+            }
+          }
+        }
+
+        fragment FullType on __Type {
+          kind
+          name
+          description
+          specifiedByURL
+          isOneOf
+          fields(includeDeprecated: true) {
+            name
+            description
+            args { ...InputValue }
+            type { ...TypeRef }
+            isDeprecated
+            deprecationReason
+          }
+          inputFields { ...InputValue }
+          interfaces { ...TypeRef }
+          enumValues(includeDeprecated: true) {
+            name
+            description
+            isDeprecated
+            deprecationReason
+          }
+          possibleTypes { ...TypeRef }
+        }
+
+        fragment InputValue on __InputValue {
+          name
+          description
+          type { ...TypeRef }
+          defaultValue
+        }
+
+        fragment TypeRef on __Type {
+          kind
+          name
+          ofType { kind name ofType { kind name ofType { kind name ofType { kind name } } } }
+        }
+    "#;
+    let introspection = schema.execute(query).await.into_result().unwrap();
+    let introspection = serde_json::to_value(&introspection).unwrap();
+    let registry = registry::Registry::from_introspection(introspection).unwrap();
+
+    let options = SDLExportOptions::new().include_specified_by();
+    assert_eq!(
+        registry.export_sdl(options),
+        schema.sdl_with_options(options)
+    );
+    assert_eq!(registry.mutation_type.as_deref(), Some("Mutation"));
+}
+
+#[tokio::test]
+async fn test_constraint_directives() {
+    #[derive(InputObject)]
+    struct CreateUser {
+        #[graphql(validator(chars_min_length = 3, max_length = 20, regex = "^[a-z]+$"))]
+        login: String,
+        #[graphql(validator(minimum = 18, maximum = 150))]
+        age: i32,
+        #[graphql(validator(hostname))]
+        email: String,
+        #[graphql(validator(ascii, alphanumeric, not_blank))]
+        nickname: String,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn users(
+            &self,
+            #[graphql(validator(max_items = 10, unique_items, list, uuid))] ids: Vec<String>,
+            #[graphql(validator(one_of("asc", "desc")))] order: Option<String>,
+            #[graphql(validator(one_of(10, 20, 50)))] page_size: Option<i32>,
+            #[graphql(validator(one_of(0.5, 1.0)))] ratio: Option<f64>,
+        ) -> i32 {
+            let _ = (ids, order, page_size, ratio);
+            0
+        }
+
+        async fn create_user(&self, input: CreateUser) -> i32 {
+            let _ = input;
+            0
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert!(!schema.sdl().contains("@constraint"));
+
+    let sdl = schema.sdl_with_options(SDLExportOptions::new().constraint_directives());
+    assert!(sdl.contains(
+        r#"users(ids: [String!]! @constraint(format: "uuid", maxItems: 10) @validation(uniqueItems: true), order: String @validation(oneOf: ["asc", "desc"]), pageSize: Int @validation(oneOfInt: [10, 20, 50]), ratio: Float @validation(oneOfFloat: [0.5, 1.0])): Int!"#
+    ));
+    assert!(sdl.contains(
+        r#"login: String! @constraint(minLength: 3, maxLength: 20, pattern: "^[a-z]+$")"#
+    ));
+    assert!(sdl.contains("age: Int! @constraint(min: 18, max: 150)"));
+    assert!(sdl.contains("email: String! @constraint(format: \"hostname\")"));
+    assert!(sdl.contains(
+        r#"nickname: String! @validation(formats: ["ascii", "alphanumeric"], notBlank: true)"#
+    ));
+    assert!(sdl.contains("directive @constraint(minLength: Int, maxLength: Int,"));
+    assert!(sdl.contains("directive @validation(formats: [String!], uniqueItems: Boolean,"));
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_constraint_directives()
+        .finish();
+    let sdl = schema.sdl();
+    assert!(sdl.contains("age: Int! @constraint(min: 18, max: 150)"));
+    assert!(sdl.contains("directive @constraint(minLength: Int, maxLength: Int,"));
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    __type(name: "CreateUser") {
+                        inputFields {
+                            name
+                            appliedDirectives { name args { name value } }
+                        }
+                    }
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "__type": {
+                "inputFields": [
+                    {
+                        "name": "login",
+                        "appliedDirectives": [{
+                            "name": "constraint",
+                            "args": [
+                                { "name": "minLength", "value": "3" },
+                                { "name": "maxLength", "value": "20" },
+                                { "name": "pattern", "value": r#""^[a-z]+$""# },
+                            ],
+                        }],
+                    },
+                    {
+                        "name": "age",
+                        "appliedDirectives": [{
+                            "name": "constraint",
+                            "args": [
+                                { "name": "min", "value": "18" },
+                                { "name": "max", "value": "150" },
+                            ],
+                        }],
+                    },
+                    {
+                        "name": "email",
+                        "appliedDirectives": [{
+                            "name": "constraint",
+                            "args": [{ "name": "format", "value": r#""hostname""# }],
+                        }],
+                    },
+                    {
+                        "name": "nickname",
+                        "appliedDirectives": [{
+                            "name": "validation",
+                            "args": [
+                                { "name": "formats", "value": r#"["ascii", "alphanumeric"]"# },
+                                { "name": "notBlank", "value": "true" },
+                            ],
+                        }],
+                    },
+                ]
+            }
+        })
+    );
+}
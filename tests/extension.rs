@@ -533,3 +533,82 @@ pub async fn subscription_execute_with_data() {
         );
     }
 }
+
+#[tokio::test]
+pub async fn test_extension_resolve_info_on_interface() {
+    struct MyObj;
+
+    #[Object]
+    impl MyObj {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "value", ty = "Option<i32>"))]
+    enum MyInterface {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn obj(&self) -> MyInterface {
+            MyObj.into()
+        }
+    }
+
+    struct MyExtensionImpl {
+        fields: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Extension for MyExtensionImpl {
+        async fn resolve(
+            &self,
+            ctx: &ExtensionContext<'_>,
+            info: ResolveInfo<'_>,
+            next: NextResolve<'_>,
+        ) -> ServerResult<Option<ConstValue>> {
+            self.fields.lock().await.push(format!(
+                "{}.{}: {}",
+                info.parent_type, info.name, info.return_type
+            ));
+            next.run(ctx, info).await
+        }
+    }
+
+    struct MyExtension {
+        fields: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl ExtensionFactory for MyExtension {
+        fn create(&self) -> Arc<dyn Extension> {
+            Arc::new(MyExtensionImpl {
+                fields: self.fields.clone(),
+            })
+        }
+    }
+
+    let fields = Arc::new(Mutex::new(Vec::new()));
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(MyExtension {
+            fields: fields.clone(),
+        })
+        .finish();
+    assert_eq!(
+        schema
+            .execute("{ obj { value } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "obj": { "value": 10 } })
+    );
+    assert_eq!(
+        *fields.lock().await,
+        vec!["Query.obj: MyInterface!", "MyObj.value: Int!"]
+    );
+}
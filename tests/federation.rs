@@ -1189,3 +1189,94 @@ pub async fn test_unresolvable_entity() {
         })
     );
 }
+
+#[tokio::test]
+pub async fn test_batch_entity() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(SimpleObject)]
+    struct Product {
+        upc: String,
+        price: i32,
+    }
+
+    #[derive(SimpleObject)]
+    struct User {
+        id: ID,
+    }
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[graphql(entity, batch)]
+        async fn find_products_by_upc(&self, upc: Vec<String>) -> Vec<Result<Product>> {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            upc.into_iter()
+                .map(|upc| {
+                    if upc == "unknown" {
+                        return Err(Error::new("Product not found"));
+                    }
+                    let price = upc.len() as i32;
+                    Ok(Product { upc, price })
+                })
+                .collect()
+        }
+
+        #[graphql(entity)]
+        async fn find_user_by_id(&self, id: ID) -> User {
+            User { id }
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert!(
+        schema
+            .sdl_with_options(SDLExportOptions::new().federation())
+            .contains(r#"type Product @key(fields: "upc")"#)
+    );
+
+    let query = r#"{
+            _entities(representations: [
+                {__typename: "Product", upc: "a"},
+                {__typename: "User", id: "1"},
+                {__typename: "Product", upc: "unknown"},
+                {__typename: "Product", upc: "abc"}
+            ]) {
+                __typename
+                ... on Product { upc price }
+                ... on User { id }
+            }
+        }"#;
+    let resp = schema.execute(query).await;
+    assert_eq!(
+        resp.data,
+        value!({
+            "_entities": [
+                {"__typename": "Product", "upc": "a", "price": 1},
+                {"__typename": "User", "id": "1"},
+                null,
+                {"__typename": "Product", "upc": "abc", "price": 3},
+            ]
+        })
+    );
+    assert_eq!(
+        resp.errors,
+        vec![ServerError {
+            message: "Product not found".to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 2,
+                column: 13
+            }],
+            path: vec![
+                PathSegment::Field("_entities".to_owned()),
+                PathSegment::Index(2)
+            ],
+            extensions: None,
+        }]
+    );
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
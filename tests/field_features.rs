@@ -4,6 +4,13 @@
 use async_graphql::*;
 use futures_util::stream::{Stream, StreamExt};
 
+fn validation_error(rule: &str) -> Option<ErrorExtensionValues> {
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("code", "GRAPHQL_VALIDATION_FAILED");
+    extensions.set("rule", rule);
+    Some(extensions)
+}
+
 #[tokio::test]
 pub async fn test_field_features() {
     #[derive(SimpleObject)]
@@ -69,7 +76,7 @@ pub async fn test_field_features() {
             source: None,
             locations: vec![Pos { column: 3, line: 1 }],
             path: Vec::new(),
-            extensions: None,
+            extensions: validation_error("FieldsOnCorrectType"),
         }]
     );
 
@@ -90,7 +97,7 @@ pub async fn test_field_features() {
             source: None,
             locations: vec![Pos { column: 9, line: 1 }],
             path: Vec::new(),
-            extensions: None,
+            extensions: validation_error("FieldsOnCorrectType"),
         }]
     );
 
@@ -122,7 +129,7 @@ pub async fn test_field_features() {
                 line: 1
             }],
             path: Vec::new(),
-            extensions: None,
+            extensions: validation_error("FieldsOnCorrectType"),
         }]
     );
 }
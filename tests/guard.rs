@@ -1,6 +1,12 @@
 use async_graphql::*;
 use futures_util::stream::{Stream, StreamExt};
 
+fn error_code(code: &str) -> Option<ErrorExtensionValues> {
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("code", code);
+    Some(extensions)
+}
+
 #[derive(Eq, PartialEq, Copy, Clone)]
 enum Role {
     Admin,
@@ -92,7 +98,7 @@ pub async fn test_guard_simple_rule() {
             source: None,
             locations: vec![Pos { line: 1, column: 3 }],
             path: vec![PathSegment::Field("value".to_owned())],
-            extensions: None,
+            extensions: error_code("FORBIDDEN"),
         }]
     );
 
@@ -124,7 +130,7 @@ pub async fn test_guard_simple_rule() {
                 column: 16
             }],
             path: vec![PathSegment::Field("values".to_owned())],
-            extensions: None,
+            extensions: error_code("FORBIDDEN"),
         }]
     );
 }
@@ -168,7 +174,7 @@ pub async fn test_guard_and_operator() {
             source: None,
             locations: vec![Pos { line: 1, column: 3 }],
             path: vec![PathSegment::Field("value".to_owned())],
-            extensions: None,
+            extensions: error_code("FORBIDDEN"),
         }]
     );
 
@@ -188,7 +194,7 @@ pub async fn test_guard_and_operator() {
             source: None,
             locations: vec![Pos { line: 1, column: 3 }],
             path: vec![PathSegment::Field("value".to_owned())],
-            extensions: None,
+            extensions: error_code("FORBIDDEN"),
         }]
     );
 
@@ -208,7 +214,7 @@ pub async fn test_guard_and_operator() {
             source: None,
             locations: vec![Pos { line: 1, column: 3 }],
             path: vec![PathSegment::Field("value".to_owned())],
-            extensions: None,
+            extensions: error_code("FORBIDDEN"),
         }]
     );
 }
@@ -278,7 +284,7 @@ pub async fn test_guard_or_operator() {
             source: None,
             locations: vec![Pos { line: 1, column: 3 }],
             path: vec![PathSegment::Field("value".to_owned())],
-            extensions: None,
+            extensions: error_code("FORBIDDEN"),
         }]
     );
 }
@@ -340,7 +346,7 @@ pub async fn test_guard_use_params() {
             source: None,
             locations: vec![Pos { line: 1, column: 3 }],
             path: vec![PathSegment::Field("get".to_owned())],
-            extensions: None,
+            extensions: error_code("FORBIDDEN"),
         }]
     );
 }
@@ -376,7 +382,7 @@ pub async fn test_guard_on_simple_object() {
             source: None,
             locations: vec![Pos { line: 1, column: 3 }],
             path: vec![PathSegment::Field("value".to_owned())],
-            extensions: None,
+            extensions: error_code("FORBIDDEN"),
         }]
     );
 }
@@ -413,7 +419,7 @@ pub async fn test_guard_on_simple_object_field() {
             source: None,
             locations: vec![Pos { line: 1, column: 3 }],
             path: vec![PathSegment::Field("value".to_owned())],
-            extensions: None,
+            extensions: error_code("FORBIDDEN"),
         }]
     );
 }
@@ -452,7 +458,7 @@ pub async fn test_guard_on_object() {
             source: None,
             locations: vec![Pos { line: 1, column: 3 }],
             path: vec![PathSegment::Field("value".to_owned())],
-            extensions: None,
+            extensions: error_code("FORBIDDEN"),
         }]
     );
 }
@@ -492,7 +498,7 @@ pub async fn test_guard_on_object_field() {
             source: None,
             locations: vec![Pos { line: 1, column: 3 }],
             path: vec![PathSegment::Field("value".to_owned())],
-            extensions: None,
+            extensions: error_code("FORBIDDEN"),
         }]
     );
 }
@@ -535,7 +541,7 @@ pub async fn test_guard_on_complex_object() {
             source: None,
             locations: vec![Pos { line: 1, column: 3 }],
             path: vec![PathSegment::Field("value2".to_owned())],
-            extensions: None,
+            extensions: error_code("FORBIDDEN"),
         }]
     );
 }
@@ -579,7 +585,7 @@ pub async fn test_guard_on_complex_object_field() {
             source: None,
             locations: vec![Pos { line: 1, column: 3 }],
             path: vec![PathSegment::Field("value2".to_owned())],
-            extensions: None,
+            extensions: error_code("FORBIDDEN"),
         }]
     );
 }
@@ -622,7 +628,431 @@ pub async fn test_guard_with_fn() {
             source: None,
             locations: vec![Pos { line: 1, column: 3 }],
             path: vec![PathSegment::Field("value".to_owned())],
-            extensions: None,
+            extensions: error_code("FORBIDDEN"),
+        }]
+    );
+}
+
+#[derive(SimpleObject)]
+struct Order {
+    owner: String,
+    total: i32,
+}
+
+struct OwnerGuard;
+
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl ResultGuard<Order> for OwnerGuard {
+    async fn check(&self, ctx: &Context<'_>, order: &Order) -> Result<GuardAction> {
+        match ctx.data_opt::<Username>() {
+            Some(username) if username.0 == order.owner => Ok(GuardAction::Allow),
+            Some(_) => Ok(GuardAction::Redact),
+            None => Err("Forbidden".into()),
+        }
+    }
+}
+
+#[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+impl ResultGuard<Option<Order>> for OwnerGuard {
+    async fn check(&self, ctx: &Context<'_>, order: &Option<Order>) -> Result<GuardAction> {
+        match order {
+            Some(order) => ResultGuard::<Order>::check(self, ctx, order).await,
+            None => Ok(GuardAction::Allow),
+        }
+    }
+}
+
+#[tokio::test]
+pub async fn test_guard_result_on_object_field() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[graphql(guard_result = "OwnerGuard")]
+        async fn order(&self, owner: String) -> Option<Order> {
+            Some(Order { owner, total: 10 })
+        }
+
+        #[graphql(guard_result = "OwnerGuard")]
+        fn required_order(&self, owner: String) -> Result<Order> {
+            Ok(Order { owner, total: 20 })
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    let query = r#"{ order(owner: "alice") { total } requiredOrder(owner: "alice") { total } }"#;
+    assert_eq!(
+        schema
+            .execute(Request::new(query).data(Username("alice".to_string())))
+            .await
+            .data,
+        value!({ "order": { "total": 10 }, "requiredOrder": { "total": 20 } })
+    );
+
+    let query = r#"{ order(owner: "alice") { total } }"#;
+    assert_eq!(
+        schema
+            .execute(Request::new(query).data(Username("bob".to_string())))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "order": null })
+    );
+
+    let query = r#"{ requiredOrder(owner: "alice") { total } }"#;
+    assert_eq!(
+        schema
+            .execute(Request::new(query).data(Username("bob".to_string())))
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: "Forbidden".to_string(),
+            source: None,
+            locations: vec![Pos { line: 1, column: 3 }],
+            path: vec![PathSegment::Field("requiredOrder".to_string())],
+            extensions: error_code("FORBIDDEN"),
+        }]
+    );
+
+    let query = r#"{ order(owner: "alice") { total } }"#;
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap_err(),
+        vec![ServerError {
+            message: "Forbidden".to_string(),
+            source: None,
+            locations: vec![Pos { line: 1, column: 3 }],
+            path: vec![PathSegment::Field("order".to_string())],
+            extensions: error_code("FORBIDDEN"),
+        }]
+    );
+}
+
+#[tokio::test]
+pub async fn test_guard_result_on_object() {
+    fn only_owner(ctx: &Context<'_>, account: &Account) -> Result<GuardAction> {
+        if ctx.data_opt::<Username>().map(|name| name.0.as_str()) == Some(&account.owner) {
+            Ok(GuardAction::Allow)
+        } else {
+            Ok(GuardAction::Redact)
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(complex, guard_result = "only_owner")]
+    struct Account {
+        owner: String,
+        #[graphql(skip)]
+        balance: i32,
+        iban: Option<String>,
+    }
+
+    #[ComplexObject(guard_result = "only_owner")]
+    impl Account {
+        async fn balance(&self) -> Option<i32> {
+            Some(self.balance)
+        }
+    }
+
+    #[derive(SimpleObject)]
+    struct Query {
+        account: Account,
+    }
+
+    let schema = Schema::new(
+        Query {
+            account: Account {
+                owner: "alice".to_string(),
+                balance: 100,
+                iban: Some("FR76".to_string()),
+            },
+        },
+        EmptyMutation,
+        EmptySubscription,
+    );
+
+    let query = "{ account { iban balance } }";
+    assert_eq!(
+        schema
+            .execute(Request::new(query).data(Username("alice".to_string())))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "account": { "iban": "FR76", "balance": 100 } })
+    );
+    assert_eq!(
+        schema
+            .execute(Request::new(query).data(Username("bob".to_string())))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "account": { "iban": null, "balance": null } })
+    );
+    assert_eq!(
+        schema
+            .execute(Request::new("{ account { owner } }").data(Username("bob".to_string())))
+            .await
+            .into_result()
+            .unwrap_err()[0]
+            .message,
+        "Forbidden"
+    );
+}
+
+#[tokio::test]
+pub async fn test_guard_result_on_object_checked_once() {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    fn public_only(ctx: &Context<'_>, item: &Item) -> Result<GuardAction> {
+        ctx.data_unchecked::<Arc<AtomicUsize>>()
+            .fetch_add(1, Ordering::SeqCst);
+        Ok(match item.public {
+            true => GuardAction::Allow,
+            false => GuardAction::Redact,
+        })
+    }
+
+    struct Item {
+        public: bool,
+    }
+
+    #[Object(guard_result = "public_only")]
+    impl Item {
+        async fn name(&self) -> Option<&str> {
+            Some("item")
+        }
+
+        async fn code(&self) -> Option<i32> {
+            Some(1)
+        }
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "name", ty = "Option<&str>"))]
+    enum Node {
+        Item(Item),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn items(&self) -> Vec<Item> {
+            vec![Item { public: true }, Item { public: false }]
+        }
+
+        async fn node(&self) -> Node {
+            Item { public: false }.into()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let count = Arc::new(AtomicUsize::new(0));
+    let query = "{ items { name code __typename } node { name ... on Item { code } } }";
+    assert_eq!(
+        schema
+            .execute(Request::new(query).data(count.clone()))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "items": [
+                { "name": "item", "code": 1, "__typename": "Item" },
+                { "name": null, "code": null, "__typename": "Item" },
+            ],
+            "node": { "name": null, "code": null },
+        })
+    );
+    assert_eq!(count.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+pub async fn test_guard_result_on_simple_object_field() {
+    #[derive(SimpleObject)]
+    struct Query {
+        #[graphql(
+            guard_result = "|_: &Context<'_>, secret: &Option<String>| Ok(match secret {
+            Some(secret) if secret.starts_with(\"sk_\") => GuardAction::Redact,
+            _ => GuardAction::Allow,
+        })"
+        )]
+        secret: Option<String>,
+        #[graphql(
+            owned,
+            guard_result = "|_: &Context<'_>, _: &Option<String>| Ok(GuardAction::Allow)"
+        )]
+        public: Option<String>,
+    }
+
+    let schema = Schema::new(
+        Query {
+            secret: Some("sk_123".to_string()),
+            public: Some("pk_123".to_string()),
+        },
+        EmptyMutation,
+        EmptySubscription,
+    );
+    assert_eq!(
+        schema
+            .execute("{ secret public }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "secret": null, "public": "pk_123" })
+    );
+}
+
+#[tokio::test]
+pub async fn test_type_guard() {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    struct CountGuard;
+
+    #[cfg_attr(feature = "boxed-trait", async_trait::async_trait)]
+    impl Guard for CountGuard {
+        async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+            ctx.data_unchecked::<Arc<AtomicUsize>>()
+                .fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    struct AdminPanel {
+        id: i32,
+    }
+
+    #[Object(type_guard = "CountGuard.and(RoleGuard::new(Role::Admin))")]
+    impl AdminPanel {
+        async fn id(&self) -> &i32 {
+            &self.id
+        }
+
+        async fn users(&self) -> i32 {
+            10
+        }
+    }
+
+    #[derive(SimpleObject)]
+    #[graphql(type_guard = "RoleGuard::new(Role::Guest).or(RoleGuard::new(Role::Admin))")]
+    struct Profile {
+        id: i32,
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "id", ty = "&i32"))]
+    enum Node {
+        AdminPanel(AdminPanel),
+        Profile(Profile),
+    }
+
+    #[derive(Union)]
+    #[graphql(type_guard = "UserGuard::new(\"alice\")")]
+    enum SearchResult {
+        AdminPanel(AdminPanel),
+        Profile(Profile),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn panel(&self) -> Option<AdminPanel> {
+            Some(AdminPanel { id: 1 })
+        }
+
+        async fn panels(&self) -> Vec<AdminPanel> {
+            vec![AdminPanel { id: 1 }, AdminPanel { id: 2 }]
+        }
+
+        async fn node(&self, admin: bool) -> Option<Node> {
+            Some(match admin {
+                true => AdminPanel { id: 1 }.into(),
+                false => Profile { id: 2 }.into(),
+            })
+        }
+
+        async fn search(&self) -> Option<SearchResult> {
+            Some(SearchResult::Profile(Profile { id: 3 }))
+        }
+    }
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(counter.clone())
+        .finish();
+
+    let query = "{ panels { id users } }";
+    assert_eq!(
+        schema
+            .execute(Request::new(query).data(Role::Admin))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "panels": [{ "id": 1, "users": 10 }, { "id": 2, "users": 10 }] })
+    );
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+
+    let query = "{ panel { id users } }";
+    let resp = schema.execute(Request::new(query).data(Role::Guest)).await;
+    assert_eq!(resp.data, value!({ "panel": null }));
+    assert_eq!(
+        resp.errors,
+        vec![ServerError {
+            message: "Forbidden".to_string(),
+            source: None,
+            locations: vec![Pos { line: 1, column: 3 }],
+            path: vec![PathSegment::Field("panel".to_string())],
+            extensions: error_code("FORBIDDEN"),
         }]
     );
+
+    let query = "{ node(admin: true) { id } }";
+    let resp = schema.execute(Request::new(query).data(Role::Guest)).await;
+    assert_eq!(resp.data, value!({ "node": null }));
+    assert_eq!(
+        resp.errors[0].path,
+        vec![PathSegment::Field("node".to_string())]
+    );
+
+    let query = "{ node(admin: false) { id } }";
+    assert_eq!(
+        schema
+            .execute(Request::new(query).data(Role::Guest))
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "node": { "id": 2 } })
+    );
+
+    let query = "{ search { ... on Profile { id } } }";
+    let resp = schema.execute(Request::new(query).data(Role::Guest)).await;
+    assert_eq!(resp.data, value!({ "search": null }));
+    assert_eq!(resp.errors[0].message, "Forbidden");
+    assert_eq!(
+        schema
+            .execute(
+                Request::new(query)
+                    .data(Role::Guest)
+                    .data(Username("alice".to_string()))
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "search": { "id": 3 } })
+    );
 }
@@ -0,0 +1,250 @@
+use async_graphql::*;
+
+/// A point in time, as an RFC 3339 string.
+struct Timestamp(String);
+
+/// A point in time, as an RFC 3339 string.
+#[Scalar]
+impl ScalarType for Timestamp {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(s) => Ok(Timestamp(s)),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.0.clone())
+    }
+}
+
+include_sdl!("tests/schemas/include_sdl.graphql");
+include_sdl!("tests/schemas/include_sdl_catalog.graphql");
+
+struct UserData {
+    id: &'static str,
+    name: &'static str,
+    status: Status,
+}
+
+#[async_trait::async_trait]
+impl UserResolver for UserData {
+    async fn id(&self, _ctx: &Context<'_>) -> Result<ID> {
+        Ok(self.id.into())
+    }
+
+    async fn name(&self, _ctx: &Context<'_>) -> Result<String> {
+        Ok(self.name.to_string())
+    }
+
+    async fn status(&self, _ctx: &Context<'_>) -> Result<Status> {
+        Ok(self.status)
+    }
+
+    async fn created_at(&self, _ctx: &Context<'_>) -> Result<Timestamp> {
+        Ok(Timestamp("2024-01-01T00:00:00Z".to_string()))
+    }
+
+    async fn nickname(&self, _ctx: &Context<'_>) -> Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+fn users() -> Vec<UserData> {
+    vec![
+        UserData {
+            id: "1",
+            name: "alice",
+            status: Status::Active,
+        },
+        UserData {
+            id: "2",
+            name: "bob",
+            status: Status::Suspended,
+        },
+    ]
+}
+
+struct TeamData;
+
+#[async_trait::async_trait]
+impl TeamResolver for TeamData {
+    async fn id(&self, _ctx: &Context<'_>) -> Result<ID> {
+        Ok("t1".into())
+    }
+
+    async fn members(&self, _ctx: &Context<'_>, first: Option<i32>) -> Result<Vec<User>> {
+        Ok(users()
+            .into_iter()
+            .take(first.unwrap_or_default() as usize)
+            .map(User::new)
+            .collect())
+    }
+}
+
+struct QueryData;
+
+#[async_trait::async_trait]
+impl QueryResolver for QueryData {
+    async fn user(&self, _ctx: &Context<'_>, key: UserKey) -> Result<Option<User>> {
+        Ok(users()
+            .into_iter()
+            .find(|user| match &key {
+                UserKey::Id(id) => user.id == id.as_str(),
+                UserKey::Email(email) => email == &format!("{}@example.com", user.name),
+            })
+            .map(User::new))
+    }
+
+    async fn users(&self, _ctx: &Context<'_>, filter: Option<UserFilter>) -> Result<Vec<User>> {
+        let filter = filter.unwrap();
+        Ok(users()
+            .into_iter()
+            .filter(|user| Some(user.status) == filter.status)
+            .take(filter.limit as usize)
+            .map(User::new)
+            .collect())
+    }
+
+    async fn search(&self, _ctx: &Context<'_>, text: String) -> Result<Vec<SearchResult>> {
+        let mut results = users()
+            .into_iter()
+            .filter(|user| user.name.contains(&text))
+            .map(|user| SearchResult::User(User::new(user)))
+            .collect::<Vec<_>>();
+        results.push(SearchResult::Team(Team::new(TeamData)));
+        Ok(results)
+    }
+
+    async fn node(&self, _ctx: &Context<'_>, id: ID) -> Result<Option<Node>> {
+        Ok(match id.as_str() {
+            "t1" => Some(Node::Team(Team::new(TeamData))),
+            _ => users()
+                .into_iter()
+                .find(|user| user.id == id.as_str())
+                .map(|user| Node::User(User::new(user))),
+        })
+    }
+}
+
+struct MutationData;
+
+#[async_trait::async_trait]
+impl MutationResolver for MutationData {
+    async fn set_status(&self, _ctx: &Context<'_>, id: ID, status: Status) -> Result<User> {
+        let user = users()
+            .into_iter()
+            .find(|user| user.id == id.as_str())
+            .ok_or("user not found")?;
+        Ok(User::new(UserData { status, ..user }))
+    }
+}
+
+fn schema() -> Schema<Query, Mutation, EmptySubscription> {
+    Schema::new(
+        Query::new(QueryData),
+        Mutation::new(MutationData),
+        EmptySubscription,
+    )
+}
+
+#[tokio::test]
+pub async fn test_include_sdl_query() {
+    let schema = schema();
+    assert_eq!(
+        schema
+            .execute(r#"{ user(key: { email: "bob@example.com" }) { id status createdAt } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "user": { "id": "2", "status": "SUSPENDED", "createdAt": "2024-01-01T00:00:00Z" } })
+    );
+    assert_eq!(
+        schema
+            .execute("{ users { name } search(text: \"a\") { __typename ... on Team { members { name } } } }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({
+            "users": [{ "name": "alice" }],
+            "search": [
+                { "__typename": "User" },
+                { "__typename": "Team", "members": [{ "name": "alice" }, { "name": "bob" }] },
+            ],
+        })
+    );
+    assert_eq!(
+        schema
+            .execute(r#"{ node(id: "t1") { id ... on Team { members(first: 1) { name } } } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "node": { "id": "t1", "members": [{ "name": "alice" }] } })
+    );
+}
+
+#[tokio::test]
+pub async fn test_include_sdl_mutation() {
+    assert_eq!(
+        schema()
+            .execute(r#"mutation { setStatus(id: "1", status: LEGACY) { status } }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "setStatus": { "status": "LEGACY" } })
+    );
+}
+
+#[test]
+pub fn test_include_sdl_mismatch() {
+    let sdl = include_str!("schemas/include_sdl.graphql");
+    assert!(resolver_utils::check_sdl::<Query, Mutation, EmptySubscription>(sdl).is_ok());
+
+    let err = resolver_utils::check_sdl::<Query, Mutation, EmptySubscription>(
+        &sdl.replace("limit: Int! = 10", "limit: Int! = 20"),
+    )
+    .unwrap_err();
+    assert!(err.contains("`UserFilter` differs"));
+
+    let err = resolver_utils::check_sdl::<Query, Mutation, EmptySubscription>(
+        &sdl.replace("scalar Timestamp", "scalar Timestamp\nscalar Date"),
+    )
+    .unwrap_err();
+    assert!(err.contains("`Date` is missing from the schema."));
+}
+
+struct CatalogQueryImpl;
+
+#[async_trait::async_trait]
+impl CatalogQueryResolver for CatalogQueryImpl {
+    async fn products(
+        &self,
+        _ctx: &Context<'_>,
+        sort: Option<ProductSort>,
+        tags: Option<Vec<String>>,
+    ) -> Result<Vec<String>> {
+        Ok(vec![format!("{:?}", sort), format!("{:?}", tags)])
+    }
+}
+
+#[tokio::test]
+pub async fn test_include_sdl_multiple_files() {
+    let schema = Schema::new(
+        CatalogQuery::new(CatalogQueryImpl),
+        EmptyMutation,
+        EmptySubscription,
+    );
+    assert_eq!(
+        schema
+            .execute("{ products }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "products": ["Some(Price)", "Some([\"new\"])"] })
+    );
+}
@@ -1,5 +1,11 @@
 use async_graphql::*;
 
+fn error_code(code: &str) -> Option<ErrorExtensionValues> {
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("code", code);
+    Some(extensions)
+}
+
 #[tokio::test]
 pub async fn test_input_object_default_value() {
     #[derive(InputObject)]
@@ -965,7 +971,7 @@ pub async fn test_input_object_validator() {
                 column: 12
             }],
             path: vec![PathSegment::Field("a".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 }
@@ -1084,3 +1090,116 @@ pub async fn test_inputobject_generic_with_name_type() {
         "IntGenericInput"
     );
 }
+
+#[tokio::test]
+pub async fn test_input_object_object_validator() {
+    fn check_order(range: &DateRange, errors: &mut InputFieldErrors) {
+        errors.ensure(range.end > range.start, "end", "must be after start");
+    }
+
+    fn check_mode(range: &DateRange, errors: &mut InputFieldErrors) {
+        if range.mode == "weeks" {
+            if range.weeks.is_some() == range.days.is_some() {
+                errors.add_object("exactly one of weeks or days is required");
+            }
+        } else {
+            errors.ensure(
+                range.weeks.is_none(),
+                "weeks",
+                "is only allowed in weeks mode",
+            );
+        }
+    }
+
+    #[derive(InputObject)]
+    #[graphql(object_validator = "check_order", object_validator = "check_mode")]
+    struct DateRange {
+        start: i32,
+        end: i32,
+        mode: String,
+        weeks: Option<i32>,
+        days: Option<i32>,
+    }
+
+    #[derive(InputObject)]
+    struct Filter {
+        ranges: Vec<DateRange>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn len(&self, range: DateRange) -> i32 {
+            range.end - range.start
+        }
+
+        async fn count(&self, filter: Filter) -> usize {
+            filter.ranges.len()
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+
+    assert_eq!(
+        schema
+            .execute(r#"{ len(range: { start: 1, end: 5, mode: "weeks", weeks: 1 }) }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "len": 4 })
+    );
+
+    assert_eq!(
+        schema
+            .execute(r#"{ len(range: { start: 5, end: 1, mode: "weeks" }) }"#)
+            .await
+            .into_result()
+            .unwrap_err(),
+        vec![ServerError {
+            message: r#"Failed to parse "DateRange": end: must be after start; exactly one of weeks or days is required"#.to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 14
+            }],
+            path: vec![PathSegment::Field("len".to_string())],
+            extensions: {
+                let mut extensions = ErrorExtensionValues::default();
+                extensions.set(
+                    "validationErrors",
+                    value!([
+                        { "path": ["end"], "message": "must be after start" },
+                        { "path": [], "message": "exactly one of weeks or days is required" },
+                    ]),
+                );
+                extensions.set("code", "BAD_USER_INPUT");
+                Some(extensions)
+            },
+        }]
+    );
+
+    let errors = schema
+        .execute(
+            r#"{ count(filter: { ranges: [
+                { start: 1, end: 5, mode: "days", days: 1 },
+                { start: 1, end: 5, mode: "days", weeks: 1 },
+            ] }) }"#,
+        )
+        .await
+        .into_result()
+        .unwrap_err();
+    assert_eq!(
+        errors[0].message,
+        r#"Failed to parse "DateRange": weeks: is only allowed in weeks mode (occurred while parsing "[DateRange!]") (occurred while parsing "Filter")"#
+    );
+    assert_eq!(
+        errors[0]
+            .extensions
+            .as_ref()
+            .unwrap()
+            .get("validationErrors"),
+        Some(&value!([{ "path": ["weeks"], "message": "is only allowed in weeks mode" }]))
+    );
+}
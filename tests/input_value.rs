@@ -2,6 +2,12 @@ use std::sync::Arc;
 
 use async_graphql::*;
 
+fn error_code(code: &str) -> Option<ErrorExtensionValues> {
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("code", code);
+    Some(extensions)
+}
+
 #[tokio::test]
 pub async fn test_input_value_custom_error() {
     struct Query;
@@ -26,7 +32,7 @@ pub async fn test_input_value_custom_error() {
                 column: 14,
             }],
             path: vec![PathSegment::Field("parseInt".to_owned())],
-            extensions: None,
+            extensions: error_code("BAD_USER_INPUT"),
         }],
     );
 }
@@ -628,3 +628,52 @@ pub mod test_interface_docs {
         MyObj(MyObj),
     }
 }
+
+#[tokio::test]
+pub async fn test_interface_fragment_spread_on_object() {
+    #[derive(SimpleObject)]
+    #[graphql(cache_control(max_age = 30))]
+    struct MyObj {
+        id: i32,
+        #[graphql(complexity = 10)]
+        expensive: i32,
+    }
+
+    #[derive(Interface)]
+    #[graphql(field(name = "id", ty = "&i32"))]
+    enum Node {
+        MyObj(MyObj),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        #[graphql(cache_control(max_age = 60))]
+        async fn node(&self) -> Node {
+            MyObj {
+                id: 1,
+                expensive: 2,
+            }
+            .into()
+        }
+    }
+
+    // The fields of a named fragment are checked on its type condition.
+    let query = "{ node { ...ObjFields } } fragment ObjFields on MyObj { expensive }";
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_complexity(5)
+        .finish();
+    let errors = schema.execute(query).await.into_result().unwrap_err();
+    assert_eq!(errors[0].message, "Query is too complex.");
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let resp = schema.execute(query).await;
+    assert_eq!(
+        resp.data,
+        value!({
+            "node": { "expensive": 2 },
+        })
+    );
+    assert_eq!(resp.cache_control.max_age, 30);
+}
@@ -667,3 +667,145 @@ pub async fn root() {
         value!({ "__schema": { "queryType": { "name": "Query" } } })
     );
 }
+
+#[cfg(feature = "introspection_cache")]
+#[tokio::test]
+pub async fn test_introspection_cache() {
+    struct IsAdmin(bool);
+
+    #[derive(SimpleObject)]
+    struct MyObj {
+        a: i32,
+        #[graphql(visible = "is_admin")]
+        b: i32,
+        #[graphql(deprecation)]
+        c: i32,
+    }
+
+    fn is_admin(ctx: &Context<'_>) -> bool {
+        ctx.data_unchecked::<IsAdmin>().0
+    }
+
+    struct Query;
+
+    #[Object]
+    #[allow(unreachable_code)]
+    impl Query {
+        async fn obj(&self) -> MyObj {
+            todo!()
+        }
+    }
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_introspection_cache(8)
+        .finish();
+    let query = r#"
+        query($deprecated: Boolean!) {
+            __schema { types { name fields(includeDeprecated: $deprecated) { name } } }
+        }
+    "#;
+
+    let fields = |is_admin: bool, deprecated: bool| {
+        let schema = schema.clone();
+        async move {
+            let data = schema
+                .execute(
+                    Request::new(query)
+                        .variables(Variables::from_value(value!({ "deprecated": deprecated })))
+                        .data(IsAdmin(is_admin)),
+                )
+                .await
+                .into_result()
+                .unwrap()
+                .data
+                .into_json()
+                .unwrap();
+            data["__schema"]["types"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|ty| ty["name"] == "MyObj")
+                .unwrap()["fields"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|field| field["name"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        }
+    };
+
+    for _ in 0..2 {
+        assert_eq!(fields(false, false).await, vec!["a"]);
+        assert_eq!(fields(true, false).await, vec!["a", "b"]);
+        assert_eq!(fields(false, true).await, vec!["a", "c"]);
+        assert_eq!(fields(true, true).await, vec!["a", "b", "c"]);
+    }
+}
+
+#[cfg(feature = "introspection_cache")]
+#[tokio::test]
+pub async fn test_introspection_cache_eviction() {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use async_graphql::extensions::{
+        Extension, ExtensionContext, ExtensionFactory, NextResolve, ResolveInfo,
+    };
+
+    struct CountSchemaResolves(Arc<AtomicUsize>);
+
+    impl ExtensionFactory for CountSchemaResolves {
+        fn create(&self) -> Arc<dyn Extension> {
+            Arc::new(CountSchemaResolvesImpl(self.0.clone()))
+        }
+    }
+
+    struct CountSchemaResolvesImpl(Arc<AtomicUsize>);
+
+    #[async_trait::async_trait]
+    impl Extension for CountSchemaResolvesImpl {
+        async fn resolve(
+            &self,
+            ctx: &ExtensionContext<'_>,
+            info: ResolveInfo<'_>,
+            next: NextResolve<'_>,
+        ) -> ServerResult<Option<Value>> {
+            if info.parent_type == "__Schema" {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+            next.run(ctx, info).await
+        }
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            1
+        }
+    }
+
+    let resolves = Arc::new(AtomicUsize::new(0));
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .extension(CountSchemaResolves(resolves.clone()))
+        .enable_introspection_cache(1)
+        .finish();
+    let execute = |query: &'static str| {
+        let schema = schema.clone();
+        let resolves = resolves.clone();
+        async move {
+            schema.execute(query).await.into_result().unwrap();
+            resolves.swap(0, Ordering::SeqCst)
+        }
+    };
+
+    assert_eq!(execute("{ __schema { queryType { name } } }").await, 1);
+    assert_eq!(execute("{ __schema { queryType { name } } }").await, 0);
+    // The second result evicts the first one.
+    assert_eq!(execute("{ __schema { mutationType { name } } }").await, 1);
+    assert_eq!(execute("{ __schema { mutationType { name } } }").await, 0);
+    assert_eq!(execute("{ __schema { queryType { name } } }").await, 1);
+}
@@ -7,6 +7,12 @@ use std::{
 
 use async_graphql::*;
 
+fn error_code(code: &str) -> Option<ErrorExtensionValues> {
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("code", code);
+    Some(extensions)
+}
+
 #[tokio::test]
 pub async fn test_list_type() {
     #[derive(InputObject)]
@@ -178,7 +184,7 @@ pub async fn test_array_type() {
                 column: 22,
             }],
             path: vec![PathSegment::Field("arrayInput".to_owned())],
-            extensions: None,
+            extensions: error_code("BAD_USER_INPUT"),
         }],
     );
 }
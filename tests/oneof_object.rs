@@ -346,3 +346,63 @@ async fn test_issue_923() {
     let query = r#"{ query(filter: {all: ["c", "d"]}) }"#;
     schema.execute(query).await.into_result().unwrap();
 }
+
+#[tokio::test]
+async fn test_oneof_object_object_validator() {
+    fn check_id(id: &UserId, errors: &mut InputFieldErrors) {
+        match id {
+            UserId::Id(id) => {
+                errors.ensure(*id > 0, "id", "must be positive");
+            }
+            UserId::Email(email) => {
+                errors.ensure(email.contains('@'), "email", "is invalid");
+            }
+        }
+    }
+
+    #[derive(OneofObject)]
+    #[graphql(object_validator = "check_id")]
+    enum UserId {
+        Id(i32),
+        Email(String),
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn user(&self, id: UserId) -> bool {
+            let _ = id;
+            true
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(r#"{ user(id: { email: "a@b.c" }) }"#)
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "user": true })
+    );
+
+    let errors = schema
+        .execute(r#"{ user(id: { email: "abc" }) }"#)
+        .await
+        .into_result()
+        .unwrap_err();
+    assert_eq!(
+        errors[0].message,
+        r#"Failed to parse "UserId": email: is invalid"#
+    );
+    assert_eq!(
+        errors[0]
+            .extensions
+            .as_ref()
+            .unwrap()
+            .get("validationErrors"),
+        Some(&value!([{ "path": ["email"], "message": "is invalid" }]))
+    );
+}
@@ -70,3 +70,92 @@ pub async fn test_float_inf() {
         value!({ "value": null })
     );
 }
+
+#[tokio::test]
+pub async fn test_strict_int() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn small(&self) -> u32 {
+            10
+        }
+
+        async fn large(&self) -> Option<u32> {
+            Some(u32::MAX)
+        }
+
+        async fn required(&self) -> u32 {
+            u32::MAX
+        }
+
+        async fn list(&self) -> Vec<Option<u32>> {
+            vec![Some(1), Some(u32::MAX)]
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute("{ small large }")
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "small": 10, "large": 4294967295u32 })
+    );
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .enable_strict_int()
+        .finish();
+    let resp = schema.execute("{ small large list }").await;
+    assert_eq!(
+        resp.data,
+        value!({ "small": 10, "large": null, "list": [1, null] })
+    );
+    assert_eq!(
+        resp.errors
+            .iter()
+            .map(|err| (err.message.as_str(), err.path.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                "Int cannot represent non 32-bit signed integer value: 4294967295",
+                vec![PathSegment::Field("large".to_string())]
+            ),
+            (
+                "Int cannot represent non 32-bit signed integer value: 4294967295",
+                vec![
+                    PathSegment::Field("list".to_string()),
+                    PathSegment::Index(1)
+                ]
+            ),
+        ]
+    );
+
+    let resp = schema.execute("{ small required }").await;
+    assert_eq!(resp.data, Value::Null);
+    assert_eq!(resp.errors.len(), 1);
+    assert_eq!(resp.errors[0].code(), Some(ErrorCode::InternalServerError));
+}
+
+#[cfg(feature = "dynamic-schema")]
+#[tokio::test]
+pub async fn test_strict_int_dynamic_schema() {
+    use async_graphql::dynamic::*;
+
+    let query =
+        Object::new("Query").field(Field::new("large", TypeRef::named(TypeRef::INT), |_| {
+            FieldFuture::new(async { Ok(Some(Value::from(i64::MAX))) })
+        }));
+    let schema = Schema::build("Query", None, None)
+        .register(query)
+        .enable_strict_int()
+        .finish()
+        .unwrap();
+    let resp = schema.execute("{ large }").await;
+    assert_eq!(
+        resp.into_result().unwrap_err()[0].message,
+        "Int cannot represent non 32-bit signed integer value: 9223372036854775807"
+    );
+}
@@ -49,3 +49,86 @@ pub async fn test_http_headers() {
         Some(&HeaderValue::from_static("1"))
     );
 }
+
+#[tokio::test]
+pub async fn test_introspection_limits() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> Vec<i32> {
+            vec![1, 2]
+        }
+    }
+
+    let query = r#"{
+        __type(name: "Query") {
+            fields { type { ofType { ofType { ofType { name } } } } }
+        }
+    }"#;
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_depth(2)
+        .finish();
+    let errors = schema.execute(query).await.into_result().unwrap_err();
+    assert_eq!(errors[0].message, "Query is nested too deep.");
+    assert_eq!(errors[0].code(), Some(ErrorCode::QueryTooDeep));
+
+    // The introspection fields are still counted by the general limits.
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_depth(2)
+        .limit_introspection_depth(5)
+        .finish();
+    let errors = schema.execute(query).await.into_result().unwrap_err();
+    assert_eq!(errors[0].message, "Query is nested too deep.");
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_complexity(2)
+        .limit_introspection_depth(5)
+        .finish();
+    let errors = schema.execute(query).await.into_result().unwrap_err();
+    assert_eq!(errors[0].message, "Query is too complex.");
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_introspection_depth(5)
+        .finish();
+    assert_eq!(
+        schema.execute(query).await.into_result().unwrap().data,
+        value!({
+            "__type": {
+                "fields": [
+                    { "type": { "ofType": { "ofType": { "ofType": { "name": "Int" } } } } }
+                ]
+            }
+        })
+    );
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_introspection_depth(4)
+        .finish();
+    let errors = schema.execute(query).await.into_result().unwrap_err();
+    assert_eq!(errors[0].message, "Introspection query is nested too deep.");
+    assert_eq!(errors[0].code(), Some(ErrorCode::QueryTooDeep));
+
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_introspection_fields(2)
+        .finish();
+    assert!(
+        schema
+            .execute(r#"{ a: __type(name: "Query") { name } __schema { queryType { name } } }"#)
+            .await
+            .is_ok()
+    );
+    let errors = schema
+        .execute(
+            r#"{ a: __type(name: "Query") { name } b: __type(name: "Int") { name } __schema { queryType { name } } }"#,
+        )
+        .await
+        .into_result()
+        .unwrap_err();
+    assert_eq!(
+        errors[0].message,
+        "Too many introspection fields in the operation."
+    );
+    assert_eq!(errors[0].code(), Some(ErrorCode::QueryTooComplex));
+}
@@ -990,3 +990,98 @@ pub async fn test_on_ping() {
         }),
     );
 }
+
+#[tokio::test]
+pub async fn test_complete_cancels_operation() {
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn value(&self) -> i32 {
+            10
+        }
+    }
+
+    #[derive(Default, Clone)]
+    struct Cancelled(Arc<Mutex<bool>>);
+
+    struct Subscription;
+
+    #[Subscription]
+    impl Subscription {
+        async fn values(&self, ctx: &Context<'_>) -> impl Stream<Item = i32> {
+            let token = ctx.cancellation_token().clone();
+            let cancelled = ctx.data_unchecked::<Cancelled>().clone();
+            tokio::spawn(async move {
+                token.cancelled().await;
+                *cancelled.0.lock().unwrap() = true;
+            });
+            futures_util::stream::pending()
+        }
+    }
+
+    let cancelled = Cancelled::default();
+    let schema = Schema::build(Query, EmptyMutation, Subscription)
+        .data(cancelled.clone())
+        .finish();
+    let (mut tx, rx) = mpsc::unbounded();
+    let mut stream = http::WebSocket::new(schema, rx, WebSocketProtocols::GraphQLWS);
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "connection_init",
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap().unwrap_text())
+            .unwrap(),
+        serde_json::json!({
+            "type": "connection_ack",
+        }),
+    );
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "start",
+            "id": "1",
+            "payload": {
+                "query": "subscription { values }"
+            },
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert!(
+        tokio::time::timeout(Duration::from_millis(50), stream.next())
+            .await
+            .is_err()
+    );
+    assert!(!*cancelled.0.lock().unwrap());
+
+    tx.send(
+        serde_json::to_string(&value!({
+            "type": "complete",
+            "id": "1",
+        }))
+        .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&stream.next().await.unwrap().unwrap_text())
+            .unwrap(),
+        serde_json::json!({
+            "type": "complete",
+            "id": "1",
+        }),
+    );
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(*cancelled.0.lock().unwrap());
+}
@@ -0,0 +1,151 @@
+#![cfg(feature = "tokio")]
+
+use std::time::Duration;
+
+use async_graphql::{runtime::TokioTimer, *};
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn fast(&self) -> i32 {
+        1
+    }
+
+    #[graphql(timeout = "50ms")]
+    async fn slow(&self) -> Option<i32> {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        Some(2)
+    }
+
+    async fn nested(&self) -> Option<Nested> {
+        Some(Nested)
+    }
+
+    async fn remaining(&self, ctx: &Context<'_>) -> bool {
+        ctx.remaining_time()
+            .is_some_and(|remaining| remaining <= Duration::from_secs(1))
+    }
+
+    async fn until_deadline(&self, ctx: &Context<'_>) -> Result<i32> {
+        ctx.until_deadline(async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            3
+        })
+        .await
+    }
+
+    async fn sleep(&self, millis: u64) -> i32 {
+        tokio::time::sleep(Duration::from_millis(millis)).await;
+        4
+    }
+}
+
+struct Nested;
+
+#[Object]
+impl Nested {
+    #[graphql(timeout = "1s")]
+    async fn value(&self) -> i32 {
+        5
+    }
+
+    #[graphql(timeout = "50ms")]
+    async fn slow(&self) -> i32 {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        6
+    }
+}
+
+fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .timer(TokioTimer::default())
+        .finish()
+}
+
+#[tokio::test]
+pub async fn test_field_timeout() {
+    let resp = schema().execute("{ fast slow }").await;
+    assert_eq!(resp.data, value!({ "fast": 1, "slow": null }));
+    assert_eq!(resp.errors.len(), 1);
+    assert_eq!(resp.errors[0].message, "Field timed out");
+    assert_eq!(resp.errors[0].code(), Some(ErrorCode::Timeout));
+    assert_eq!(
+        resp.errors[0].path,
+        vec![PathSegment::Field("slow".to_string())]
+    );
+}
+
+#[tokio::test]
+pub async fn test_non_null_field_timeout() {
+    let resp = schema().execute("{ fast nested { value slow } }").await;
+    assert_eq!(resp.data, value!({ "fast": 1, "nested": null }));
+    assert_eq!(resp.errors.len(), 1);
+    assert_eq!(resp.errors[0].code(), Some(ErrorCode::Timeout));
+    assert_eq!(
+        resp.errors[0].path,
+        vec![
+            PathSegment::Field("nested".to_string()),
+            PathSegment::Field("slow".to_string())
+        ]
+    );
+
+    let resp = schema().execute("{ nested { value } }").await;
+    assert_eq!(resp.data, value!({ "nested": { "value": 5 } }));
+    assert!(resp.errors.is_empty());
+}
+
+#[tokio::test]
+pub async fn test_request_deadline() {
+    let resp = schema()
+        .execute(
+            Request::new("{ fast remaining sleep(millis: 5000) }")
+                .timeout(Duration::from_millis(100)),
+        )
+        .await;
+    assert_eq!(
+        resp.into_result().unwrap_err(),
+        vec![ServerError {
+            message: "Field timed out".to_string(),
+            source: None,
+            locations: vec![Pos {
+                line: 1,
+                column: 18
+            }],
+            path: vec![PathSegment::Field("sleep".to_string())],
+            extensions: Some({
+                let mut extensions = ErrorExtensionValues::default();
+                extensions.set("code", "TIMEOUT");
+                extensions
+            }),
+        }]
+    );
+
+    let resp = schema()
+        .execute(Request::new("{ fast remaining }").timeout(Duration::from_millis(100)))
+        .await;
+    assert_eq!(resp.data, value!({ "fast": 1, "remaining": true }));
+
+    let resp = schema().execute("{ remaining }").await;
+    assert_eq!(resp.data, value!({ "remaining": false }));
+}
+
+#[tokio::test]
+pub async fn test_until_deadline() {
+    let resp = schema()
+        .execute(Request::new("{ untilDeadline }").timeout(Duration::from_millis(50)))
+        .await;
+    assert_eq!(resp.data, Value::Null);
+    assert_eq!(resp.errors[0].message, "Deadline exceeded");
+    assert_eq!(resp.errors[0].code(), Some(ErrorCode::Timeout));
+}
+
+#[tokio::test]
+pub async fn test_timeout_without_timer() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    let resp = schema
+        .execute(Request::new("{ sleep(millis: 100) }").timeout(Duration::from_millis(10)))
+        .await;
+    assert_eq!(resp.data, value!({ "sleep": 4 }));
+    assert!(resp.errors.is_empty());
+}
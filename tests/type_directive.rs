@@ -82,7 +82,10 @@ pub fn test_type_directive_1() {
 
     let sdl = schema.sdl_with_options(SDLExportOptions::new().federation().compose_directive());
 
+    #[cfg(not(feature = "int64_as_long"))]
     let expected = include_str!("schemas/test_fed2_compose.schema.graphql");
+    #[cfg(feature = "int64_as_long")]
+    let expected = include_str!("schemas/test_fed2_compose_int64_as_long.schema.graphql");
     assert_eq!(expected, &sdl)
 }
 
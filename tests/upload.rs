@@ -0,0 +1,227 @@
+use async_graphql::{
+    futures_util::{AsyncReadExt, SinkExt, TryStreamExt},
+    http::{MultipartOptions, receive_batch_body, receive_batch_body_streaming},
+    *,
+};
+
+const BOUNDARY: &str = "boundary";
+
+fn part(name: &str, filename: Option<&str>, content: &str) -> String {
+    match filename {
+        Some(filename) => format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{name}\"; filename=\"{filename}\"\r\nContent-Type: text/plain\r\n\r\n{content}\r\n"
+        ),
+        None => format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{content}\r\n"
+        ),
+    }
+}
+
+fn operations(query: &str) -> String {
+    part(
+        "operations",
+        None,
+        &serde_json::json!({
+            "query": query,
+            "variables": { "files": [null, null] },
+        })
+        .to_string(),
+    )
+}
+
+fn map() -> String {
+    part(
+        "map",
+        None,
+        r#"{ "0": ["variables.files.0"], "1": ["variables.files.1"] }"#,
+    )
+}
+
+fn end() -> String {
+    format!("--{BOUNDARY}--\r\n")
+}
+
+fn content_type() -> Option<String> {
+    Some(format!("multipart/form-data; boundary={BOUNDARY}"))
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn value(&self) -> i32 {
+        10
+    }
+}
+
+struct Mutation;
+
+#[Object]
+impl Mutation {
+    async fn upload(&self, ctx: &Context<'_>, files: Vec<Upload>) -> Result<Vec<String>> {
+        let mut contents = Vec::new();
+        for file in files {
+            let stream = file.stream(ctx).await?;
+            let filename = stream.filename.clone();
+            let mut content = String::new();
+            stream
+                .into_async_read()
+                .read_to_string(&mut content)
+                .await?;
+            contents.push(format!("{}: {}", filename, content));
+        }
+        Ok(contents)
+    }
+
+    async fn upload_reversed(&self, ctx: &Context<'_>, files: Vec<Upload>) -> Result<i32> {
+        let mut size = 0;
+        for file in files.iter().rev() {
+            let chunks = file.stream(ctx).await?.try_collect::<Vec<_>>().await?;
+            size += chunks.iter().map(|chunk| chunk.len()).sum::<usize>();
+        }
+        Ok(size as i32)
+    }
+}
+
+fn schema() -> Schema<Query, Mutation, EmptySubscription> {
+    Schema::new(Query, Mutation, EmptySubscription)
+}
+
+#[tokio::test]
+pub async fn test_upload_streaming() {
+    let first_file = part("0", Some("a.txt"), "hello");
+    let (first_file_headers, first_file_content) =
+        first_file.split_at(first_file.find("hello").unwrap());
+
+    let (mut tx, rx) = futures_channel::mpsc::unbounded::<std::io::Result<Vec<u8>>>();
+    tx.send(Ok((operations(
+        "mutation($files: [Upload!]!) { upload(files: $files) }",
+    ) + &map()
+        + first_file_headers)
+        .into_bytes()))
+        .await
+        .unwrap();
+
+    // The request is received before the content of the files is sent.
+    let request = receive_batch_body_streaming(
+        content_type(),
+        rx.into_async_read(),
+        MultipartOptions::default().stream_uploads(true),
+    )
+    .await
+    .unwrap()
+    .into_single()
+    .unwrap();
+
+    let remaining = [
+        first_file_content.to_string(),
+        part("1", Some("b.txt"), "world"),
+        end(),
+    ];
+    let sender = tokio::spawn(async move {
+        for chunk in remaining {
+            tokio::task::yield_now().await;
+            tx.send(Ok(chunk.into_bytes())).await.unwrap();
+        }
+    });
+
+    assert_eq!(
+        schema().execute(request).await.into_result().unwrap().data,
+        value!({ "upload": ["a.txt: hello", "b.txt: world"] })
+    );
+    sender.await.unwrap();
+}
+
+#[tokio::test]
+pub async fn test_upload_streaming_errors() {
+    let body = |query: &str, file: &str| {
+        futures_util::io::Cursor::new(
+            (operations(query)
+                + &map()
+                + &part("0", Some("a.txt"), file)
+                + &part("1", Some("b.txt"), "world")
+                + &end())
+                .into_bytes(),
+        )
+    };
+
+    // The size limit is enforced while the file is read.
+    let request = receive_batch_body_streaming(
+        content_type(),
+        body(
+            "mutation($files: [Upload!]!) { upload(files: $files) }",
+            &"a".repeat(1000),
+        ),
+        MultipartOptions::default()
+            .stream_uploads(true)
+            .max_file_size(200),
+    )
+    .await
+    .unwrap()
+    .into_single()
+    .unwrap();
+    let errors = schema().execute(request).await.errors;
+    assert_eq!(errors.len(), 1);
+    assert!(
+        errors[0].message.contains("exceeded"),
+        "{}",
+        errors[0].message
+    );
+
+    // The files must be read in the order of the map.
+    let request = receive_batch_body_streaming(
+        content_type(),
+        body(
+            "mutation($files: [Upload!]!) { uploadReversed(files: $files) }",
+            "hello",
+        ),
+        MultipartOptions::default().stream_uploads(true),
+    )
+    .await
+    .unwrap()
+    .into_single()
+    .unwrap();
+    assert_eq!(
+        schema().execute(request).await.errors[0].message,
+        "the upload has already been read, uploads must be read in the order of the map"
+    );
+}
+
+#[tokio::test]
+pub async fn test_upload_buffered_stream() {
+    let body = operations("mutation($files: [Upload!]!) { upload(files: $files) }")
+        + &map()
+        + &part("0", Some("a.txt"), "hello")
+        + &part("1", Some("b.txt"), "world")
+        + &end();
+    let request = receive_batch_body(content_type(), body.as_bytes(), MultipartOptions::default())
+        .await
+        .unwrap()
+        .into_single()
+        .unwrap();
+
+    assert_eq!(
+        schema().execute(request).await.into_result().unwrap().data,
+        value!({ "upload": ["a.txt: hello", "b.txt: world"] })
+    );
+}
+
+#[tokio::test]
+pub async fn test_upload_stream_requires_streaming_body() {
+    let body = operations("mutation($files: [Upload!]!) { upload(files: $files) }")
+        + &map()
+        + &part("0", Some("a.txt"), "hello")
+        + &part("1", Some("b.txt"), "world")
+        + &end();
+    let err = receive_batch_body(
+        content_type(),
+        body.as_bytes(),
+        MultipartOptions::default().stream_uploads(true),
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        ParseRequestError::UnsupportedStreamingUploads
+    ));
+}
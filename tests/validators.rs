@@ -7,6 +7,12 @@ use std::sync::Arc;
 use async_graphql::*;
 use futures_util::{Stream, StreamExt};
 
+fn error_code(code: &str) -> Option<ErrorExtensionValues> {
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("code", code);
+    Some(extensions)
+}
+
 #[tokio::test]
 pub async fn test_all_validator() {
     struct Query;
@@ -66,6 +72,160 @@ pub async fn test_all_validator() {
         ) -> i32 {
             todo!()
         }
+
+        async fn url(&self, #[graphql(validator(url))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn uuid(&self, #[graphql(validator(uuid))] n: ID) -> i32 {
+            todo!()
+        }
+
+        async fn uuid_v4(&self, #[graphql(validator(uuid = 4))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn ip(&self, #[graphql(validator(ip))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn cidr(&self, #[graphql(validator(cidr))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn hostname(&self, #[graphql(validator(hostname))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn phone(&self, #[graphql(validator(phone))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn date(&self, #[graphql(validator(date))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn time(&self, #[graphql(validator(time))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn date_time(&self, #[graphql(validator(date_time))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn hex_color(&self, #[graphql(validator(hex_color))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn ascii(&self, #[graphql(validator(ascii))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn alphanumeric(&self, #[graphql(validator(alphanumeric))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn not_blank(&self, #[graphql(validator(not_blank))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn one_of(&self, #[graphql(validator(one_of("a", "b")))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn one_of_numbers(&self, #[graphql(validator(one_of(1, 2)))] n: i32) -> i32 {
+            todo!()
+        }
+
+        async fn starts_with(&self, #[graphql(validator(starts_with = "a"))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn ends_with(&self, #[graphql(validator(ends_with = "a"))] n: String) -> i32 {
+            todo!()
+        }
+
+        async fn unique_items(
+            &self,
+            #[graphql(validator(unique_items))] n: Option<Vec<String>>,
+        ) -> i32 {
+            todo!()
+        }
+    }
+}
+
+#[tokio::test]
+pub async fn test_format_validators() {
+    #[derive(InputObject)]
+    struct Contact {
+        #[graphql(validator(phone))]
+        phone: String,
+        #[graphql(validator(url))]
+        website: Option<String>,
+        #[graphql(validator(unique_items, list, not_blank, max_length = 10))]
+        tags: Vec<String>,
+    }
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn contact(&self, contact: Contact) -> String {
+            contact.phone
+        }
+
+        async fn color(
+            &self,
+            #[graphql(validator(one_of("red", "green", "blue")))] name: String,
+        ) -> String {
+            name
+        }
+    }
+
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert_eq!(
+        schema
+            .execute(
+                r#"{
+                    contact(contact: { phone: "+14155552671", website: "https://example.com", tags: ["a", "b"] })
+                    color(name: "red")
+                }"#
+            )
+            .await
+            .into_result()
+            .unwrap()
+            .data,
+        value!({ "contact": "+14155552671", "color": "red" })
+    );
+
+    for (query, message) in [
+        (
+            r#"{ contact(contact: { phone: "4155552671", tags: [] }) }"#,
+            r#"Failed to parse "String": invalid phone number, must be in E.164 format (occurred while parsing "Contact")"#,
+        ),
+        (
+            r#"{ contact(contact: { phone: "+14155552671", website: "example.com", tags: [] }) }"#,
+            r#"Failed to parse "String": invalid url (occurred while parsing "Contact")"#,
+        ),
+        (
+            r#"{ contact(contact: { phone: "+14155552671", tags: ["a", "b", "a"] }) }"#,
+            r#"Failed to parse "[String!]": the items must be unique, the item at index 2 is a duplicate (occurred while parsing "Contact")"#,
+        ),
+        (
+            r#"{ contact(contact: { phone: "+14155552671", tags: ["a", " "] }) }"#,
+            r#"Failed to parse "String": the value must not be blank (occurred while parsing "Contact")"#,
+        ),
+        (
+            r#"{ color(name: "black") }"#,
+            r#"Failed to parse "String": the value must be one of red, green, blue"#,
+        ),
+    ] {
+        assert_eq!(
+            schema.execute(query).await.into_result().unwrap_err()[0].message,
+            message,
+            "{}",
+            query
+        );
     }
 }
 
@@ -106,7 +266,7 @@ pub async fn test_validator_on_object_field_args() {
                 column: 12
             }],
             path: vec![PathSegment::Field("value".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 }
@@ -167,7 +327,7 @@ pub async fn test_validator_on_input_object_field() {
                 column: 16
             }],
             path: vec![PathSegment::Field("value".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 
@@ -186,7 +346,7 @@ pub async fn test_validator_on_input_object_field() {
                 column: 16
             }],
             path: vec![PathSegment::Field("value".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 }
@@ -232,7 +392,7 @@ pub async fn test_validator_on_complex_object_field_args() {
                 column: 12
             }],
             path: vec![PathSegment::Field("value".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 }
@@ -290,7 +450,7 @@ pub async fn test_validator_on_subscription_field_args() {
                 column: 25
             }],
             path: vec![PathSegment::Field("value".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 }
@@ -393,7 +553,7 @@ pub async fn test_custom_validator() {
                 column: 12
             }],
             path: vec![PathSegment::Field("value".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 
@@ -422,7 +582,7 @@ pub async fn test_custom_validator() {
                 column: 16
             }],
             path: vec![PathSegment::Field("input".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 
@@ -454,7 +614,7 @@ pub async fn test_custom_validator() {
                 column: 25
             }],
             path: vec![PathSegment::Field("value".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 
@@ -472,7 +632,7 @@ pub async fn test_custom_validator() {
                 column: 18
             }],
             path: vec![PathSegment::Field("value2".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 
@@ -490,7 +650,7 @@ pub async fn test_custom_validator() {
                 column: 18
             }],
             path: vec![PathSegment::Field("value3".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 
@@ -551,7 +711,7 @@ pub async fn test_custom_validator_with_fn() {
                 column: 12
             }],
             path: vec![PathSegment::Field("value".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 }
@@ -662,7 +822,7 @@ pub async fn test_list_validator() {
                 column: 12
             }],
             path: vec![PathSegment::Field("value".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 }
@@ -748,7 +908,7 @@ pub async fn test_validate_wrapper_types() {
             source: None,
             locations: vec![Pos { line: 1, column: 7 }],
             path: vec![PathSegment::Field("a".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 
@@ -764,7 +924,7 @@ pub async fn test_validate_wrapper_types() {
             source: None,
             locations: vec![Pos { line: 1, column: 7 }],
             path: vec![PathSegment::Field("b".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 
@@ -780,7 +940,7 @@ pub async fn test_validate_wrapper_types() {
             source: None,
             locations: vec![Pos { line: 1, column: 7 }],
             path: vec![PathSegment::Field("f".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 }
@@ -818,7 +978,7 @@ pub async fn test_list_both_max_items_and_max_length() {
             source: None,
             locations: vec![Pos { column: 17, line: 1}],
             path: vec![PathSegment::Field("value".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 
@@ -833,7 +993,7 @@ pub async fn test_list_both_max_items_and_max_length() {
             source: None,
             locations: vec![Pos { column: 17, line: 1}],
             path: vec![PathSegment::Field("value".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 
@@ -860,7 +1020,7 @@ pub async fn test_list_both_max_items_and_max_length() {
             source: None,
             locations: vec![Pos { column: 18, line: 1}],
             path: vec![PathSegment::Field("value2".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 
@@ -875,7 +1035,7 @@ pub async fn test_list_both_max_items_and_max_length() {
             source: None,
             locations: vec![Pos { column: 18, line: 1}],
             path: vec![PathSegment::Field("value2".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 
@@ -890,7 +1050,7 @@ pub async fn test_list_both_max_items_and_max_length() {
             source: None,
             locations: vec![Pos { column: 18, line: 1}],
             path: vec![PathSegment::Field("value2".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 
@@ -968,7 +1128,7 @@ pub async fn test_issue_1164() {
             source: None,
             locations: vec![Pos { column: 12, line: 1}],
             path: vec![PathSegment::Field("a".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 
@@ -983,7 +1143,7 @@ pub async fn test_issue_1164() {
             source: None,
             locations: vec![Pos { column: 12, line: 1}],
             path: vec![PathSegment::Field("a".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 
@@ -1001,7 +1161,7 @@ pub async fn test_issue_1164() {
                 line: 1
             }],
             path: vec![PathSegment::Field("a".to_string())],
-            extensions: None
+            extensions: error_code("BAD_USER_INPUT")
         }]
     );
 }